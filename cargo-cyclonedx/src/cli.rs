@@ -1,7 +1,7 @@
 use cargo_cyclonedx::{
     config::{
-        CdxExtension, CustomPrefix, IncludedDependencies, OutputOptions, Pattern, Prefix,
-        PrefixError, SbomConfig,
+        CdxExtension, CustomPrefix, DependencySet, Features, IncludedDependencies, OutputOptions,
+        Pattern, Prefix, PrefixError, SbomConfig,
     },
     format::Format,
 };
@@ -25,7 +25,7 @@ pub struct Args {
     #[clap(long = "manifest-path", value_name = "PATH")]
     pub manifest_path: Option<path::PathBuf>,
 
-    /// Output BOM format: json, xml
+    /// Output BOM format: json, xml, protobuf
     #[clap(long = "format", short = 'f', value_name = "FORMAT")]
     pub format: Option<Format>,
 
@@ -45,8 +45,73 @@ pub struct Args {
     #[clap(name = "top-level", long = "top-level")]
     pub top_level: bool,
 
+    /// Members whose dependencies are listed in each BOM: member (default), workspace
+    #[clap(long = "dependency-set", value_name = "SET")]
+    pub dependency_set: Option<DependencySet>,
+
+    /// Space or comma separated list of features to activate
+    #[clap(long = "features", short = 'F', value_name = "FEATURES")]
+    pub features: Vec<String>,
+
+    /// Activate all available features
+    #[clap(long = "all-features")]
+    pub all_features: bool,
+
+    /// Do not activate the `default` feature
+    #[clap(long = "no-default-features")]
+    pub no_default_features: bool,
+
+    /// Only include dependencies activated on the target triple, may be passed multiple times
+    #[clap(long = "target", value_name = "TRIPLE")]
+    pub target: Vec<String>,
+
+    /// Include build dependencies, with an excluded scope
+    #[clap(long = "build-dependencies", overrides_with = "no_build_dependencies")]
+    pub build_dependencies: bool,
+
+    /// Do not include build dependencies, overriding the configuration file
+    #[clap(long = "no-build-dependencies", overrides_with = "build_dependencies")]
+    pub no_build_dependencies: bool,
+
+    /// Include dev dependencies, with an excluded scope
+    #[clap(long = "dev-dependencies", overrides_with = "no_dev_dependencies")]
+    pub dev_dependencies: bool,
+
+    /// Do not include dev dependencies, overriding the configuration file
+    #[clap(long = "no-dev-dependencies", overrides_with = "dev_dependencies")]
+    pub no_dev_dependencies: bool,
+
+    /// Compute SHA-256 hashes over the source files of path and git dependencies
+    #[clap(long = "source-hashes", overrides_with = "no_source_hashes")]
+    pub source_hashes: bool,
+
+    /// Do not compute source hashes, overriding the configuration file
+    #[clap(long = "no-source-hashes", overrides_with = "source_hashes")]
+    pub no_source_hashes: bool,
+
+    /// Derive the serial number from the package and Cargo.lock, take the timestamp from
+    /// SOURCE_DATE_EPOCH and sort the output, so that the same sources produce the same BOM
+    #[clap(long = "reproducible", overrides_with = "no_reproducible")]
+    pub reproducible: bool,
+
+    /// Do not generate a reproducible BOM, overriding the configuration file
+    #[clap(long = "no-reproducible", overrides_with = "reproducible")]
+    pub no_reproducible: bool,
+
+    /// Write the BOM to this file instead of next to Cargo.toml, `-` writes to stdout
+    #[clap(
+        long = "output-file",
+        value_name = "PATH",
+        conflicts_with_all = &["output-dir", "output-cdx", "output-pattern", "output-prefix"]
+    )]
+    pub output_file: Option<path::PathBuf>,
+
+    /// Write the BOM files to this directory instead of next to Cargo.toml
+    #[clap(name = "output-dir", long = "output-dir", value_name = "DIR")]
+    pub output_dir: Option<path::PathBuf>,
+
     /// Prepend file extension with .cdx
-    #[clap(long = "output-cdx")]
+    #[clap(name = "output-cdx", long = "output-cdx")]
     pub output_cdx: bool,
 
     /// Prefix patterns to use for the filename: bom, package
@@ -67,6 +132,15 @@ pub struct Args {
 }
 
 impl Args {
+    pub fn output_destination(&self) -> OutputDestination {
+        match (&self.output_file, &self.output_dir) {
+            (Some(path), _) if path.as_os_str() == "-" => OutputDestination::Stdout,
+            (Some(path), _) => OutputDestination::File(path.clone()),
+            (_, Some(dir)) => OutputDestination::Directory(dir.clone()),
+            (_, _) => OutputDestination::Manifest,
+        }
+    }
+
     pub fn as_config(&self) -> Result<SbomConfig, ArgsError> {
         let included_dependencies = match (self.all, self.top_level) {
             (true, _) => Some(IncludedDependencies::AllDependencies),
@@ -83,6 +157,23 @@ impl Args {
             (_, _) => None,
         };
 
+        let features = if !self.features.is_empty() || self.all_features || self.no_default_features
+        {
+            Some(Features {
+                features: self.features.clone(),
+                all_features: self.all_features,
+                no_default_features: self.no_default_features,
+            })
+        } else {
+            None
+        };
+
+        let targets = if self.target.is_empty() {
+            None
+        } else {
+            Some(self.target.clone())
+        };
+
         let cdx_extension = match self.output_cdx {
             true => Some(CdxExtension::Included),
             false => None,
@@ -107,13 +198,69 @@ impl Args {
         Ok(SbomConfig {
             format: self.format,
             included_dependencies,
+            dependency_set: self.dependency_set,
+            features,
+            targets,
+            build_dependencies: flag(self.build_dependencies, self.no_build_dependencies),
+            dev_dependencies: flag(self.dev_dependencies, self.no_dev_dependencies),
+            source_hashes: flag(self.source_hashes, self.no_source_hashes),
+            reproducible: flag(self.reproducible, self.no_reproducible),
             output_options,
         })
     }
 }
 
+/// A flag that is only set when it is passed on the command line, either way
+fn flag(enabled: bool, disabled: bool) -> Option<bool> {
+    match (enabled, disabled) {
+        (true, _) => Some(true),
+        (_, true) => Some(false),
+        _ => None,
+    }
+}
+
+/// Where the generated BOMs are written to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputDestination {
+    /// Next to the `Cargo.toml` of each member
+    Manifest,
+    /// A single file
+    File(path::PathBuf),
+    /// Standard output
+    Stdout,
+    /// A directory, using the regular file names
+    Directory(path::PathBuf),
+}
+
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum ArgsError {
     #[error("Invalid prefix from CLI")]
     CustomPrefixError(#[from] PrefixError),
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_should_turn_off_flags_from_the_configuration_file() {
+        let parse = |args: &[&str]| {
+            let Opts::Bom(args) = Opts::parse_from([&["cargo", "cyclonedx"], args].concat());
+            args.as_config()
+                .expect("Failed to convert arguments")
+                .build_dependencies
+        };
+
+        assert_eq!(parse(&[]), None);
+        assert_eq!(parse(&["--build-dependencies"]), Some(true));
+        assert_eq!(parse(&["--no-build-dependencies"]), Some(false));
+        assert_eq!(
+            parse(&["--build-dependencies", "--no-build-dependencies"]),
+            Some(false)
+        );
+        assert_eq!(
+            parse(&["--no-build-dependencies", "--build-dependencies"]),
+            Some(true)
+        );
+    }
+}
@@ -21,10 +21,17 @@ use thiserror::Error;
  */
 use crate::format::Format;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SbomConfig {
     pub format: Option<Format>,
     pub included_dependencies: Option<IncludedDependencies>,
+    pub dependency_set: Option<DependencySet>,
+    pub features: Option<Features>,
+    pub targets: Option<Vec<String>>,
+    pub build_dependencies: Option<bool>,
+    pub dev_dependencies: Option<bool>,
+    pub source_hashes: Option<bool>,
+    pub reproducible: Option<bool>,
     pub output_options: Option<OutputOptions>,
 }
 
@@ -33,6 +40,13 @@ impl SbomConfig {
         Self {
             format: None,
             included_dependencies: None,
+            dependency_set: None,
+            features: None,
+            targets: None,
+            build_dependencies: None,
+            dev_dependencies: None,
+            source_hashes: None,
+            reproducible: None,
             output_options: None,
         }
     }
@@ -41,6 +55,13 @@ impl SbomConfig {
         SbomConfig {
             format: other.format.or(self.format),
             included_dependencies: other.included_dependencies.or(self.included_dependencies),
+            dependency_set: other.dependency_set.or(self.dependency_set),
+            features: other.features.clone().or_else(|| self.features.clone()),
+            targets: other.targets.clone().or_else(|| self.targets.clone()),
+            build_dependencies: other.build_dependencies.or(self.build_dependencies),
+            dev_dependencies: other.dev_dependencies.or(self.dev_dependencies),
+            source_hashes: other.source_hashes.or(self.source_hashes),
+            reproducible: other.reproducible.or(self.reproducible),
             output_options: other
                 .output_options
                 .clone()
@@ -56,6 +77,37 @@ impl SbomConfig {
         self.included_dependencies.unwrap_or_default()
     }
 
+    pub fn dependency_set(&self) -> DependencySet {
+        self.dependency_set.unwrap_or_default()
+    }
+
+    pub fn features(&self) -> Features {
+        self.features.clone().unwrap_or_default()
+    }
+
+    /// Target triples to generate a BOM for, an empty list means all targets
+    pub fn targets(&self) -> Vec<String> {
+        self.targets.clone().unwrap_or_default()
+    }
+
+    pub fn build_dependencies(&self) -> bool {
+        self.build_dependencies.unwrap_or_default()
+    }
+
+    pub fn dev_dependencies(&self) -> bool {
+        self.dev_dependencies.unwrap_or_default()
+    }
+
+    pub fn source_hashes(&self) -> bool {
+        self.source_hashes.unwrap_or_default()
+    }
+
+    /// Derive the serial number and timestamp from the inputs and sort the output, so that the
+    /// same sources always produce the same bytes
+    pub fn reproducible(&self) -> bool {
+        self.reproducible.unwrap_or_default()
+    }
+
     pub fn output_options(&self) -> OutputOptions {
         self.output_options.clone().unwrap_or_default()
     }
@@ -85,6 +137,40 @@ impl FromStr for IncludedDependencies {
     }
 }
 
+/// Which workspace members the dependencies of a BOM are collected from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DependencySet {
+    /// Only the dependencies reachable from the member the BOM is generated for
+    #[default]
+    Member,
+    /// The dependencies of every member in the workspace
+    Workspace,
+}
+
+impl FromStr for DependencySet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "member" => Ok(Self::Member),
+            "workspace" => Ok(Self::Workspace),
+            _ => Err(format!("Expected member or workspace, got `{}`", s)),
+        }
+    }
+}
+
+/// Cargo features used when resolving the dependencies of a package
+///
+/// * `features` - Features to activate, separated by commas or spaces
+/// * `all_features` - Activate all available features
+/// * `no_default_features` - Do not activate the `default` feature
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Features {
+    pub features: Vec<String>,
+    pub all_features: bool,
+    pub no_default_features: bool,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OutputOptions {
     pub cdx_extension: CdxExtension,
@@ -25,6 +25,21 @@ use std::{fmt, str::FromStr};
 pub enum Format {
     Json,
     Xml,
+    #[cfg(feature = "protobuf")]
+    Protobuf,
+}
+
+impl Format {
+    /// The file extension of SBOMs written in this format, following the CycloneDX convention of
+    /// `.bin` for Protocol Buffers
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Xml => "xml",
+            #[cfg(feature = "protobuf")]
+            Format::Protobuf => "bin",
+        }
+    }
 }
 
 impl Default for Format {
@@ -38,6 +53,8 @@ impl fmt::Display for Format {
         match self {
             Format::Json => "json".fmt(f),
             Format::Xml => "xml".fmt(f),
+            #[cfg(feature = "protobuf")]
+            Format::Protobuf => "protobuf".fmt(f),
         }
     }
 }
@@ -49,7 +66,14 @@ impl FromStr for Format {
         match s {
             "xml" => Ok(Self::Xml),
             "json" => Ok(Self::Json),
-            _ => Err(format!("Expected xml or json, got `{}`", s)),
+            #[cfg(feature = "protobuf")]
+            "protobuf" => Ok(Self::Protobuf),
+            #[cfg(not(feature = "protobuf"))]
+            "protobuf" => Err(
+                "Protocol Buffers output requires cargo-cyclonedx to be built with the `protobuf` feature"
+                    .to_string(),
+            ),
+            _ => Err(format!("Expected xml, json or protobuf, got `{}`", s)),
         }
     }
 }
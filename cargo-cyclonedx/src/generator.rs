@@ -15,6 +15,7 @@
  *
  * SPDX-License-Identifier: Apache-2.0
  */
+use crate::config::DependencySet;
 use crate::config::IncludedDependencies;
 use crate::config::Pattern;
 use crate::config::Prefix;
@@ -22,33 +23,58 @@ use crate::config::SbomConfig;
 use crate::format::Format;
 use crate::toml::config_from_toml;
 use crate::toml::ConfigError;
+use cargo::core::compiler::{CompileKind, CompileTarget, RustcTargetData};
 use cargo::core::dependency::DepKind;
+use cargo::core::resolver::features::{
+    CliFeatures, FeaturesFor, ForceAllTargets, HasDevUnits, ResolvedFeatures,
+};
+use cargo::core::Dependency as CargoDependency;
+use cargo::core::FeatureMap;
+use cargo::core::FeatureValue;
 use cargo::core::Package;
+use cargo::core::PackageId;
+use cargo::core::PackageIdSpec;
 use cargo::core::PackageSet;
 use cargo::core::Resolve;
+use cargo::core::SourceId;
 use cargo::core::Workspace;
 use cargo::ops;
+use cargo::ops::WorkspaceResolve;
+use cargo::sources::PathSource;
+use cargo::util::interning::InternedString;
+use cargo::Config;
+use cargo_util::Sha256;
 
+use cyclonedx_bom::external_models::date_time::DateTime;
 use cyclonedx_bom::external_models::normalized_string::NormalizedString;
 use cyclonedx_bom::external_models::spdx::SpdxExpression;
-use cyclonedx_bom::external_models::uri::{Purl, Uri};
-use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::external_models::uri::{Purl, Uri, UriError};
+use cyclonedx_bom::models::bom::{Bom, UrnUuid};
 use cyclonedx_bom::models::component::{Classification, Component, Components, Scope};
+use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
 use cyclonedx_bom::models::external_reference::{
     ExternalReference, ExternalReferenceType, ExternalReferences,
 };
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
 use cyclonedx_bom::models::license::{License, LicenseChoice, Licenses};
 use cyclonedx_bom::models::metadata::Metadata;
 use cyclonedx_bom::models::metadata::MetadataError;
 use cyclonedx_bom::models::organization::OrganizationalContact;
+use cyclonedx_bom::models::property::{Properties, Property};
 use cyclonedx_bom::models::tool::{Tool, Tools};
 use cyclonedx_bom::validation::Validate;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
 use std::convert::TryFrom;
-use std::{collections::BTreeSet, fs::File, path::PathBuf};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
 use thiserror::Error;
+use uuid::Uuid;
 use validator::validate_email;
 
 pub struct SbomGenerator {}
@@ -65,12 +91,6 @@ impl SbomGenerator {
         let workspace_config = config_from_toml(ws.custom_metadata())?;
         let members: Vec<Package> = ws.members().cloned().collect();
 
-        let (package_ids, resolve) =
-            ops::resolve_ws(&ws).map_err(|error| GeneratorError::CargoConfigError {
-                config_filepath: ws.root_manifest().to_string_lossy().to_string(),
-                error,
-            })?;
-
         let mut result = Vec::with_capacity(members.len());
         for member in members.iter() {
             log::trace!(
@@ -87,53 +107,619 @@ impl SbomGenerator {
             log::trace!("Config from config override: {:?}", config_override);
             log::debug!("Config from merged config: {:?}", config);
 
-            let dependencies =
-                if config.included_dependencies() == IncludedDependencies::AllDependencies {
-                    all_dependencies(&members, &package_ids, &resolve)?
+            let roots = match config.dependency_set() {
+                DependencySet::Member => std::slice::from_ref(member),
+                DependencySet::Workspace => &members[..],
+            };
+
+            let to_config_error = |error| GeneratorError::CargoConfigError {
+                config_filepath: member.manifest_path().to_string_lossy().to_string(),
+                error,
+            };
+
+            let targets = config.targets();
+            let target_kinds = targets
+                .iter()
+                .map(|target| CompileTarget::new(target).map(CompileKind::Target))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(to_config_error)?;
+
+            let requested_kinds = if target_kinds.is_empty() {
+                vec![CompileKind::Host]
+            } else {
+                target_kinds.clone()
+            };
+            let target_data =
+                RustcTargetData::new(&ws, &requested_kinds).map_err(to_config_error)?;
+
+            let filters: Vec<(Option<String>, CompileKind, DependencyFilter)> =
+                if target_kinds.is_empty() {
+                    let filter = DependencyFilter::new(&target_data, None, &config);
+                    vec![(None, CompileKind::Host, filter)]
                 } else {
-                    top_level_dependencies(&members, &package_ids)?
+                    targets
+                        .iter()
+                        .zip(target_kinds)
+                        .map(|(target, kind)| {
+                            let filter = DependencyFilter::new(&target_data, Some(kind), &config);
+                            (Some(target.clone()), kind, filter)
+                        })
+                        .collect()
                 };
 
-            let bom = create_bom(member, dependencies)?;
+            for (target, kind, filter) in filters {
+                let workspace_resolve =
+                    resolve_workspace(&ws, &target_data, kind, target.is_none(), roots, &config)?;
+                let WorkspaceResolve {
+                    pkg_set: package_ids,
+                    targeted_resolve: resolve,
+                    ..
+                } = &workspace_resolve;
+
+                let dependencies =
+                    if config.included_dependencies() == IncludedDependencies::AllDependencies {
+                        all_dependencies(roots, package_ids, resolve, filter)?
+                    } else {
+                        top_level_dependencies(roots, package_ids, resolve, filter)?
+                    };
+
+                let usages = dependency_usages(roots, resolve, filter);
+
+                let hashes =
+                    package_hashes(&dependencies, resolve, ws.config(), config.source_hashes())?;
+
+                let mut bom = create_bom(
+                    member,
+                    dependencies,
+                    &workspace_resolve,
+                    filter,
+                    &usages,
+                    &hashes,
+                    ws.root(),
+                )?;
+
+                if config.reproducible() {
+                    make_reproducible(&mut bom, member, target.as_deref(), &ws)?;
+                }
 
-            log::debug!("Bom validation: {:?}", &bom.validate());
+                log::debug!("Bom validation: {:?}", &bom.validate());
 
-            let generated = GeneratedSbom {
-                bom,
-                manifest_path: member.manifest_path().to_path_buf(),
-                package_name: member.name().to_string(),
-                sbom_config: config,
-            };
+                let generated = GeneratedSbom {
+                    bom,
+                    manifest_path: member.manifest_path().to_path_buf(),
+                    package_name: member.name().to_string(),
+                    target,
+                    sbom_config: config.clone(),
+                };
 
-            result.push(generated);
+                result.push(generated);
+            }
         }
 
         Ok(result)
     }
 }
 
-fn create_bom(package: &Package, dependencies: BTreeSet<Package>) -> Result<Bom, GeneratorError> {
+/// Resolves the dependencies of `roots` with the requested cargo features activated
+///
+/// The features of every package are resolved for a single compilation target, the way cargo
+/// resolves them for a build, so dev dependencies only contribute features when they are
+/// included in the BOM. Without an explicit target the dependencies of every platform are
+/// resolved, matching the BOM that lists them.
+fn resolve_workspace<'cfg>(
+    ws: &Workspace<'cfg>,
+    target_data: &RustcTargetData<'cfg>,
+    kind: CompileKind,
+    all_targets: bool,
+    roots: &[Package],
+    config: &SbomConfig,
+) -> Result<WorkspaceResolve<'cfg>, GeneratorError> {
+    let to_config_error = |error| GeneratorError::CargoConfigError {
+        config_filepath: ws.root_manifest().to_string_lossy().to_string(),
+        error,
+    };
+
+    let features = config.features();
+
+    let cli_features = CliFeatures::from_command_line(
+        &features.features,
+        features.all_features,
+        !features.no_default_features,
+    )
+    .map_err(to_config_error)?;
+
+    let specs: Vec<PackageIdSpec> = roots
+        .iter()
+        .map(|package| PackageIdSpec::from_package_id(package.package_id()))
+        .collect();
+
+    let has_dev_units = if config.dev_dependencies() {
+        HasDevUnits::Yes
+    } else {
+        HasDevUnits::No
+    };
+    let force_all_targets = if all_targets {
+        ForceAllTargets::Yes
+    } else {
+        ForceAllTargets::No
+    };
+
+    ops::resolve_ws_with_opts(
+        ws,
+        target_data,
+        &[kind],
+        &cli_features,
+        &specs,
+        has_dev_units,
+        force_all_targets,
+    )
+    .map_err(to_config_error)
+}
+
+/// Restricts the dependencies in a `Resolve` to the included dependency kinds that are activated
+/// on a compilation target
+///
+/// Platform specific dependencies are matched against the `cfg` values of the target, so that
+/// for example `[target.'cfg(windows)'.dependencies]` are left out for Linux targets. Build and
+/// dev dependencies are only kept when they were requested in the configuration.
+#[derive(Clone, Copy)]
+struct DependencyFilter<'a, 'cfg> {
+    target_data: &'a RustcTargetData<'cfg>,
+    kind: Option<CompileKind>,
+    build_dependencies: bool,
+    dev_dependencies: bool,
+}
+
+impl<'a, 'cfg> DependencyFilter<'a, 'cfg> {
+    fn new(
+        target_data: &'a RustcTargetData<'cfg>,
+        kind: Option<CompileKind>,
+        config: &SbomConfig,
+    ) -> Self {
+        Self {
+            target_data,
+            kind,
+            build_dependencies: config.build_dependencies(),
+            dev_dependencies: config.dev_dependencies(),
+        }
+    }
+
+    fn is_activated(&self, dependency: &CargoDependency) -> bool {
+        let kind_included = match dependency.kind() {
+            DepKind::Normal => true,
+            DepKind::Build => self.build_dependencies,
+            DepKind::Development => self.dev_dependencies,
+        };
+
+        kind_included
+            && match self.kind {
+                Some(kind) => self.target_data.dep_platform_activated(dependency, kind),
+                None => true,
+            }
+    }
+
+    /// Lists the direct dependencies of a package together with their declarations that are
+    /// included and activated on the target
+    fn deps<'r>(
+        &self,
+        resolve: &'r Resolve,
+        package_id: PackageId,
+    ) -> Vec<(PackageId, Vec<&'r CargoDependency>)> {
+        resolve
+            .deps(package_id)
+            .filter_map(|(dependency_id, dependencies)| {
+                let activated: Vec<_> = dependencies
+                    .iter()
+                    .filter(|dependency| self.is_activated(dependency))
+                    .collect();
+
+                if activated.is_empty() {
+                    None
+                } else {
+                    Some((dependency_id, activated))
+                }
+            })
+            .collect()
+    }
+}
+
+/// How a package is used by the packages a BOM is generated for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DependencyUsage {
+    /// Needed at runtime through non-optional normal dependencies
+    Required,
+    /// Needed at runtime, but only because a feature enabled an optional dependency
+    Optional,
+    /// Only needed to build the packages, e.g. by build scripts
+    Build,
+    /// Only needed to build and run tests, examples and benchmarks
+    Development,
+}
+
+impl DependencyUsage {
+    fn scope(&self) -> Scope {
+        match self {
+            DependencyUsage::Required => Scope::Required,
+            DependencyUsage::Optional => Scope::Optional,
+            DependencyUsage::Build | DependencyUsage::Development => Scope::Excluded,
+        }
+    }
+
+    /// The value of the `cdx:cargo:dependency_kind` property for packages that are not needed at
+    /// runtime
+    fn dependency_kind(&self) -> Option<&'static str> {
+        match self {
+            DependencyUsage::Required | DependencyUsage::Optional => None,
+            DependencyUsage::Build => Some("build"),
+            DependencyUsage::Development => Some("dev"),
+        }
+    }
+}
+
+/// Classifies every package reachable from `roots` by the way it is used
+///
+/// A package that is used in several ways is classified by the one closest to runtime, so a crate
+/// that is both a normal and a build dependency is `Required`.
+fn dependency_usages(
+    roots: &[Package],
+    resolve: &Resolve,
+    filter: DependencyFilter,
+) -> BTreeMap<PackageId, DependencyUsage> {
+    let required = reachable_packages(roots, resolve, filter, |dependency| {
+        dependency.kind() == DepKind::Normal && !dependency.is_optional()
+    });
+    let runtime = reachable_packages(roots, resolve, filter, |dependency| {
+        dependency.kind() == DepKind::Normal
+    });
+    let build = reachable_packages(roots, resolve, filter, |dependency| {
+        dependency.kind() != DepKind::Development
+    });
+    let all = reachable_packages(roots, resolve, filter, |_| true);
+
+    all.into_iter()
+        .map(|package_id| {
+            let usage = if required.contains(&package_id) {
+                DependencyUsage::Required
+            } else if runtime.contains(&package_id) {
+                DependencyUsage::Optional
+            } else if build.contains(&package_id) {
+                DependencyUsage::Build
+            } else {
+                DependencyUsage::Development
+            };
+            (package_id, usage)
+        })
+        .collect()
+}
+
+/// Finds every package reachable from `roots` through dependency declarations that satisfy
+/// `predicate`, including the roots themselves
+fn reachable_packages(
+    roots: &[Package],
+    resolve: &Resolve,
+    filter: DependencyFilter,
+    predicate: impl Fn(&CargoDependency) -> bool,
+) -> BTreeSet<PackageId> {
+    let mut reachable: BTreeSet<PackageId> = roots.iter().map(Package::package_id).collect();
+    let mut queue: Vec<PackageId> = reachable.iter().copied().collect();
+    while let Some(package_id) = queue.pop() {
+        for (dependency_id, dependencies) in filter.deps(resolve, package_id) {
+            if dependencies.iter().any(|dependency| predicate(dependency))
+                && reachable.insert(dependency_id)
+            {
+                queue.push(dependency_id);
+            }
+        }
+    }
+
+    reachable
+}
+
+fn create_bom(
+    package: &Package,
+    dependencies: BTreeSet<Package>,
+    workspace_resolve: &WorkspaceResolve,
+    filter: DependencyFilter,
+    usages: &BTreeMap<PackageId, DependencyUsage>,
+    hashes: &BTreeMap<PackageId, String>,
+    workspace_root: &Path,
+) -> Result<Bom, GeneratorError> {
+    let resolve = &workspace_resolve.targeted_resolve;
+    let resolved_features = &workspace_resolve.resolved_features;
+
     let mut bom = Bom::default();
 
+    let mut metadata = create_metadata(package, workspace_root)?;
+
+    let enabled_by = features_enabling_dependencies(resolve, resolved_features, usages, filter);
+
+    if let Some(component) = metadata.component.as_mut() {
+        let features = activated_features(
+            resolved_features,
+            package.package_id(),
+            DependencyUsage::Required,
+        );
+        component.properties = get_properties(package.package_id(), &features, &enabled_by, None);
+    }
+
+    let mut bom_refs = BTreeMap::new();
+    if let Some(bom_ref) = metadata.component.as_ref().and_then(|c| c.bom_ref.clone()) {
+        bom_refs.insert(package.package_id(), bom_ref);
+    }
+
     let components: Vec<_> = dependencies
-        .into_iter()
-        .map(|package| create_component(&package))
+        .iter()
+        .map(|package| {
+            let usage = usages
+                .get(&package.package_id())
+                .copied()
+                .unwrap_or(DependencyUsage::Required);
+
+            let mut component = create_component(package, workspace_root);
+            component.scope = Some(usage.scope());
+            component.hashes = hashes.get(&package.package_id()).map(|hash| {
+                Hashes(vec![Hash {
+                    alg: HashAlgorithm::SHA256,
+                    content: HashValue::new(hash),
+                }])
+            });
+            let features = activated_features(resolved_features, package.package_id(), usage);
+            component.properties =
+                get_properties(package.package_id(), &features, &enabled_by, Some(usage));
+            if let Some(bom_ref) = &component.bom_ref {
+                bom_refs.insert(package.package_id(), bom_ref.clone());
+            }
+            component
+        })
         .collect();
 
     bom.components = Some(Components(components));
 
-    let metadata = create_metadata(package)?;
-
     bom.metadata = Some(metadata);
 
+    bom.dependencies = Some(create_dependencies(&bom_refs, resolve, filter));
+
     Ok(bom)
 }
 
-fn create_component(package: &Package) -> Component {
+/// Replaces the random serial number and the current time in the BOM with values derived from
+/// its inputs
+///
+/// The serial number is a name-based UUID over the name and version of the package, the target
+/// and the contents of `Cargo.lock`. The timestamp is taken from `SOURCE_DATE_EPOCH`, or left out
+/// when it is not set.
+fn make_reproducible(
+    bom: &mut Bom,
+    package: &Package,
+    target: Option<&str>,
+    ws: &Workspace,
+) -> Result<(), GeneratorError> {
+    let lockfile_path = ws.root().join("Cargo.lock");
+    let lockfile =
+        std::fs::read(&lockfile_path).map_err(|error| GeneratorError::LockfileError {
+            lockfile_path: lockfile_path.to_string_lossy().to_string(),
+            error,
+        })?;
+
+    let mut name = format!(
+        "{}@{}\n{}\n",
+        package.name(),
+        package.version(),
+        target.unwrap_or_default()
+    )
+    .into_bytes();
+    name.extend_from_slice(&lockfile);
+    bom.serial_number = Some(UrnUuid::new_v5(&SERIAL_NUMBER_NAMESPACE, &name));
+
+    let timestamp = match std::env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) => {
+            let seconds = epoch
+                .trim()
+                .parse()
+                .map_err(|_| GeneratorError::SourceDateEpochError(epoch.clone()))?;
+            let timestamp = DateTime::from_unix_timestamp(seconds)
+                .map_err(|_| GeneratorError::SourceDateEpochError(epoch.clone()))?;
+            Some(timestamp)
+        }
+        Err(_) => None,
+    };
+    if let Some(metadata) = bom.metadata.as_mut() {
+        metadata.timestamp = timestamp;
+    }
+
+    Ok(())
+}
+
+/// Namespace of the name-based serial numbers of reproducible BOMs
+const SERIAL_NUMBER_NAMESPACE: Uuid = Uuid::from_u128(0x5d3c_7e0b_9a41_4c6f_8f1e_2b7a_d04c_93e6);
+
+/// Creates the dependency graph of the BOM from the cargo `Resolve`
+///
+/// Every package with a bom-ref gets an entry listing its direct dependencies, restricted to the
+/// packages that are present in the BOM so that every reference can be resolved.
+fn create_dependencies(
+    bom_refs: &BTreeMap<PackageId, String>,
+    resolve: &Resolve,
+    filter: DependencyFilter,
+) -> Dependencies {
+    let dependencies = bom_refs
+        .iter()
+        .map(|(package_id, bom_ref)| {
+            let depends_on: BTreeSet<String> = filter
+                .deps(resolve, *package_id)
+                .into_iter()
+                .filter_map(|(dependency_id, _)| bom_refs.get(&dependency_id).cloned())
+                .collect();
+
+            Dependency {
+                dependency_ref: bom_ref.clone(),
+                dependencies: depends_on.into_iter().collect(),
+            }
+        })
+        .collect();
+
+    Dependencies(dependencies)
+}
+
+/// Collects the hex encoded SHA-256 hashes of `packages`
+///
+/// Registry packages use the checksum of their `.crate` file recorded in `Cargo.lock`. When
+/// `source_hashes` is enabled, path and git packages get a hash computed over their source files.
+fn package_hashes(
+    packages: &BTreeSet<Package>,
+    resolve: &Resolve,
+    config: &Config,
+    source_hashes: bool,
+) -> Result<BTreeMap<PackageId, String>, GeneratorError> {
+    let mut hashes = BTreeMap::new();
+
+    for package in packages {
+        let package_id = package.package_id();
+        let source_id = package_id.source_id();
+
+        let hash = if source_id.is_registry() {
+            resolve
+                .checksums()
+                .get(&package_id)
+                .cloned()
+                .flatten()
+                .or_else(|| package.summary().checksum().map(str::to_string))
+        } else if source_hashes && (source_id.is_path() || source_id.is_git()) {
+            let hash = source_tree_hash(package, config)
+                .map_err(|error| GeneratorError::PackageError { package_id, error })?;
+            Some(hash)
+        } else {
+            None
+        };
+
+        match hash {
+            Some(hash) => {
+                hashes.insert(package_id, hash);
+            }
+            None => log::trace!("Package {} has no hash", package.name()),
+        }
+    }
+
+    Ok(hashes)
+}
+
+/// Computes a SHA-256 hash over the files `cargo package` would include for a package
+///
+/// The files are hashed in order of their path relative to the package root, each contributing
+/// its `/` separated relative path, a NUL byte and its contents.
+fn source_tree_hash(package: &Package, config: &Config) -> anyhow::Result<String> {
+    let source = PathSource::new(package.root(), package.package_id().source_id(), config);
+
+    let mut files: Vec<(String, PathBuf)> = source
+        .list_files(package)?
+        .into_iter()
+        .map(|file| {
+            let relative_path = file
+                .strip_prefix(package.root())
+                .unwrap_or(&file)
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            (relative_path, file)
+        })
+        .collect();
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for (relative_path, file) in files {
+        hasher.update(relative_path.as_bytes());
+        hasher.update(&[0]);
+        hasher.update_path(file)?;
+    }
+
+    Ok(hasher.finish_hex())
+}
+
+/// Creates the bom-ref of a package, using the same format as the package ids of `cargo metadata`
+///
+/// Unlike the purl, this includes the full source of the package, e.g. the git reference, so it is
+/// unique for every package in the resolve while staying stable across runs. Path sources are
+/// relative to the workspace root, e.g. `proj 0.1.0 (path+file:///./crates/proj)`, so that the
+/// same sources get the same bom-refs wherever they are checked out.
+fn get_bom_ref(package_id: PackageId, workspace_root: &Path) -> String {
+    let source_id = package_id.source_id();
+    let source = match source_id.url().to_file_path() {
+        Ok(path) if source_id.is_path() => {
+            format!("path+file:///{}", relative_path(&path, workspace_root))
+        }
+        _ => source_id.as_url().to_string(),
+    };
+
+    format!(
+        "{} {} ({})",
+        package_id.name(),
+        package_id.version(),
+        source
+    )
+}
+
+/// Formats `path` relative to `base` with `/` separators, e.g. `./crates/proj` or `../other`
+fn relative_path(path: &Path, base: &Path) -> String {
+    let path: Vec<_> = path.components().collect();
+    let base: Vec<_> = base.components().collect();
+    let common = path
+        .iter()
+        .zip(&base)
+        .take_while(|(left, right)| left == right)
+        .count();
+
+    let mut components = vec![if common == base.len() { "." } else { ".." }.to_string()];
+    components.extend(base[common..].iter().skip(1).map(|_| "..".to_string()));
+    components.extend(
+        path[common..]
+            .iter()
+            .map(|component| component.as_os_str().to_string_lossy().to_string()),
+    );
+    components.join("/")
+}
+
+/// Creates the package URL of a package, encoding where it comes from
+///
+/// crates.io is the default repository of the `cargo` purl type, so its crates only get the
+/// `download_url` of their `.crate` file. Crates from alternate registries get a `repository_url`,
+/// as their download location is configured in the registry index, and git sources a `vcs_url`
+/// pinned to the locked commit. Path sources have no location that others could download them
+/// from, so they get no qualifiers, which also keeps local paths out of the purl.
+fn get_purl(source_id: SourceId, name: &str, version: &str) -> Result<Purl, UriError> {
+    let qualifier = if source_id.is_default_registry() {
+        Some((
+            "download_url",
+            format!("{}/{}/{}/download", CRATES_IO_DOWNLOAD_URL, name, version),
+        ))
+    } else if source_id.is_registry() {
+        Some(("repository_url", source_id.url().to_string()))
+    } else if source_id.is_git() {
+        let vcs_url = match source_id.precise() {
+            Some(commit) => format!("git+{}@{}", source_id.url(), commit),
+            None => format!("git+{}", source_id.url()),
+        };
+        Some(("vcs_url", vcs_url))
+    } else {
+        None
+    };
+
+    let qualifiers: Vec<(&str, &str)> = qualifier
+        .iter()
+        .map(|(key, value)| (*key, value.as_str()))
+        .collect();
+
+    Purl::from_parts("cargo", None, name, version, &qualifiers, None)
+}
+
+/// Base URL of the crates.io API endpoint that downloads `.crate` files
+const CRATES_IO_DOWNLOAD_URL: &str = "https://crates.io/api/v1/crates";
+
+fn create_component(package: &Package, workspace_root: &Path) -> Component {
     let name = package.name().to_owned().trim().to_string();
     let version = package.version().to_string();
 
-    let purl = match Purl::new("cargo", &name, &version) {
+    let purl = match get_purl(package.package_id().source_id(), &name, &version) {
         Ok(purl) => Some(purl),
         Err(e) => {
             log::error!("Package {} has an invalid Purl: {} ", package.name(), e);
@@ -145,7 +731,7 @@ fn create_component(package: &Package) -> Component {
         Classification::Library,
         &name,
         &version,
-        purl.clone().map(|p| p.to_string()),
+        Some(get_bom_ref(package.package_id(), workspace_root)),
     );
 
     component.purl = purl;
@@ -163,6 +749,121 @@ fn create_component(package: &Package) -> Component {
     component
 }
 
+/// Lists the active features of a package, the features of other packages that caused it to be
+/// included as an optional dependency, and the kind of dependency for packages not needed at
+/// runtime
+fn get_properties(
+    package_id: PackageId,
+    features: &[InternedString],
+    enabled_by: &BTreeMap<PackageId, BTreeSet<String>>,
+    usage: Option<DependencyUsage>,
+) -> Option<Properties> {
+    let mut properties: Vec<Property> = features
+        .iter()
+        .map(|feature| Property::new("cdx:cargo:feature", feature))
+        .collect();
+
+    if let Some(enabled_by) = enabled_by.get(&package_id) {
+        properties.extend(
+            enabled_by
+                .iter()
+                .map(|feature| Property::new("cdx:cargo:enabled_by", feature)),
+        );
+    }
+
+    if let Some(dependency_kind) = usage.and_then(|usage| usage.dependency_kind()) {
+        properties.push(Property::new("cdx:cargo:dependency_kind", dependency_kind));
+    }
+
+    if properties.is_empty() {
+        return None;
+    }
+
+    Some(Properties(properties))
+}
+
+/// Lists the features of a package that are activated when building it for the way it is used
+///
+/// Packages that are only used to build others get the features of host dependencies. Proc
+/// macros are always built for the host, so they fall back to those as well.
+fn activated_features(
+    resolved_features: &ResolvedFeatures,
+    package_id: PackageId,
+    usage: DependencyUsage,
+) -> Vec<InternedString> {
+    let features_for = match usage {
+        DependencyUsage::Build => FeaturesFor::HostDep,
+        _ => FeaturesFor::default(),
+    };
+
+    resolved_features
+        .activated_features_unverified(package_id, features_for)
+        .or_else(|| {
+            resolved_features.activated_features_unverified(package_id, FeaturesFor::HostDep)
+        })
+        .unwrap_or_default()
+}
+
+/// Finds, for every optional dependency in the `Resolve`, the active features of the depending
+/// packages that enabled it, formatted as `package/feature`
+fn features_enabling_dependencies(
+    resolve: &Resolve,
+    resolved_features: &ResolvedFeatures,
+    usages: &BTreeMap<PackageId, DependencyUsage>,
+    filter: DependencyFilter,
+) -> BTreeMap<PackageId, BTreeSet<String>> {
+    let mut enabled_by: BTreeMap<PackageId, BTreeSet<String>> = BTreeMap::new();
+
+    for (parent_id, usage) in usages {
+        let parent_id = *parent_id;
+        let feature_map = resolve.summary(parent_id).features();
+        let active_features = activated_features(resolved_features, parent_id, *usage);
+
+        for (dependency_id, dependencies) in filter.deps(resolve, parent_id) {
+            for dependency in dependencies.iter().filter(|d| d.is_optional()) {
+                let dep_name = dependency.name_in_toml();
+
+                let enabling_features = active_features.iter().filter(|feature| {
+                    feature_enables_dependency(feature_map, feature, dep_name, &mut BTreeSet::new())
+                });
+
+                enabled_by.entry(dependency_id).or_default().extend(
+                    enabling_features.map(|feature| format!("{}/{}", parent_id.name(), feature)),
+                );
+            }
+        }
+    }
+
+    enabled_by
+}
+
+/// Checks whether a feature enables an optional dependency, either directly or through the other
+/// features it enables
+fn feature_enables_dependency(
+    feature_map: &FeatureMap,
+    feature: &InternedString,
+    dep_name: InternedString,
+    visited: &mut BTreeSet<InternedString>,
+) -> bool {
+    if !visited.insert(*feature) {
+        return false;
+    }
+
+    feature_map.get(feature).map_or(false, |values| {
+        values.iter().any(|value| match value {
+            FeatureValue::Dep { dep_name: name } => *name == dep_name,
+            FeatureValue::DepFeature {
+                dep_name: name,
+                weak,
+                ..
+            } => !weak && *name == dep_name,
+            FeatureValue::Feature(name) => {
+                feature_enables_dependency(feature_map, name, dep_name, visited)
+            }
+        })
+    })
+}
+
 fn get_classification(pkg: &Package) -> Classification {
     if pkg.targets().iter().any(|tgt| tgt.is_bin()) {
         return Classification::Application;
@@ -273,7 +974,7 @@ fn get_licenses(package: &Package) -> Option<Licenses> {
     Some(Licenses(licenses))
 }
 
-fn create_metadata(package: &Package) -> Result<Metadata, GeneratorError> {
+fn create_metadata(package: &Package, workspace_root: &Path) -> Result<Metadata, GeneratorError> {
     let authors = create_authors(package);
 
     let mut metadata = Metadata::new()?;
@@ -281,7 +982,7 @@ fn create_metadata(package: &Package) -> Result<Metadata, GeneratorError> {
         metadata.authors = Some(authors);
     }
 
-    let mut component = create_component(package);
+    let mut component = create_component(package, workspace_root);
 
     component.component_type = get_classification(package);
 
@@ -289,7 +990,7 @@ fn create_metadata(package: &Package) -> Result<Metadata, GeneratorError> {
 
     let tool = Tool::new("CycloneDX", "cargo-cyclonedx", env!("CARGO_PKG_VERSION"));
 
-    metadata.tools = Some(Tools(vec![tool]));
+    metadata.tools = Some(Tools::List(vec![tool]));
 
     Ok(metadata)
 }
@@ -369,53 +1070,66 @@ pub enum GeneratorError {
 
     #[error("Invalid regular expression")]
     InvalidRegexError(#[source] regex::Error),
+
+    #[error("Could not read the lockfile: {lockfile_path}")]
+    LockfileError {
+        lockfile_path: String,
+        #[source]
+        error: std::io::Error,
+    },
+
+    #[error("SOURCE_DATE_EPOCH is not a valid number of seconds since the Unix epoch: {0}")]
+    SourceDateEpochError(String),
 }
 
+/// Collects the direct dependencies of `roots`, leaving out the `roots` themselves
+///
+/// Other workspace members that the roots depend on are kept, so that the dependencies they bring
+/// in are connected to the rest of the graph.
 fn top_level_dependencies(
-    members: &[Package],
+    roots: &[Package],
     package_ids: &PackageSet<'_>,
+    resolve: &Resolve,
+    filter: DependencyFilter,
 ) -> Result<BTreeSet<Package>, GeneratorError> {
     log::trace!("Adding top-level dependencies to SBOM");
     let mut dependencies = BTreeSet::new();
 
-    let all_dependencies = members
+    let all_dependencies = roots
         .iter()
-        .flat_map(|m| m.dependencies().iter())
-        .filter(|d| d.kind() == DepKind::Normal);
-    for dependency in all_dependencies {
-        if let Some(package_id) = package_ids
-            .package_ids()
-            .find(|id| dependency.matches_id(*id))
-        {
-            let package = package_ids
-                .get_one(package_id)
-                .map_err(|error| GeneratorError::PackageError { package_id, error })?;
-            dependencies.insert(package.to_owned());
-        }
+        .flat_map(|m| filter.deps(resolve, m.package_id()));
+    for (package_id, _) in all_dependencies {
+        let package = package_ids
+            .get_one(package_id)
+            .map_err(|error| GeneratorError::PackageError { package_id, error })?;
+        dependencies.insert(package.to_owned());
     }
 
-    // Filter out our own workspace crates from dependency list
-    for member in members {
-        dependencies.remove(member);
+    // Filter out the packages the BOM is generated for from the dependency list
+    for root in roots {
+        dependencies.remove(root);
     }
 
     Ok(dependencies)
 }
 
+/// Collects every package reachable from `roots` through the `Resolve`, leaving out the `roots`
+/// themselves
 fn all_dependencies(
-    members: &[Package],
+    roots: &[Package],
     package_ids: &PackageSet<'_>,
     resolve: &Resolve,
+    filter: DependencyFilter,
 ) -> Result<BTreeSet<Package>, GeneratorError> {
     log::trace!("Adding all dependencies to SBOM");
     let mut dependencies = BTreeSet::new();
 
-    for package_id in resolve.iter() {
+    for package_id in reachable_packages(roots, resolve, filter, |_| true) {
         let package = package_ids
             .get_one(package_id)
             .map_err(|error| GeneratorError::PackageError { package_id, error })?;
-        if members.contains(package) {
-            // Skip listing our own packages in our workspace
+        if roots.contains(package) {
+            // Skip listing the packages the BOM is generated for
             continue;
         }
         dependencies.insert(package.to_owned());
@@ -429,37 +1143,60 @@ fn all_dependencies(
 /// * `bom` - Generated SBOM
 /// * `manifest_path` - Folder containing the `Cargo.toml` manifest
 /// * `package_name` - Package from which this SBOM was generated
+/// * `target` - Target triple the dependencies were filtered for, if any
 /// * `sbom_config` - Configuration options used during generation
 pub struct GeneratedSbom {
     pub bom: Bom,
     pub manifest_path: PathBuf,
     pub package_name: String,
+    pub target: Option<String>,
     pub sbom_config: SbomConfig,
 }
 
 impl GeneratedSbom {
-    /// Writes SBOM to either a JSON or XML file in the same folder as `Cargo.toml` manifest
+    /// Writes SBOM to either a JSON, XML or Protocol Buffers file in the same folder as `Cargo.toml` manifest
     pub fn write_to_file(self) -> Result<(), SbomWriterError> {
         let path = self.manifest_path.with_file_name(self.filename());
+        self.write_to_path(&path)
+    }
+
+    /// Writes SBOM to either a JSON, XML or Protocol Buffers file at `path`
+    pub fn write_to_path(self, path: &Path) -> Result<(), SbomWriterError> {
         log::info!("Outputting {}", path.display());
         let mut file = File::create(path).map_err(SbomWriterError::FileCreateError)?;
+        self.write_to(&mut file)
+    }
+
+    /// Serializes SBOM as either JSON, XML or Protocol Buffers into `writer`
+    ///
+    /// Reproducible BOMs have their components, dependencies and properties sorted canonically.
+    pub fn write_to<W: Write>(self, writer: &mut W) -> Result<(), SbomWriterError> {
+        let mut bom = self.bom;
+        if self.sbom_config.reproducible() {
+            bom.sort();
+        }
+
         match self.sbom_config.format() {
             Format::Json => {
-                self.bom
-                    .output_as_json_v1_3(&mut file)
+                bom.output_as_json_v1_3(writer)
                     .map_err(SbomWriterError::JsonWriteError)?;
             }
             Format::Xml => {
-                self.bom
-                    .output_as_xml_v1_3(&mut file)
+                bom.output_as_xml_v1_3(writer)
                     .map_err(SbomWriterError::XmlWriteError)?;
             }
+            #[cfg(feature = "protobuf")]
+            Format::Protobuf => {
+                bom.output_as_protobuf_v1_3(writer)
+                    .map_err(SbomWriterError::ProtobufWriteError)?;
+            }
         }
 
         Ok(())
     }
 
-    fn filename(&self) -> String {
+    /// The file name of the SBOM, based on the configured output options, target and format
+    pub fn filename(&self) -> String {
         let output_options = self.sbom_config.output_options();
         let prefix = match output_options.prefix {
             Prefix::Pattern(Pattern::Bom) => "bom".to_string(),
@@ -467,11 +1204,17 @@ impl GeneratedSbom {
             Prefix::Custom(c) => c.to_string(),
         };
 
+        let target = match &self.target {
+            Some(target) => format!("_{}", target),
+            None => "".to_string(),
+        };
+
         format!(
-            "{}{}.{}",
+            "{}{}{}.{}",
             prefix,
+            target,
             output_options.cdx_extension.extension(),
-            self.sbom_config.format()
+            self.sbom_config.format().extension()
         )
     }
 }
@@ -487,6 +1230,10 @@ pub enum SbomWriterError {
     #[error("Error writing XML file")]
     XmlWriteError(#[source] cyclonedx_bom::errors::XmlWriteError),
 
+    #[cfg(feature = "protobuf")]
+    #[error("Error writing Protocol Buffers file")]
+    ProtobufWriteError(#[source] cyclonedx_bom::errors::ProtobufWriteError),
+
     #[error("Error serializing to XML")]
     SerializeXmlError(#[source] std::io::Error),
 }
@@ -494,6 +1241,55 @@ pub enum SbomWriterError {
 #[cfg(test)]
 mod test {
     use super::*;
+    use cargo::util::IntoUrl;
+
+    #[test]
+    fn it_should_make_paths_relative_to_the_workspace_root() {
+        let root = Path::new("/home/user/ws");
+
+        assert_eq!(relative_path(root, root), ".");
+        assert_eq!(
+            relative_path(Path::new("/home/user/ws/crates/proj"), root),
+            "./crates/proj"
+        );
+        assert_eq!(
+            relative_path(Path::new("/home/user/other"), root),
+            "../other"
+        );
+        assert_eq!(
+            relative_path(Path::new("/opt/vendor"), root),
+            "../../../opt/vendor"
+        );
+    }
+
+    #[test]
+    fn it_should_encode_package_sources_in_purls() {
+        let crates_io = SourceId::for_registry(
+            &cargo::sources::CRATES_IO_INDEX
+                .into_url()
+                .expect("Failed to parse URL"),
+        )
+        .expect("Failed to create source id");
+        let registry = SourceId::for_registry(
+            &"https://example.com/index"
+                .into_url()
+                .expect("Failed to parse URL"),
+        )
+        .expect("Failed to create source id");
+
+        assert_eq!(
+            get_purl(crates_io, "serde", "1.0.0")
+                .expect("Failed to create purl")
+                .to_string(),
+            "pkg:cargo/serde@1.0.0?download_url=https://crates.io/api/v1/crates/serde/1.0.0/download"
+        );
+        assert_eq!(
+            get_purl(registry, "serde", "1.0.0")
+                .expect("Failed to create purl")
+                .to_string(),
+            "pkg:cargo/serde@1.0.0?repository_url=https://example.com/index"
+        );
+    }
 
     #[test]
     fn it_should_parse_author_and_email() {
@@ -47,8 +47,9 @@
 */
 use cargo::core::Workspace;
 use cargo::Config;
-use cargo_cyclonedx::generator::SbomGenerator;
+use cargo_cyclonedx::generator::{GeneratedSbom, SbomGenerator};
 use std::{
+    collections::BTreeSet,
     io::{self},
     path::PathBuf,
 };
@@ -61,7 +62,7 @@ use log::LevelFilter;
 #[deny(clippy::all)]
 #[deny(warnings)]
 mod cli;
-use cli::{Args, Opts};
+use cli::{Args, Opts, OutputDestination};
 
 fn main() -> anyhow::Result<()> {
     let Opts::Bom(args) = Opts::parse();
@@ -78,14 +79,73 @@ fn main() -> anyhow::Result<()> {
     log::trace!("SBOM generation finished");
 
     log::trace!("SBOM output started");
-    for bom in boms {
-        bom.write_to_file()?;
-    }
+    write_sboms(boms, args.output_destination())?;
     log::trace!("SBOM output finished");
 
     Ok(())
 }
 
+fn write_sboms(boms: Vec<GeneratedSbom>, destination: OutputDestination) -> anyhow::Result<()> {
+    match destination {
+        OutputDestination::Manifest => {
+            for bom in boms {
+                bom.write_to_file()?;
+            }
+        }
+        OutputDestination::File(path) => {
+            let bom = single_sbom(boms, &path.to_string_lossy())?;
+            bom.write_to_path(&path)?;
+        }
+        OutputDestination::Stdout => {
+            let bom = single_sbom(boms, "stdout")?;
+            let stdout = io::stdout();
+            bom.write_to(&mut stdout.lock())?;
+        }
+        OutputDestination::Directory(dir) => {
+            std::fs::create_dir_all(&dir)?;
+
+            let mut paths = BTreeSet::new();
+            for bom in &boms {
+                let path = dir.join(bom.filename());
+                if !paths.insert(path.clone()) {
+                    anyhow::bail!(
+                        "Multiple BOMs would be written to {}, use --output-pattern package to give each member its own file name",
+                        path.display()
+                    );
+                }
+            }
+
+            for bom in boms {
+                let path = dir.join(bom.filename());
+                bom.write_to_path(&path)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the only generated BOM, or an error if a single destination would receive several
+fn single_sbom(mut boms: Vec<GeneratedSbom>, destination: &str) -> anyhow::Result<GeneratedSbom> {
+    if boms.len() != 1 {
+        let names: Vec<String> = boms
+            .iter()
+            .map(|bom| match &bom.target {
+                Some(target) => format!("{} ({})", bom.package_name, target),
+                None => bom.package_name.clone(),
+            })
+            .collect();
+        anyhow::bail!(
+            "Cannot write {} BOMs to {}, generated BOMs for: {}. Use --output-dir or --manifest-path to select a single member",
+            boms.len(),
+            destination,
+            names.join(", ")
+        );
+    }
+
+    Ok(boms.remove(0))
+}
+
 fn setup_logging(args: &Args, config: &mut Config) -> anyhow::Result<()> {
     let mut builder = Builder::new();
 
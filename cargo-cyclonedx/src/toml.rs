@@ -60,6 +60,15 @@ impl TryFrom<ConfigWrapper> for SbomConfig {
 pub struct TomlConfig {
     pub format: Option<Format>,
     pub included_dependencies: Option<IncludedDependencies>,
+    pub dependency_set: Option<DependencySet>,
+    pub features: Option<Vec<String>>,
+    pub all_features: Option<bool>,
+    pub no_default_features: Option<bool>,
+    pub targets: Option<Vec<String>>,
+    pub build_dependencies: Option<bool>,
+    pub dev_dependencies: Option<bool>,
+    pub source_hashes: Option<bool>,
+    pub reproducible: Option<bool>,
     pub output_options: Option<OutputOptions>,
 }
 
@@ -68,6 +77,15 @@ impl TomlConfig {
         Self {
             format: None,
             included_dependencies: None,
+            dependency_set: None,
+            features: None,
+            all_features: None,
+            no_default_features: None,
+            targets: None,
+            build_dependencies: None,
+            dev_dependencies: None,
+            source_hashes: None,
+            reproducible: None,
             output_options: None,
         }
     }
@@ -82,9 +100,29 @@ impl TryFrom<TomlConfig> for SbomConfig {
             None => None,
         };
 
+        let features = match (
+            value.features,
+            value.all_features,
+            value.no_default_features,
+        ) {
+            (None, None, None) => None,
+            (features, all_features, no_default_features) => Some(config::Features {
+                features: features.unwrap_or_default(),
+                all_features: all_features.unwrap_or_default(),
+                no_default_features: no_default_features.unwrap_or_default(),
+            }),
+        };
+
         Ok(Self {
             format: value.format,
             included_dependencies: value.included_dependencies.map(Into::into),
+            dependency_set: value.dependency_set.map(Into::into),
+            features,
+            targets: value.targets,
+            build_dependencies: value.build_dependencies,
+            dev_dependencies: value.dev_dependencies,
+            source_hashes: value.source_hashes,
+            reproducible: value.reproducible,
             output_options,
         })
     }
@@ -125,6 +163,22 @@ impl From<IncludedDependencies> for config::IncludedDependencies {
     }
 }
 
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all(deserialize = "lowercase"))]
+pub enum DependencySet {
+    Member,
+    Workspace,
+}
+
+impl From<DependencySet> for config::DependencySet {
+    fn from(val: DependencySet) -> Self {
+        match val {
+            DependencySet::Member => Self::Member,
+            DependencySet::Workspace => Self::Workspace,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 pub struct OutputOptions {
     #[serde(rename(deserialize = "cdx"))]
@@ -219,6 +273,12 @@ mod test {
 [cyclonedx]
 format = "json"
 included_dependencies = "top-level"
+dependency_set = "workspace"
+features = ["tls-rustls"]
+no_default_features = true
+targets = ["x86_64-unknown-linux-gnu"]
+build_dependencies = true
+reproducible = true
 output_options = { cdx = true, pattern = "bom", prefix = "tacos" }
 "#;
 
@@ -227,6 +287,15 @@ output_options = { cdx = true, pattern = "bom", prefix = "tacos" }
         let expected = TomlConfig {
             format: Some(Format::Json),
             included_dependencies: Some(IncludedDependencies::TopLevelDependencies),
+            dependency_set: Some(DependencySet::Workspace),
+            features: Some(vec!["tls-rustls".to_string()]),
+            all_features: None,
+            no_default_features: Some(true),
+            targets: Some(vec!["x86_64-unknown-linux-gnu".to_string()]),
+            build_dependencies: Some(true),
+            dev_dependencies: None,
+            source_hashes: None,
+            reproducible: Some(true),
             output_options: Some(OutputOptions {
                 cdx_extension: Some(true),
                 prefix: Some("tacos".to_string()),
@@ -278,6 +347,32 @@ output_options = { cdx = true, pattern = "bom", prefix = "tacos" }
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn it_should_convert_feature_options_to_config_features() {
+        let toml = TomlConfig {
+            all_features: Some(true),
+            ..TomlConfig::empty_config()
+        };
+
+        let actual: SbomConfig = toml
+            .try_into()
+            .expect("Should have been able to convert to SbomConfig");
+
+        let expected = config::Features {
+            features: Vec::new(),
+            all_features: true,
+            no_default_features: false,
+        };
+
+        assert_eq!(actual.features, Some(expected));
+        assert_eq!(
+            SbomConfig::try_from(TomlConfig::empty_config())
+                .expect("Should have been able to convert to SbomConfig")
+                .features,
+            None
+        );
+    }
+
     #[test]
     fn it_should_ignore_other_packages_from_toml_value() {
         let toml = r#"
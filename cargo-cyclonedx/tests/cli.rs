@@ -86,6 +86,35 @@ fn find_content_in_bom_files() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[cfg(feature = "protobuf")]
+#[test]
+fn protobuf_bom_files_can_be_read_back() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("protobuf");
+
+    cmd.assert().success().stdout("");
+
+    let bom = std::fs::read(tmp_dir.child("bom.bin").path())?;
+    let bom = cyclonedx_bom::models::bom::Bom::parse_from_protobuf_v1_3(bom.as_slice())?;
+    let tools = match bom.metadata.and_then(|m| m.tools) {
+        Some(cyclonedx_bom::models::tool::Tools::List(tools)) => tools,
+        other => panic!("Expected a list of tools, got {:?}", other),
+    };
+    assert_eq!(
+        tools[0].vendor.as_ref().map(|v| v.to_string()),
+        Some("CycloneDX".to_string())
+    );
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
 #[test]
 fn find_content_in_stderr() -> Result<(), Box<dyn std::error::Error>> {
     let tmp_dir = make_temp_rust_project()?;
@@ -149,6 +178,632 @@ fn find_content_in_stderr() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn find_dependency_graph_in_bom_files() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [dependencies.nested-pkg]
+        path = "nested-pkg"
+        "#,
+    )?;
+
+    let pkg_dir = tmp_dir.child("nested-pkg");
+    pkg_dir.child("src/lib.rs").touch()?;
+    pkg_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "nested-pkg", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir.child("bom.json").assert(predicate::str::is_match(
+        r#""ref": "test 0.0.0 \(path\+file://[^"]+\)",
+      "dependsOn": \[
+        "nested-pkg 0.0.0 \(path\+file://[^"]+/nested-pkg\)"
+      \]"#,
+    )?);
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn workspace_members_only_list_their_own_dependencies() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [workspace]
+        members = ["member-a", "member-b"]
+        exclude = ["nested-pkg"]
+        "#,
+    )?;
+
+    let member_a = tmp_dir.child("member-a");
+    member_a.child("src/lib.rs").touch()?;
+    member_a.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "member-a"
+        version = "0.0.0"
+
+        [dependencies.nested-pkg]
+        path = "../nested-pkg"
+        "#,
+    )?;
+
+    let member_b = tmp_dir.child("member-b");
+    member_b.child("src/lib.rs").touch()?;
+    member_b
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "member-b", version = "0.0.0" }"#)?;
+
+    let nested_pkg = tmp_dir.child("nested-pkg");
+    nested_pkg.child("src/lib.rs").touch()?;
+    nested_pkg
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "nested-pkg", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--all");
+
+    cmd.assert().success().stdout("");
+
+    member_a
+        .child("bom.xml")
+        .assert(predicate::str::contains("pkg:cargo/nested-pkg@0.0.0"));
+    member_b
+        .child("bom.xml")
+        .assert(predicate::str::contains("pkg:cargo/nested-pkg@0.0.0").not());
+
+    cmd.arg("--dependency-set").arg("workspace");
+
+    cmd.assert().success().stdout("");
+
+    member_b
+        .child("bom.xml")
+        .assert(predicate::str::contains("pkg:cargo/nested-pkg@0.0.0"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn workspace_members_list_the_members_they_depend_on() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [workspace]
+        members = ["member-a", "member-b"]
+        exclude = ["nested-pkg"]
+        "#,
+    )?;
+
+    let member_a = tmp_dir.child("member-a");
+    member_a.child("src/lib.rs").touch()?;
+    member_a.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "member-a"
+        version = "0.0.0"
+
+        [dependencies.member-b]
+        path = "../member-b"
+        "#,
+    )?;
+
+    let member_b = tmp_dir.child("member-b");
+    member_b.child("src/lib.rs").touch()?;
+    member_b.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "member-b"
+        version = "0.0.0"
+
+        [dependencies.nested-pkg]
+        path = "../nested-pkg"
+        "#,
+    )?;
+
+    let nested_pkg = tmp_dir.child("nested-pkg");
+    nested_pkg.child("src/lib.rs").touch()?;
+    nested_pkg
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "nested-pkg", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--all")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    member_a
+        .child("bom.json")
+        .assert(predicate::str::contains(
+            r#""purl": "pkg:cargo/member-b@0.0.0""#,
+        ))
+        .assert(predicate::str::contains(
+            r#""purl": "pkg:cargo/nested-pkg@0.0.0""#,
+        ))
+        .assert(predicate::str::is_match(
+            r#""ref": "member-a 0.0.0 \(path\+file:///./member-a\)",
+      "dependsOn": \[
+        "member-b 0.0.0 \(path\+file:///./member-b\)"
+      \]"#,
+        )?)
+        .assert(predicate::str::is_match(
+            r#""ref": "member-b 0.0.0 \(path\+file:///./member-b\)",
+      "dependsOn": \[
+        "nested-pkg 0.0.0 \(path\+file:///./nested-pkg\)"
+      \]"#,
+        )?);
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn optional_dependencies_follow_feature_flags() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [dependencies.nested-pkg]
+        path = "nested-pkg"
+        optional = true
+
+        [features]
+        extra = ["nested-pkg"]
+        "#,
+    )?;
+
+    let pkg_dir = tmp_dir.child("nested-pkg");
+    pkg_dir.child("src/lib.rs").touch()?;
+    pkg_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "nested-pkg", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("bom.xml")
+        .assert(predicate::str::contains("pkg:cargo/nested-pkg@0.0.0").not());
+
+    cmd.arg("--features").arg("extra");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("bom.xml")
+        .assert(predicate::str::contains("pkg:cargo/nested-pkg@0.0.0"))
+        .assert(predicate::str::contains(
+            r#"<property name="cdx:cargo:feature">extra</property>"#,
+        ))
+        .assert(predicate::str::contains(
+            r#"<property name="cdx:cargo:enabled_by">test/extra</property>"#,
+        ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn features_enabled_by_dev_dependencies_are_not_recorded() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+        edition = "2021"
+
+        [dependencies]
+        nested-pkg = { path = "nested-pkg" }
+
+        [dev-dependencies]
+        nested-pkg = { path = "nested-pkg", features = ["extra"] }
+        "#,
+    )?;
+
+    let pkg_dir = tmp_dir.child("nested-pkg");
+    pkg_dir.child("src/lib.rs").touch()?;
+    pkg_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "nested-pkg"
+        version = "0.0.0"
+        edition = "2021"
+
+        [features]
+        extra = []
+        "#,
+    )?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("bom.xml")
+        .assert(predicate::str::contains("pkg:cargo/nested-pkg@0.0.0"))
+        .assert(
+            predicate::str::contains(r#"<property name="cdx:cargo:feature">extra</property>"#)
+                .not(),
+        );
+
+    cmd.arg("--dev-dependencies");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir.child("bom.xml").assert(predicate::str::contains(
+        r#"<property name="cdx:cargo:feature">extra</property>"#,
+    ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn platform_specific_dependencies_follow_target() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [target.'cfg(windows)'.dependencies.nested-pkg]
+        path = "nested-pkg"
+        "#,
+    )?;
+
+    let pkg_dir = tmp_dir.child("nested-pkg");
+    pkg_dir.child("src/lib.rs").touch()?;
+    pkg_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "nested-pkg", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--target")
+        .arg("x86_64-unknown-linux-gnu")
+        .arg("--target")
+        .arg("x86_64-pc-windows-msvc");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir.child("bom.xml").assert(predicate::path::missing());
+
+    tmp_dir
+        .child("bom_x86_64-unknown-linux-gnu.xml")
+        .assert(predicate::str::contains("pkg:cargo/nested-pkg@0.0.0").not());
+
+    tmp_dir
+        .child("bom_x86_64-pc-windows-msvc.xml")
+        .assert(predicate::str::contains("pkg:cargo/nested-pkg@0.0.0"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn dependency_kinds_have_distinct_scopes() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [features]
+        default = ["optional-pkg"]
+
+        [dependencies]
+        normal-pkg = { path = "normal-pkg" }
+        optional-pkg = { path = "optional-pkg", optional = true }
+
+        [build-dependencies]
+        build-pkg = { path = "build-pkg" }
+
+        [dev-dependencies]
+        dev-pkg = { path = "dev-pkg" }
+        "#,
+    )?;
+
+    for pkg_name in ["normal-pkg", "optional-pkg", "build-pkg", "dev-pkg"] {
+        let pkg_dir = tmp_dir.child(pkg_name);
+        pkg_dir.child("src/lib.rs").touch()?;
+        pkg_dir.child("Cargo.toml").write_str(&format!(
+            r#"package = {{ name = "{}", version = "0.0.0" }}"#,
+            pkg_name
+        ))?;
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("bom.xml")
+        .assert(predicate::str::contains("pkg:cargo/build-pkg@0.0.0").not())
+        .assert(predicate::str::contains("pkg:cargo/dev-pkg@0.0.0").not());
+
+    cmd.arg("--build-dependencies").arg("--dev-dependencies");
+
+    cmd.assert().success().stdout("");
+
+    let scope_of = |pkg_name: &str, scope: &str| {
+        predicate::str::contains(format!(
+            "<name>{}</name>\n      <version>0.0.0</version>\n      <scope>{}</scope>",
+            pkg_name, scope
+        ))
+    };
+
+    tmp_dir
+        .child("bom.xml")
+        .assert(scope_of("normal-pkg", "required"))
+        .assert(scope_of("optional-pkg", "optional"))
+        .assert(scope_of("build-pkg", "excluded"))
+        .assert(scope_of("dev-pkg", "excluded"))
+        .assert(predicate::str::contains(
+            r#"<property name="cdx:cargo:dependency_kind">build</property>"#,
+        ))
+        .assert(predicate::str::contains(
+            r#"<property name="cdx:cargo:dependency_kind">dev</property>"#,
+        ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn path_dependencies_get_source_hashes_on_request() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [dependencies.nested-pkg]
+        path = "nested-pkg"
+        "#,
+    )?;
+
+    let pkg_dir = tmp_dir.child("nested-pkg");
+    pkg_dir
+        .child("src/lib.rs")
+        .write_str("pub fn nested() {}")?;
+    pkg_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "nested-pkg", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("bom.xml")
+        .assert(predicate::str::contains(r#"<hash alg="SHA-256">"#).not());
+
+    cmd.arg("--source-hashes");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir.child("bom.xml").assert(predicate::str::is_match(
+        r#"<hash alg="SHA-256">[0-9a-f]{64}</hash>"#,
+    )?);
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn reproducible_boms_are_identical_between_runs() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .env_remove("SOURCE_DATE_EPOCH")
+        .arg("cyclonedx")
+        .arg("--reproducible")
+        .arg("--output-file")
+        .arg("-");
+
+    let first = cmd.assert().success().get_output().stdout.clone();
+    let second = cmd.assert().success().get_output().stdout.clone();
+
+    assert_eq!(first, second);
+    let first = String::from_utf8(first)?;
+    assert!(!first.contains("<timestamp>"));
+    assert!(predicate::str::is_match(
+        r#"serialNumber="urn:uuid:[0-9a-f]{8}-[0-9a-f]{4}-5[0-9a-f]{3}-"#
+    )?
+    .eval(&first));
+
+    cmd.env("SOURCE_DATE_EPOCH", "1700000000");
+
+    cmd.assert().success().stdout(predicate::str::contains(
+        "<timestamp>2023-11-14T22:13:20Z</timestamp>",
+    ));
+
+    cmd.env("SOURCE_DATE_EPOCH", "yesterday");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("SOURCE_DATE_EPOCH"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn reproducible_boms_are_identical_between_checkouts() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+
+    let mut outputs = Vec::new();
+    for checkout in ["a/proj", "b/deeper/proj"] {
+        let project = tmp_dir.child(checkout);
+        project.child("src/main.rs").touch()?;
+        project.child("Cargo.toml").write_str(
+            r#"
+            [package]
+            name = "proj"
+            version = "0.1.0"
+
+            [dependencies]
+            nested = { path = "crates/nested" }
+            "#,
+        )?;
+        project.child("crates/nested/src/lib.rs").touch()?;
+        project
+            .child("crates/nested/Cargo.toml")
+            .write_str(r#"package = { name = "nested", version = "0.1.0" }"#)?;
+
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.current_dir(project.path())
+            .env_remove("SOURCE_DATE_EPOCH")
+            .arg("cyclonedx")
+            .arg("--reproducible")
+            .arg("--output-file")
+            .arg("-");
+
+        let bom = cmd.assert().success().get_output().stdout.clone();
+        outputs.push(String::from_utf8(bom)?);
+    }
+
+    assert_eq!(outputs[0], outputs[1]);
+    assert!(outputs[0].contains(r#"bom-ref="nested 0.1.0 (path+file:///./crates/nested)""#));
+    assert!(!outputs[0].contains("download_url"));
+    assert!(!outputs[0].contains(&tmp_dir.path().to_string_lossy().to_string()));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn packages_with_the_same_name_and_version_have_unique_bom_refs(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    for dir in ["path-dup", "git-dup"] {
+        let pkg_dir = tmp_dir.child(dir);
+        pkg_dir.child("src/lib.rs").touch()?;
+        pkg_dir
+            .child("Cargo.toml")
+            .write_str(r#"package = { name = "dup-pkg", version = "0.0.0" }"#)?;
+    }
+
+    let git_dir = tmp_dir.child("git-dup");
+    for args in [
+        vec!["init", "--quiet"],
+        vec!["add", "."],
+        vec![
+            "-c",
+            "user.name=test",
+            "-c",
+            "user.email=test@example.com",
+            "commit",
+            "--quiet",
+            "-m",
+            "init",
+        ],
+    ] {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(git_dir.path())
+            .status()?;
+        assert!(status.success());
+    }
+
+    tmp_dir.child("Cargo.toml").write_str(&format!(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [workspace]
+        exclude = ["path-dup", "git-dup"]
+
+        [dependencies]
+        path-dup = {{ package = "dup-pkg", path = "path-dup" }}
+        git-dup = {{ package = "dup-pkg", git = "file://{}" }}
+        "#,
+        git_dir.path().display()
+    ))?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("bom.xml")
+        .assert(predicate::str::is_match(
+            r#"<component type="library" bom-ref="dup-pkg 0.0.0 \(path\+file:///\./path-dup\)">"#,
+        )?)
+        .assert(predicate::str::is_match(
+            r#"<component type="library" bom-ref="dup-pkg 0.0.0 \(git\+file://[^"]+/git-dup#[0-9a-f]{40}\)">"#,
+        )?);
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
 #[test]
 fn bom_file_name_extension_is_prepended_with_cdx() -> Result<(), Box<dyn std::error::Error>> {
     let tmp_dir = make_temp_rust_project()?;
@@ -172,6 +827,112 @@ fn bom_file_name_extension_is_prepended_with_cdx() -> Result<(), Box<dyn std::er
     Ok(())
 }
 
+#[test]
+fn bom_is_written_to_output_file_or_stdout() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--output-file")
+        .arg("sbom/custom.json");
+
+    tmp_dir.child("sbom").create_dir_all()?;
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir.child("bom.json").assert(predicate::path::missing());
+    tmp_dir
+        .child("sbom/custom.json")
+        .assert(predicate::str::contains(r#""bomFormat": "CycloneDX""#));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--output-file")
+        .arg("-");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(r#""bomFormat": "CycloneDX""#));
+
+    tmp_dir.child("bom.json").assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn workspace_boms_are_written_to_output_dir() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [workspace]
+        members = ["member-a", "member-b"]
+        "#,
+    )?;
+
+    for member in ["member-a", "member-b"] {
+        let member_dir = tmp_dir.child(member);
+        member_dir.child("src/lib.rs").touch()?;
+        member_dir.child("Cargo.toml").write_str(&format!(
+            r#"package = {{ name = "{}", version = "0.0.0" }}"#,
+            member
+        ))?;
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--output-file")
+        .arg("-");
+
+    cmd.assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "Cannot write 2 BOMs to stdout, generated BOMs for: member-a, member-b",
+        ));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--output-dir")
+        .arg("sboms");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Multiple BOMs would be written to",
+    ));
+
+    cmd.arg("--output-pattern").arg("package");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("sboms/member-a.xml")
+        .assert(predicate::path::exists());
+    tmp_dir
+        .child("sboms/member-b.xml")
+        .assert(predicate::path::exists());
+    tmp_dir
+        .child("member-a/member-a.xml")
+        .assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
 fn make_temp_rust_project() -> Result<assert_fs::TempDir, assert_fs::fixture::FixtureError> {
     let tmp_dir = assert_fs::TempDir::new()?;
     tmp_dir.child("src/main.rs").touch()?;
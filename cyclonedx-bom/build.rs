@@ -0,0 +1,55 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "json-schema")]
+    for schema in [
+        "bom-1.3.schema.json",
+        "bom-1.4.schema.json",
+        "bom-1.5.schema.json",
+        "spdx.schema.json",
+        "jsf-0.82.schema.json",
+    ] {
+        println!("cargo:rerun-if-changed=schema/{schema}");
+
+        if !std::path::Path::new("schema").join(schema).exists() {
+            panic!(
+                "schema/{} is missing, run schema/vendor.sh to vendor the CycloneDX schemas",
+                schema
+            );
+        }
+    }
+
+    #[cfg(feature = "protobuf")]
+    {
+        println!("cargo:rerun-if-changed=schema/bom-1.3.proto");
+
+        if !std::path::Path::new("schema/bom-1.3.proto").exists() {
+            panic!("schema/bom-1.3.proto is missing, run schema/vendor.sh to vendor the CycloneDX schemas");
+        }
+
+        protobuf_codegen::Codegen::new()
+            .pure()
+            .include("schema")
+            .input("schema/bom-1.3.proto")
+            .cargo_out_dir("protobuf")
+            .run_from_script();
+    }
+}
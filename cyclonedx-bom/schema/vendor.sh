@@ -0,0 +1,48 @@
+#!/bin/sh
+#
+# This file is part of CycloneDX Rust Cargo.
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+#
+# SPDX-License-Identifier: Apache-2.0
+#
+# Copies the schemas bundled with this crate unchanged from the CycloneDX specification release
+# pinned below and records the source and checksum of every file in SOURCES. Commit the downloaded
+# files together with SOURCES.
+
+set -eu
+
+SPECIFICATION_VERSION="1.5"
+BASE_URL="https://raw.githubusercontent.com/CycloneDX/specification/${SPECIFICATION_VERSION}/schema"
+FILES="
+bom-1.3.schema.json
+bom-1.4.schema.json
+bom-1.5.schema.json
+spdx.schema.json
+jsf-0.82.schema.json
+bom-1.3.proto
+"
+
+cd "$(dirname "$0")"
+
+{
+    echo "# Vendored from the CycloneDX specification ${SPECIFICATION_VERSION} release by vendor.sh"
+    echo "# sha256  file  source"
+} > SOURCES.new
+
+for file in $FILES; do
+    curl --fail --silent --show-error --location --output "$file" "${BASE_URL}/${file}"
+    echo "$(sha256sum "$file" | cut -d ' ' -f 1)  ${file}  ${BASE_URL}/${file}" >> SOURCES.new
+done
+
+mv SOURCES.new SOURCES
@@ -0,0 +1,737 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Reporting of the data that a particular version of the specification cannot represent.
+//!
+//! The [`Bom`] model is a superset of all supported versions of the specification. When a BOM is
+//! written with [`Bom::output_as`], every field that the requested version and format has no place
+//! for is either dropped or rewritten to the closest supported value, and reported as a
+//! [`ConversionLoss`].
+
+use crate::models::{
+    bom::{Bom, OutputFormat, SpecVersion},
+    component::{Classification, Component, Components},
+    external_reference::{ExternalReferenceType, ExternalReferences},
+    license::{LicenseChoice, Licenses},
+    service::{Service, Services},
+    tool::Tools,
+    vulnerability::{ScoreMethod, Vulnerability},
+};
+
+/// A field that could not be represented in the requested version of the specification
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionLoss {
+    /// The location of the field in the document, e.g. `components[0].modelCard`
+    pub path: String,
+    pub kind: ConversionLossKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConversionLossKind {
+    /// The field was left out of the output
+    Dropped,
+    /// The value was replaced with one that the requested version supports
+    Rewritten { from: String, to: String },
+}
+
+/// Remove or rewrite everything in the BOM that cannot be output in the given version and format
+pub(crate) fn downgrade(
+    bom: &mut Bom,
+    format: OutputFormat,
+    spec_version: SpecVersion,
+) -> Vec<ConversionLoss> {
+    let mut downgrade = Downgrade {
+        format,
+        spec_version,
+        losses: Vec::new(),
+    };
+    downgrade.bom(bom);
+    downgrade.losses
+}
+
+struct Downgrade {
+    format: OutputFormat,
+    spec_version: SpecVersion,
+    losses: Vec<ConversionLoss>,
+}
+
+impl Downgrade {
+    fn supports(&self, spec_version: SpecVersion) -> bool {
+        self.spec_version >= spec_version
+    }
+
+    /// Signatures are JSON Signature Format objects, which only the JSON format can hold
+    fn supports_signatures(&self) -> bool {
+        self.supports(SpecVersion::V1_4) && self.format == OutputFormat::Json
+    }
+
+    /// Take the value out of the field if it is not supported, returning whether it was kept
+    fn keep<T>(&mut self, field: &mut Option<T>, supported: bool, path: String) -> bool {
+        if field.is_none() {
+            return false;
+        }
+
+        if !supported {
+            *field = None;
+            self.losses.push(ConversionLoss {
+                path,
+                kind: ConversionLossKind::Dropped,
+            });
+            return false;
+        }
+
+        true
+    }
+
+    fn rewrite(&mut self, path: String, from: String, to: String) {
+        self.losses.push(ConversionLoss {
+            path,
+            kind: ConversionLossKind::Rewritten { from, to },
+        });
+    }
+
+    fn bom(&mut self, bom: &mut Bom) {
+        if let Some(metadata) = &mut bom.metadata {
+            self.keep(
+                &mut metadata.lifecycles,
+                self.supports(SpecVersion::V1_5),
+                "metadata.lifecycles".to_string(),
+            );
+            self.tools(&mut metadata.tools, "metadata.tools");
+            if let Some(component) = &mut metadata.component {
+                self.component(component, "metadata.component");
+            }
+            self.licenses(&mut metadata.licenses, "metadata.licenses");
+        }
+
+        self.components(&mut bom.components, "components");
+        self.services(&mut bom.services, "services");
+        self.external_references(&mut bom.external_references, "externalReferences");
+
+        if let Some(compositions) = &mut bom.compositions {
+            for (index, composition) in compositions.0.iter_mut().enumerate() {
+                self.keep(
+                    &mut composition.signature,
+                    self.supports_signatures(),
+                    format!("compositions[{index}].signature"),
+                );
+            }
+        }
+
+        let supported = self.supports(SpecVersion::V1_4);
+        if self.keep(
+            &mut bom.vulnerabilities,
+            supported,
+            "vulnerabilities".to_string(),
+        ) {
+            if let Some(vulnerabilities) = &mut bom.vulnerabilities {
+                for (index, vulnerability) in vulnerabilities.0.iter_mut().enumerate() {
+                    self.vulnerability(vulnerability, &format!("vulnerabilities[{index}]"));
+                }
+            }
+        }
+
+        let supported = self.supports(SpecVersion::V1_5);
+        if self.keep(&mut bom.annotations, supported, "annotations".to_string()) {
+            if let Some(annotations) = &mut bom.annotations {
+                for (index, annotation) in annotations.0.iter_mut().enumerate() {
+                    let path = format!("annotations[{index}]");
+                    match &mut annotation.annotator {
+                        crate::models::annotation::Annotator::Component(component) => {
+                            self.component(component, &format!("{path}.annotator.component"))
+                        }
+                        crate::models::annotation::Annotator::Service(service) => {
+                            self.service(service, &format!("{path}.annotator.service"))
+                        }
+                        _ => (),
+                    }
+                    self.keep(
+                        &mut annotation.signature,
+                        self.supports_signatures(),
+                        format!("{path}.signature"),
+                    );
+                }
+            }
+        }
+
+        let supported = self.supports(SpecVersion::V1_5);
+        if self.keep(&mut bom.formulation, supported, "formulation".to_string()) {
+            if let Some(formulation) = &mut bom.formulation {
+                for (index, formula) in formulation.0.iter_mut().enumerate() {
+                    let path = format!("formulation[{index}]");
+                    self.components(&mut formula.components, &format!("{path}.components"));
+                    self.services(&mut formula.services, &format!("{path}.services"));
+                }
+            }
+        }
+
+        // the signature of the whole document is also kept in version 1.3, which carries it along
+        // so that signed JSON documents can still be verified
+        self.keep(
+            &mut bom.signature,
+            self.format == OutputFormat::Json,
+            "signature".to_string(),
+        );
+    }
+
+    fn components(&mut self, components: &mut Option<Components>, path: &str) {
+        if let Some(components) = components {
+            for (index, component) in components.0.iter_mut().enumerate() {
+                self.component(component, &format!("{path}[{index}]"));
+            }
+        }
+    }
+
+    fn component(&mut self, component: &mut Component, path: &str) {
+        if !self.supports(SpecVersion::V1_5) {
+            let replacement = match component.component_type {
+                Classification::Platform => Some(Classification::Framework),
+                Classification::DeviceDriver => Some(Classification::Library),
+                Classification::MachineLearningModel | Classification::Data => {
+                    Some(Classification::File)
+                }
+                _ => None,
+            };
+            if let Some(replacement) = replacement {
+                let from = std::mem::replace(&mut component.component_type, replacement);
+                self.rewrite(
+                    format!("{path}.type"),
+                    from.to_string(),
+                    component.component_type.to_string(),
+                );
+            }
+        }
+
+        self.licenses(&mut component.licenses, &format!("{path}.licenses"));
+        if let Some(pedigree) = &mut component.pedigree {
+            self.components(
+                &mut pedigree.ancestors,
+                &format!("{path}.pedigree.ancestors"),
+            );
+            self.components(
+                &mut pedigree.descendants,
+                &format!("{path}.pedigree.descendants"),
+            );
+            self.components(&mut pedigree.variants, &format!("{path}.pedigree.variants"));
+        }
+        self.external_references(
+            &mut component.external_references,
+            &format!("{path}.externalReferences"),
+        );
+        self.components(&mut component.components, &format!("{path}.components"));
+
+        if let Some(evidence) = &mut component.evidence {
+            self.licenses(&mut evidence.licenses, &format!("{path}.evidence.licenses"));
+            let supported = self.supports(SpecVersion::V1_5);
+            self.keep(
+                &mut evidence.identity,
+                supported,
+                format!("{path}.evidence.identity"),
+            );
+            self.keep(
+                &mut evidence.occurrences,
+                supported,
+                format!("{path}.evidence.occurrences"),
+            );
+            self.keep(
+                &mut evidence.callstack,
+                supported,
+                format!("{path}.evidence.callstack"),
+            );
+        }
+
+        self.keep(
+            &mut component.release_notes,
+            self.supports(SpecVersion::V1_4),
+            format!("{path}.releaseNotes"),
+        );
+        self.keep(
+            &mut component.model_card,
+            self.supports(SpecVersion::V1_5),
+            format!("{path}.modelCard"),
+        );
+        self.keep(
+            &mut component.data,
+            self.supports(SpecVersion::V1_5),
+            format!("{path}.data"),
+        );
+        self.keep(
+            &mut component.signature,
+            self.supports_signatures(),
+            format!("{path}.signature"),
+        );
+    }
+
+    fn services(&mut self, services: &mut Option<Services>, path: &str) {
+        if let Some(services) = services {
+            for (index, service) in services.0.iter_mut().enumerate() {
+                self.service(service, &format!("{path}[{index}]"));
+            }
+        }
+    }
+
+    fn service(&mut self, service: &mut Service, path: &str) {
+        self.keep(
+            &mut service.trust_zone,
+            self.supports(SpecVersion::V1_5),
+            format!("{path}.trustZone"),
+        );
+        self.licenses(&mut service.licenses, &format!("{path}.licenses"));
+        self.external_references(
+            &mut service.external_references,
+            &format!("{path}.externalReferences"),
+        );
+        self.services(&mut service.services, &format!("{path}.services"));
+        self.keep(
+            &mut service.release_notes,
+            self.supports(SpecVersion::V1_4),
+            format!("{path}.releaseNotes"),
+        );
+        self.keep(
+            &mut service.signature,
+            self.supports_signatures(),
+            format!("{path}.signature"),
+        );
+    }
+
+    fn licenses(&mut self, licenses: &mut Option<Licenses>, path: &str) {
+        if let Some(licenses) = licenses {
+            for (index, license) in licenses.0.iter_mut().enumerate() {
+                if let LicenseChoice::License(license) = license {
+                    self.keep(
+                        &mut license.licensing,
+                        self.supports(SpecVersion::V1_5),
+                        format!("{path}[{index}].license.licensing"),
+                    );
+                }
+            }
+        }
+    }
+
+    fn vulnerability(&mut self, vulnerability: &mut Vulnerability, path: &str) {
+        if !self.supports(SpecVersion::V1_5) {
+            if let Some(ratings) = &mut vulnerability.ratings {
+                for (index, rating) in ratings.iter_mut().enumerate() {
+                    if let Some(method @ (ScoreMethod::CVSSv4 | ScoreMethod::SSVC)) =
+                        &mut rating.method
+                    {
+                        let from = std::mem::replace(method, ScoreMethod::Other);
+                        self.rewrite(
+                            format!("{path}.ratings[{index}].method"),
+                            from.to_string(),
+                            ScoreMethod::Other.to_string(),
+                        );
+                    }
+                }
+            }
+        }
+
+        let supported = self.supports(SpecVersion::V1_5);
+        self.keep(
+            &mut vulnerability.workaround,
+            supported,
+            format!("{path}.workaround"),
+        );
+        self.keep(
+            &mut vulnerability.proof_of_concept,
+            supported,
+            format!("{path}.proofOfConcept"),
+        );
+        self.keep(
+            &mut vulnerability.rejected,
+            supported,
+            format!("{path}.rejected"),
+        );
+        self.tools(&mut vulnerability.tools, &format!("{path}.tools"));
+        if let Some(analysis) = &mut vulnerability.analysis {
+            self.keep(
+                &mut analysis.first_issued,
+                supported,
+                format!("{path}.analysis.firstIssued"),
+            );
+            self.keep(
+                &mut analysis.last_updated,
+                supported,
+                format!("{path}.analysis.lastUpdated"),
+            );
+        }
+    }
+
+    fn tools(&mut self, tools: &mut Option<Tools>, path: &str) {
+        if let Some(Tools::Object {
+            components,
+            services,
+        }) = tools
+        {
+            if self.supports(SpecVersion::V1_5) {
+                self.components(components, &format!("{path}.components"));
+                self.services(services, &format!("{path}.services"));
+                return;
+            }
+
+            let components = components.as_ref().map_or(0, |c| c.0.len());
+            let services = services.as_ref().map_or(0, |s| s.0.len());
+            for index in 0..components {
+                self.rewrite(
+                    format!("{path}.components[{index}]"),
+                    "component".to_string(),
+                    "tool".to_string(),
+                );
+            }
+            for index in 0..services {
+                self.rewrite(
+                    format!("{path}.services[{index}]"),
+                    "service".to_string(),
+                    "tool".to_string(),
+                );
+            }
+            *tools = tools.take().map(|t| Tools::List(t.into_list()));
+        }
+
+        if let Some(Tools::List(tools)) = tools {
+            for (index, tool) in tools.iter_mut().enumerate() {
+                let path = format!("{path}[{index}].externalReferences");
+                let supported = self.supports(SpecVersion::V1_4);
+                if self.keep(&mut tool.external_references, supported, path.clone()) {
+                    self.external_references(&mut tool.external_references, &path);
+                }
+            }
+        }
+    }
+
+    fn external_references(
+        &mut self,
+        external_references: &mut Option<ExternalReferences>,
+        path: &str,
+    ) {
+        if let Some(external_references) = external_references {
+            for (index, external_reference) in external_references.0.iter_mut().enumerate() {
+                let introduced_in = match external_reference.external_reference_type {
+                    ExternalReferenceType::ReleaseNotes => SpecVersion::V1_4,
+                    ExternalReferenceType::DistributionIntake
+                    | ExternalReferenceType::SecurityContact
+                    | ExternalReferenceType::ModelCard
+                    | ExternalReferenceType::Log
+                    | ExternalReferenceType::Configuration
+                    | ExternalReferenceType::Evidence
+                    | ExternalReferenceType::Formulation
+                    | ExternalReferenceType::Attestation
+                    | ExternalReferenceType::ThreatModel
+                    | ExternalReferenceType::AdversaryModel
+                    | ExternalReferenceType::RiskAssessment
+                    | ExternalReferenceType::VulnerabilityAssertion
+                    | ExternalReferenceType::ExploitabilityStatement
+                    | ExternalReferenceType::PentestReport
+                    | ExternalReferenceType::StaticAnalysisReport
+                    | ExternalReferenceType::DynamicAnalysisReport
+                    | ExternalReferenceType::RuntimeAnalysisReport
+                    | ExternalReferenceType::ComponentAnalysisReport
+                    | ExternalReferenceType::MaturityReport
+                    | ExternalReferenceType::CertificationReport
+                    | ExternalReferenceType::CodifiedInfrastructure
+                    | ExternalReferenceType::QualityMetrics
+                    | ExternalReferenceType::Poam => SpecVersion::V1_5,
+                    _ => SpecVersion::V1_3,
+                };
+                if !self.supports(introduced_in) {
+                    let from = std::mem::replace(
+                        &mut external_reference.external_reference_type,
+                        ExternalReferenceType::Other,
+                    );
+                    self.rewrite(
+                        format!("{path}[{index}].type"),
+                        from.to_string(),
+                        ExternalReferenceType::Other.to_string(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
+        models::{
+            external_reference::ExternalReference,
+            license::{License, Licensing},
+            lifecycle::{Lifecycle, LifecyclePhase, Lifecycles},
+            metadata::Metadata,
+            release_notes::ReleaseNotes,
+            signature::{Algorithm, Signature},
+            tool::Tool,
+            vulnerability::{Vulnerabilities, VulnerabilityAnalysis, VulnerabilityRating},
+        },
+    };
+    use pretty_assertions::assert_eq;
+
+    fn example_bom() -> Bom {
+        let mut component = Component::new(
+            Classification::MachineLearningModel,
+            "model",
+            "1.0.0",
+            Some("model-1".to_string()),
+        );
+        component.external_references = Some(ExternalReferences(vec![
+            ExternalReference::new(
+                ExternalReferenceType::ReleaseNotes,
+                Uri("https://example.com/releases".to_string()),
+            ),
+            ExternalReference::new(
+                ExternalReferenceType::ModelCard,
+                Uri("https://example.com/model-card".to_string()),
+            ),
+        ]));
+        component.release_notes = Some(ReleaseNotes::new("major"));
+        component.signature = Some(Signature::new(Algorithm::ES256, "abcdefgh"));
+
+        let mut service = Service::new("service", None);
+        service.release_notes = Some(ReleaseNotes::new("minor"));
+
+        Bom {
+            serial_number: None,
+            metadata: Some(Metadata {
+                lifecycles: Some(Lifecycles(vec![Lifecycle::Phase(LifecyclePhase::Build)])),
+                tools: Some(Tools::List(vec![Tool {
+                    external_references: Some(ExternalReferences(vec![ExternalReference::new(
+                        ExternalReferenceType::Website,
+                        Uri("https://example.com".to_string()),
+                    )])),
+                    ..Tool::default()
+                }])),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![component])),
+            services: Some(Services(vec![service])),
+            vulnerabilities: Some(Vulnerabilities(vec![Vulnerability::new(
+                None,
+                "CVE-2023-1234",
+            )])),
+            ..Bom::default()
+        }
+    }
+
+    fn dropped(path: &str) -> ConversionLoss {
+        ConversionLoss {
+            path: path.to_string(),
+            kind: ConversionLossKind::Dropped,
+        }
+    }
+
+    fn rewritten(path: &str, from: &str, to: &str) -> ConversionLoss {
+        ConversionLoss {
+            path: path.to_string(),
+            kind: ConversionLossKind::Rewritten {
+                from: from.to_string(),
+                to: to.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn it_should_report_nothing_when_the_version_supports_everything() {
+        let mut bom = example_bom();
+
+        let losses = downgrade(&mut bom, OutputFormat::Json, SpecVersion::V1_5);
+
+        assert_eq!(losses, vec![]);
+        assert_eq!(bom, example_bom());
+    }
+
+    #[test]
+    fn it_should_report_the_fields_that_version_1_3_cannot_represent() {
+        let mut bom = example_bom();
+
+        let losses = downgrade(&mut bom, OutputFormat::Json, SpecVersion::V1_3);
+
+        assert_eq!(
+            losses,
+            vec![
+                dropped("metadata.lifecycles"),
+                dropped("metadata.tools[0].externalReferences"),
+                rewritten("components[0].type", "machine-learning-model", "file"),
+                rewritten(
+                    "components[0].externalReferences[0].type",
+                    "release-notes",
+                    "other"
+                ),
+                rewritten(
+                    "components[0].externalReferences[1].type",
+                    "model-card",
+                    "other"
+                ),
+                dropped("components[0].releaseNotes"),
+                dropped("components[0].signature"),
+                dropped("services[0].releaseNotes"),
+                dropped("vulnerabilities"),
+            ]
+        );
+
+        let component = &bom.components.as_ref().unwrap().0[0];
+        assert_eq!(component.component_type, Classification::File);
+        assert_eq!(component.release_notes, None);
+        assert_eq!(bom.vulnerabilities, None);
+    }
+
+    #[test]
+    fn it_should_report_the_fields_that_version_1_4_cannot_represent() {
+        let mut bom = example_bom();
+
+        let losses = downgrade(&mut bom, OutputFormat::Json, SpecVersion::V1_4);
+
+        assert_eq!(
+            losses,
+            vec![
+                dropped("metadata.lifecycles"),
+                rewritten("components[0].type", "machine-learning-model", "file"),
+                rewritten(
+                    "components[0].externalReferences[1].type",
+                    "model-card",
+                    "other"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_licensing_service_and_vulnerability_fields_of_version_1_5() {
+        let mut license = License::named_license("Commercial");
+        license.licensing = Some(Box::new(Licensing {
+            purchase_order: Some("PO-1234".to_string()),
+            ..Licensing::default()
+        }));
+        let mut service = Service::new("service", None);
+        service.trust_zone = Some(NormalizedString::new("trusted"));
+        service.licenses = Some(Licenses(vec![LicenseChoice::License(license)]));
+        let mut vulnerability = Vulnerability::new(None, "CVE-2023-1234");
+        vulnerability.ratings = Some(vec![
+            VulnerabilityRating::new(None, None, Some(ScoreMethod::CVSSv31)),
+            VulnerabilityRating::new(None, None, Some(ScoreMethod::CVSSv4)),
+            VulnerabilityRating::new(None, None, Some(ScoreMethod::SSVC)),
+        ]);
+        vulnerability.workaround = Some("Disable the feature".to_string());
+        vulnerability.rejected = Some(DateTime("2023-01-01T00:00:00Z".to_string()));
+        vulnerability.analysis = Some(VulnerabilityAnalysis {
+            state: None,
+            justification: None,
+            responses: None,
+            detail: None,
+            first_issued: Some(DateTime("2023-01-01T00:00:00Z".to_string())),
+            last_updated: None,
+        });
+        let mut bom = Bom {
+            services: Some(Services(vec![service])),
+            vulnerabilities: Some(Vulnerabilities(vec![vulnerability])),
+            ..Bom::default()
+        };
+
+        let losses = downgrade(&mut bom, OutputFormat::Json, SpecVersion::V1_4);
+
+        assert_eq!(
+            losses,
+            vec![
+                dropped("services[0].trustZone"),
+                dropped("services[0].licenses[0].license.licensing"),
+                rewritten("vulnerabilities[0].ratings[1].method", "CVSSv4", "other"),
+                rewritten("vulnerabilities[0].ratings[2].method", "SSVC", "other"),
+                dropped("vulnerabilities[0].workaround"),
+                dropped("vulnerabilities[0].rejected"),
+                dropped("vulnerabilities[0].analysis.firstIssued"),
+            ]
+        );
+        let vulnerability = &bom.vulnerabilities.as_ref().unwrap().0[0];
+        let methods: Vec<_> = vulnerability
+            .ratings
+            .iter()
+            .flatten()
+            .map(|rating| rating.method.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(methods, vec!["CVSSv31", "other", "other"]);
+        assert_eq!(vulnerability.workaround, None);
+    }
+
+    #[test]
+    fn it_should_rewrite_tool_components_and_services_as_tools_before_version_1_5() {
+        let mut bom = Bom {
+            metadata: Some(Metadata {
+                tools: Some(Tools::Object {
+                    components: Some(Components(vec![Component::new(
+                        Classification::Application,
+                        "generator",
+                        "1.0.0",
+                        None,
+                    )])),
+                    services: Some(Services(vec![Service::new("scanner", None)])),
+                }),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+
+        let losses = downgrade(&mut bom, OutputFormat::Json, SpecVersion::V1_4);
+
+        assert_eq!(
+            losses,
+            vec![
+                rewritten("metadata.tools.components[0]", "component", "tool"),
+                rewritten("metadata.tools.services[0]", "service", "tool"),
+            ]
+        );
+        assert_eq!(
+            bom.metadata.unwrap().tools,
+            Some(Tools::List(vec![
+                Tool {
+                    name: Some(NormalizedString::new("generator")),
+                    version: Some(NormalizedString::new("1.0.0")),
+                    ..Tool::default()
+                },
+                Tool {
+                    name: Some(NormalizedString::new("scanner")),
+                    ..Tool::default()
+                },
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_report_signatures_as_dropped_from_xml() {
+        let mut bom = example_bom();
+
+        let losses = downgrade(&mut bom, OutputFormat::Xml, SpecVersion::V1_5);
+
+        assert_eq!(losses, vec![dropped("components[0].signature")]);
+    }
+
+    #[test]
+    fn it_should_output_the_bom_without_the_lost_fields() {
+        let mut output = Vec::new();
+
+        let losses = example_bom()
+            .output_as(OutputFormat::Json, SpecVersion::V1_3, &mut output)
+            .expect("Failed to output BOM");
+
+        let output = String::from_utf8(output).expect("Failed to read output as a string");
+        assert_eq!(losses.len(), 9);
+        assert!(output.contains(r#""specVersion": "1.3""#));
+        assert!(output.contains(r#""type": "file""#));
+        assert!(!output.contains("release-notes"));
+        assert!(!output.contains("vulnerabilities"));
+    }
+}
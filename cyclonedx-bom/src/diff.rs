@@ -0,0 +1,595 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Compare two BOMs to find out what changed between them.
+//!
+//! Components are matched between the BOMs by their purl without the version, or by their group
+//! and name if they do not have a purl. When a package occurs more than once within either BOM,
+//! each version is matched on its own instead, and occurrences of the same version are told apart
+//! by their full purl or, failing that, their `bom-ref`. Dependencies are compared as edges
+//! between those same keys, so that a dependency on a component whose version changed is not
+//! reported as a changed edge.
+//!
+//! The resulting [`BomDiff`] implements [`serde::Serialize`], so it can be rendered as JSON or fed
+//! into a template.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::models::{
+    bom::Bom,
+    component::{Component, Components},
+    hash::Hashes,
+    license::{LicenseChoice, LicenseIdentifier, Licenses},
+};
+
+/// The differences between two BOMs
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BomDiff {
+    pub added_components: Vec<ComponentSummary>,
+    pub removed_components: Vec<ComponentSummary>,
+    pub changed_components: Vec<ComponentChange>,
+    pub added_dependencies: Vec<DependencyEdge>,
+    pub removed_dependencies: Vec<DependencyEdge>,
+}
+
+impl BomDiff {
+    /// Whether the BOMs contain the same components and dependencies
+    pub fn is_empty(&self) -> bool {
+        self.added_components.is_empty()
+            && self.removed_components.is_empty()
+            && self.changed_components.is_empty()
+            && self.added_dependencies.is_empty()
+            && self.removed_dependencies.is_empty()
+    }
+}
+
+/// A component that only occurs in one of the BOMs
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentSummary {
+    /// The key that the component was matched by
+    pub key: String,
+    pub name: String,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+}
+
+/// A component that occurs in both BOMs, but with different details
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentChange {
+    /// The key that the component was matched by
+    pub key: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<ValueChange<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub licenses: Option<ValueChange<Vec<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hashes: Option<ValueChange<Vec<String>>>,
+}
+
+/// The value of a field in the old and in the new BOM
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ValueChange<T> {
+    pub old: T,
+    pub new: T,
+}
+
+/// A dependency of one component on another, identified by their keys
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct DependencyEdge {
+    pub from: String,
+    pub to: String,
+}
+
+/// Compare the `old` BOM with the `new` BOM.
+///
+/// All components are compared, including nested ones, but not the `metadata.component`
+/// describing the subject of the BOM itself.
+pub fn diff(old: &Bom, new: &Bom) -> BomDiff {
+    let (old_components, new_components) = keyed_components(&old.components, &new.components);
+
+    let mut diff = BomDiff::default();
+
+    for (key, old_component) in &old_components {
+        match new_components.get(key) {
+            Some(new_component) => {
+                if let Some(change) = compare_components(key, old_component, new_component) {
+                    diff.changed_components.push(change);
+                }
+            }
+            None => diff
+                .removed_components
+                .push(summarize_component(key, old_component)),
+        }
+    }
+
+    for (key, new_component) in &new_components {
+        if !old_components.contains_key(key) {
+            diff.added_components
+                .push(summarize_component(key, new_component));
+        }
+    }
+
+    let old_edges = dependency_edges(old, &old_components);
+    let new_edges = dependency_edges(new, &new_components);
+    diff.added_dependencies = new_edges.difference(&old_edges).cloned().collect();
+    diff.removed_dependencies = old_edges.difference(&new_edges).cloned().collect();
+
+    diff
+}
+
+/// Index all components of both BOMs by the key that they are matched by.
+///
+/// Every component is keyed by the most general of its [`component_keys`] that does not occur more
+/// than once in either BOM, so that a component is keyed the same way in both of them.
+fn keyed_components<'a>(
+    old: &'a Option<Components>,
+    new: &'a Option<Components>,
+) -> (
+    BTreeMap<String, &'a Component>,
+    BTreeMap<String, &'a Component>,
+) {
+    let mut pending: [Vec<(String, &Component)>; 2] = [Vec::new(), Vec::new()];
+    for (components, pending) in [old, new].iter().zip(pending.iter_mut()) {
+        let mut all_components = Vec::new();
+        collect_components(components, &mut all_components);
+        *pending = all_components
+            .into_iter()
+            .map(|component| (component_key(component), component))
+            .collect();
+    }
+
+    let mut keyed = [BTreeMap::new(), BTreeMap::new()];
+    for level in 0..KEY_LEVELS {
+        let keys: Vec<Vec<(String, &Component)>> = pending
+            .iter_mut()
+            .map(|pending| {
+                std::mem::take(pending)
+                    .into_iter()
+                    .map(|(key, component)| {
+                        (component_keys(component, level).unwrap_or(key), component)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut ambiguous = HashSet::new();
+        for keys in &keys {
+            let mut seen = HashSet::new();
+            for (key, _) in keys {
+                if !seen.insert(key) {
+                    ambiguous.insert(key.clone());
+                }
+            }
+        }
+
+        for ((keys, pending), keyed) in keys.into_iter().zip(pending.iter_mut()).zip(&mut keyed) {
+            for (key, component) in keys {
+                if ambiguous.contains(&key) && level + 1 < KEY_LEVELS {
+                    pending.push((key, component));
+                } else {
+                    keyed.insert(key, component);
+                }
+            }
+        }
+    }
+
+    let [old, new] = keyed;
+    (old, new)
+}
+
+/// The number of levels of [`component_keys`]
+const KEY_LEVELS: usize = 4;
+
+/// The key of the component at the given level, from the most general one at level 0 to the most
+/// specific one, or `None` if the component lacks the details for that level
+fn component_keys(component: &Component, level: usize) -> Option<String> {
+    let key = component_key(component);
+    match level {
+        0 => Some(key),
+        1 => Some(format!("{key}@{}", component.version.to_string())),
+        2 => component.purl.as_ref().map(|purl| purl.to_string()),
+        _ => component
+            .bom_ref
+            .as_ref()
+            .map(|bom_ref| format!("{key}@{}#{bom_ref}", component.version.to_string())),
+    }
+}
+
+fn collect_components<'a>(components: &'a Option<Components>, into: &mut Vec<&'a Component>) {
+    if let Some(components) = components {
+        for component in &components.0 {
+            into.push(component);
+            collect_components(&component.components, into);
+        }
+    }
+}
+
+/// The purl of the component without its version, qualifiers and subpath, or its group and name
+fn component_key(component: &Component) -> String {
+    let purl = component
+        .purl
+        .as_ref()
+        .and_then(|purl| packageurl::PackageUrl::from_str(&purl.to_string()).ok());
+
+    match (purl, &component.group) {
+        (Some(purl), _) => match purl.namespace() {
+            Some(namespace) => format!("pkg:{}/{}/{}", purl.ty(), namespace, purl.name()),
+            None => format!("pkg:{}/{}", purl.ty(), purl.name()),
+        },
+        (None, Some(group)) => format!("{}/{}", group.to_string(), component.name.to_string()),
+        (None, None) => component.name.to_string(),
+    }
+}
+
+fn summarize_component(key: &str, component: &Component) -> ComponentSummary {
+    ComponentSummary {
+        key: key.to_string(),
+        name: component.name.to_string(),
+        version: component.version.to_string(),
+        purl: component.purl.as_ref().map(|purl| purl.to_string()),
+    }
+}
+
+fn compare_components(key: &str, old: &Component, new: &Component) -> Option<ComponentChange> {
+    let version = value_change(old.version.to_string(), new.version.to_string());
+    let licenses = value_change(license_names(&old.licenses), license_names(&new.licenses));
+    let hashes = value_change(hash_values(&old.hashes), hash_values(&new.hashes));
+
+    if version.is_none() && licenses.is_none() && hashes.is_none() {
+        return None;
+    }
+
+    Some(ComponentChange {
+        key: key.to_string(),
+        name: new.name.to_string(),
+        version,
+        licenses,
+        hashes,
+    })
+}
+
+fn value_change<T: PartialEq>(old: T, new: T) -> Option<ValueChange<T>> {
+    if old == new {
+        None
+    } else {
+        Some(ValueChange { old, new })
+    }
+}
+
+fn license_names(licenses: &Option<Licenses>) -> Vec<String> {
+    let mut names: Vec<String> = licenses
+        .iter()
+        .flat_map(|licenses| licenses.0.iter())
+        .map(|license| match license {
+            LicenseChoice::Expression(expression) => expression.to_string(),
+            LicenseChoice::License(license) => match &license.license_identifier {
+                LicenseIdentifier::SpdxId(id) => id.to_string(),
+                LicenseIdentifier::Name(name) => name.to_string(),
+            },
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+fn hash_values(hashes: &Option<Hashes>) -> Vec<String> {
+    let mut values: Vec<String> = hashes
+        .iter()
+        .flat_map(|hashes| hashes.0.iter())
+        .map(|hash| format!("{}:{}", hash.alg.to_string(), hash.content.0))
+        .collect();
+    values.sort();
+    values
+}
+
+/// The dependency edges of the BOM, with `bom-ref`s replaced by component keys where possible
+fn dependency_edges(
+    bom: &Bom,
+    components: &BTreeMap<String, &Component>,
+) -> BTreeSet<DependencyEdge> {
+    let keys_by_bom_ref: HashMap<&str, &str> = components
+        .iter()
+        .filter_map(|(key, component)| {
+            component
+                .bom_ref
+                .as_deref()
+                .map(|bom_ref| (bom_ref, key.as_str()))
+        })
+        .collect();
+    let key = |bom_ref: &str| {
+        keys_by_bom_ref
+            .get(bom_ref)
+            .copied()
+            .unwrap_or(bom_ref)
+            .to_string()
+    };
+
+    bom.dependencies
+        .iter()
+        .flat_map(|dependencies| dependencies.0.iter())
+        .flat_map(|dependency| {
+            let from = key(&dependency.dependency_ref);
+            dependency
+                .dependencies
+                .iter()
+                .map(move |depends_on| DependencyEdge {
+                    from: from.clone(),
+                    to: key(depends_on),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        external_models::uri::Purl,
+        models::{
+            component::Classification,
+            dependency::{Dependencies, Dependency},
+            hash::{Hash, HashAlgorithm, HashValue},
+            license::License,
+        },
+    };
+    use pretty_assertions::assert_eq;
+
+    fn component(name: &str, version: &str) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            version,
+            Some(format!("{name}@{version}")),
+        );
+        component.purl = Some(Purl::new("cargo", name, version).expect("Failed to create purl"));
+        component
+    }
+
+    fn bom(components: Vec<Component>, dependencies: Vec<(&str, &str)>) -> Bom {
+        Bom {
+            components: Some(Components(components)),
+            dependencies: Some(Dependencies(
+                dependencies
+                    .into_iter()
+                    .map(|(from, to)| Dependency {
+                        dependency_ref: from.to_string(),
+                        dependencies: vec![to.to_string()],
+                    })
+                    .collect(),
+            )),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_report_nothing_for_identical_boms() {
+        let old = bom(vec![component("a", "1.0.0")], vec![]);
+        let new = bom(vec![component("a", "1.0.0")], vec![]);
+
+        assert!(diff(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn it_should_report_added_removed_and_changed_components() {
+        let mut old_b = component("b", "1.0.0");
+        old_b.licenses = Some(Licenses(vec![LicenseChoice::License(
+            License::license_id("MIT").expect("Failed to create license"),
+        )]));
+        old_b.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA256,
+            content: HashValue::new("aaaa"),
+        }]));
+        let mut new_b = component("b", "1.0.0");
+        new_b.licenses = Some(Licenses(vec![LicenseChoice::License(
+            License::license_id("Apache-2.0").expect("Failed to create license"),
+        )]));
+        new_b.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA256,
+            content: HashValue::new("bbbb"),
+        }]));
+
+        let old = bom(
+            vec![component("a", "1.0.0"), old_b, component("c", "1.0.0")],
+            vec![("a@1.0.0", "b@1.0.0"), ("a@1.0.0", "c@1.0.0")],
+        );
+        let new = bom(
+            vec![component("a", "2.0.0"), new_b, component("d", "1.0.0")],
+            vec![("a@2.0.0", "b@1.0.0"), ("a@2.0.0", "d@1.0.0")],
+        );
+
+        let actual = diff(&old, &new);
+
+        assert_eq!(
+            actual,
+            BomDiff {
+                added_components: vec![ComponentSummary {
+                    key: "pkg:cargo/d".to_string(),
+                    name: "d".to_string(),
+                    version: "1.0.0".to_string(),
+                    purl: Some("pkg:cargo/d@1.0.0".to_string()),
+                }],
+                removed_components: vec![ComponentSummary {
+                    key: "pkg:cargo/c".to_string(),
+                    name: "c".to_string(),
+                    version: "1.0.0".to_string(),
+                    purl: Some("pkg:cargo/c@1.0.0".to_string()),
+                }],
+                changed_components: vec![
+                    ComponentChange {
+                        key: "pkg:cargo/a".to_string(),
+                        name: "a".to_string(),
+                        version: Some(ValueChange {
+                            old: "1.0.0".to_string(),
+                            new: "2.0.0".to_string(),
+                        }),
+                        licenses: None,
+                        hashes: None,
+                    },
+                    ComponentChange {
+                        key: "pkg:cargo/b".to_string(),
+                        name: "b".to_string(),
+                        version: None,
+                        licenses: Some(ValueChange {
+                            old: vec!["MIT".to_string()],
+                            new: vec!["Apache-2.0".to_string()],
+                        }),
+                        hashes: Some(ValueChange {
+                            old: vec!["SHA-256:aaaa".to_string()],
+                            new: vec!["SHA-256:bbbb".to_string()],
+                        }),
+                    },
+                ],
+                added_dependencies: vec![DependencyEdge {
+                    from: "pkg:cargo/a".to_string(),
+                    to: "pkg:cargo/d".to_string(),
+                }],
+                removed_dependencies: vec![DependencyEdge {
+                    from: "pkg:cargo/a".to_string(),
+                    to: "pkg:cargo/c".to_string(),
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_match_each_version_of_a_package_that_occurs_more_than_once() {
+        let old = bom(
+            vec![component("a", "1.0.0"), component("a", "2.0.0")],
+            vec![],
+        );
+        let new = bom(
+            vec![component("a", "2.0.0"), component("a", "3.0.0")],
+            vec![],
+        );
+
+        let actual = diff(&old, &new);
+
+        assert_eq!(
+            actual
+                .added_components
+                .iter()
+                .map(|c| c.key.as_str())
+                .collect::<Vec<_>>(),
+            vec!["pkg:cargo/a@3.0.0"]
+        );
+        assert_eq!(
+            actual
+                .removed_components
+                .iter()
+                .map(|c| c.key.as_str())
+                .collect::<Vec<_>>(),
+            vec!["pkg:cargo/a@1.0.0"]
+        );
+        assert_eq!(actual.changed_components, vec![]);
+    }
+
+    #[test]
+    fn it_should_serialize_to_json() {
+        let old = bom(vec![component("a", "1.0.0")], vec![]);
+        let new = bom(vec![component("a", "1.1.0")], vec![]);
+
+        let actual = serde_json::to_value(diff(&old, &new)).expect("Failed to serialize diff");
+
+        assert_eq!(
+            actual,
+            serde_json::json!({
+                "addedComponents": [],
+                "removedComponents": [],
+                "changedComponents": [
+                    {
+                        "key": "pkg:cargo/a",
+                        "name": "a",
+                        "version": { "old": "1.0.0", "new": "1.1.0" }
+                    }
+                ],
+                "addedDependencies": [],
+                "removedDependencies": []
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_match_versions_of_a_package_that_occurs_more_than_once_in_the_new_bom() {
+        let old = bom(vec![component("a", "1.0.0")], vec![]);
+        let new = bom(
+            vec![component("a", "1.0.0"), component("a", "2.0.0")],
+            vec![],
+        );
+
+        let actual = diff(&old, &new);
+
+        assert_eq!(
+            actual,
+            BomDiff {
+                added_components: vec![ComponentSummary {
+                    key: "pkg:cargo/a@2.0.0".to_string(),
+                    name: "a".to_string(),
+                    version: "2.0.0".to_string(),
+                    purl: Some("pkg:cargo/a@2.0.0".to_string()),
+                }],
+                ..BomDiff::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_tell_apart_the_same_version_of_a_package_from_different_sources() {
+        let mut git = component("a", "1.0.0");
+        git.purl = Some(
+            Purl::from_parts(
+                "cargo",
+                None,
+                "a",
+                "1.0.0",
+                &[("vcs_url", "git+https://example.com/a.git")],
+                None,
+            )
+            .expect("Failed to create purl"),
+        );
+        git.bom_ref = Some("a@1.0.0 (git)".to_string());
+        let mut path = component("a", "1.0.0");
+        path.bom_ref = Some("a@1.0.0 (path)".to_string());
+
+        let old = bom(vec![component("a", "1.0.0")], vec![]);
+        let new = bom(vec![component("a", "1.0.0"), git, path], vec![]);
+
+        let actual = diff(&old, &new);
+
+        assert_eq!(
+            actual
+                .added_components
+                .iter()
+                .map(|c| c.key.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "pkg:cargo/a@1.0.0#a@1.0.0 (path)",
+                "pkg:cargo/a@1.0.0?vcs_url=git+https://example.com/a.git",
+            ]
+        );
+        assert_eq!(actual.removed_components, vec![]);
+        assert_eq!(actual.changed_components, vec![]);
+    }
+}
@@ -26,6 +26,38 @@ pub enum BomError {
     XmlSerializationError(String),
 }
 
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BomReadError {
+    #[error("Failed to read the document: {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Could not detect the format of the document, expected JSON or XML")]
+    UnknownFormat,
+
+    #[error("The document does not declare a CycloneDX specification version")]
+    MissingSpecVersion,
+
+    #[error("Unsupported CycloneDX specification version: {0}")]
+    UnknownSpecVersion(String),
+
+    #[error(transparent)]
+    JsonReadError(#[from] JsonReadError),
+
+    #[error(transparent)]
+    XmlReadError(#[from] XmlReadError),
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BomWriteError {
+    #[error(transparent)]
+    JsonWriteError(#[from] JsonWriteError),
+
+    #[error(transparent)]
+    XmlWriteError(#[from] XmlWriteError),
+}
+
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum JsonWriteError {
@@ -55,6 +87,15 @@ pub enum JsonReadError {
         #[from]
         error: serde_json::Error,
     },
+
+    #[error("Failed to deserialize JSON at \"{path}\": {error}")]
+    JsonElementReadError {
+        #[source]
+        error: serde_json::Error,
+        /// The location of the element that failed to deserialize as a JSON pointer, e.g.
+        /// `/components/1532/licenses/0`
+        path: String,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -82,6 +123,9 @@ pub enum XmlReadError {
         element: String,
     },
 
+    #[error("The element {element} is not supported yet and cannot be read without losing data")]
+    UnsupportedElement { element: String },
+
     #[error(
         "Expected document to be in the form {expected_namespace}, but received {}", .actual_namespace.as_ref().unwrap_or(&"no CycloneDX namespace".to_string())
     )]
@@ -89,4 +133,109 @@ pub enum XmlReadError {
         expected_namespace: String,
         actual_namespace: Option<String>,
     },
+
+    #[error("{error} at line {}, column {} (byte offset {})", .position.line, .position.column, .position.offset)]
+    PositionedError {
+        error: Box<XmlReadError>,
+        position: DocumentPosition,
+    },
+}
+
+impl XmlReadError {
+    /// The position in the document at which the error was detected, if known
+    pub fn position(&self) -> Option<&DocumentPosition> {
+        match self {
+            XmlReadError::PositionedError { position, .. } => Some(position),
+            _ => None,
+        }
+    }
+}
+
+/// A position within a document that was being read
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DocumentPosition {
+    /// The number of bytes read from the start of the document
+    pub offset: u64,
+    /// The line number, starting at 1
+    pub line: u64,
+    /// The column number within the line, starting at 1
+    pub column: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum MergeError {
+    #[error("BOM at index {index} has no metadata component to nest its components beneath")]
+    MissingMetadataComponent { index: usize },
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum SpdxWriteError {
+    #[error("Failed to serialize SPDX JSON: {0}")]
+    JsonSerializationError(#[from] serde_json::Error),
+
+    #[error("Failed to write SPDX tag-value: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum SpdxReadError {
+    #[error("Failed to deserialize SPDX JSON: {0}")]
+    JsonReadError(#[from] serde_json::Error),
+
+    #[error("Unsupported SPDX version {0}, expected SPDX-2.3")]
+    UnsupportedSpdxVersion(String),
+}
+
+#[cfg(feature = "protobuf")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ProtobufReadError {
+    #[error("Failed to deserialize Protocol Buffers: {0}")]
+    ProtobufError(#[from] protobuf::Error),
+}
+
+#[cfg(feature = "protobuf")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ProtobufWriteError {
+    #[error("Failed to serialize Protocol Buffers: {0}")]
+    ProtobufError(#[from] protobuf::Error),
+
+    #[error("Could not convert {value} on {element} to a Protocol Buffers timestamp")]
+    InvalidTimestamp { value: String, element: String },
+}
+
+#[cfg(feature = "signature")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum SignatureError {
+    #[error("Failed to read the key: {0}")]
+    KeyReadError(#[from] std::io::Error),
+
+    #[error("Expected a PEM encoded P-256, RSA or Ed25519 key")]
+    UnsupportedKey,
+
+    #[error("Failed to read the JSON document: {0}")]
+    JsonReadError(#[from] serde_json::Error),
+
+    #[error("Failed to read the XML document: {0}")]
+    XmlReadError(#[from] xml::reader::Error),
+
+    #[error("Invalid document: {0}")]
+    InvalidDocument(String),
+
+    #[error("The document is not signed")]
+    MissingSignature,
+
+    #[error("Unsupported signature algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+
+    #[error("The document was signed with {actual}, but the key is for {expected}")]
+    AlgorithmMismatch { expected: String, actual: String },
+
+    #[error("The signature does not match the document")]
+    InvalidSignature,
 }
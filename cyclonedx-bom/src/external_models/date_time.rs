@@ -19,10 +19,13 @@
 use std::convert::TryFrom;
 
 use thiserror::Error;
-use time::{format_description::well_known::Iso8601, OffsetDateTime};
+use time::{
+    format_description::well_known::{Iso8601, Rfc3339},
+    OffsetDateTime,
+};
 
 use crate::validation::{
-    FailureReason, Validate, ValidationContext, ValidationError, ValidationResult,
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationResult,
 };
 
 /// For the purposes of CycloneDX SBOM documents, `DateTime` is a ISO8601 formatted timestamp
@@ -50,6 +53,16 @@ impl DateTime {
             .map_err(|_| DateTimeError::FailedCurrentTime)?;
         Ok(Self(now))
     }
+
+    /// Create a timestamp from a number of seconds since the Unix epoch, as used by
+    /// [`SOURCE_DATE_EPOCH`](https://reproducible-builds.org/specs/source-date-epoch/)
+    pub fn from_unix_timestamp(seconds: i64) -> Result<Self, DateTimeError> {
+        let date_time = OffsetDateTime::from_unix_timestamp(seconds)
+            .map_err(|e| DateTimeError::InvalidDateTime(format!("{}", e)))?
+            .format(&Rfc3339)
+            .map_err(|e| DateTimeError::InvalidDateTime(format!("{}", e)))?;
+        Ok(Self(date_time))
+    }
 }
 
 impl TryFrom<String> for DateTime {
@@ -75,6 +88,7 @@ impl Validate for DateTime {
             Ok(_) => Ok(ValidationResult::Passed),
             Err(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "DateTime does not conform to ISO 8601".to_string(),
                     context,
                 }],
@@ -113,6 +127,20 @@ mod test {
         assert_eq!(validation_result, ValidationResult::Passed)
     }
 
+    #[test]
+    fn it_should_create_a_datetime_from_a_unix_timestamp() {
+        let date_time = DateTime::from_unix_timestamp(1700000000).expect("Failed to create");
+
+        assert_eq!(date_time.to_string(), "2023-11-14T22:13:20Z");
+        assert_eq!(
+            date_time
+                .validate_with_context(ValidationContext::default())
+                .expect("Error while validating"),
+            ValidationResult::Passed
+        );
+        assert!(DateTime::from_unix_timestamp(i64::MAX).is_err());
+    }
+
     #[test]
     fn invalid_datetimes_should_fail_validation() {
         let validation_result = DateTime("invalid date".to_string())
@@ -123,6 +151,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "DateTime does not conform to ISO 8601".to_string(),
                     context: ValidationContext::default()
                 }]
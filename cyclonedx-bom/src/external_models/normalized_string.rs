@@ -17,7 +17,7 @@
  */
 
 use crate::validation::{
-    FailureReason, Validate, ValidationContext, ValidationError, ValidationResult,
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationResult,
 };
 
 /// A string that does not contain carriage return, line feed, or tab characters
@@ -63,6 +63,7 @@ impl Validate for NormalizedString {
         {
             return Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
                     context,
@@ -115,6 +116,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
                     context: ValidationContext::default()
@@ -21,7 +21,7 @@ use std::convert::TryFrom;
 use spdx::{Expression, ParseMode};
 use thiserror::Error;
 
-use crate::validation::{FailureReason, Validate, ValidationResult};
+use crate::validation::{FailureReason, Severity, Validate, ValidationResult};
 
 /// An identifier for a single, specific license
 ///
@@ -89,6 +89,7 @@ impl Validate for SpdxIdentifier {
             Ok(_) => Ok(ValidationResult::Passed),
             Err(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "SPDX identifier is not valid".to_string(),
                     context,
                 }],
@@ -189,6 +190,7 @@ impl Validate for SpdxExpression {
             Ok(_) => Ok(ValidationResult::Passed),
             Err(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "SPDX expression is not valid".to_string(),
                     context,
                 }],
@@ -274,6 +276,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "SPDX identifier is not valid".to_string(),
                     context: ValidationContext::default()
                 }]
@@ -324,6 +327,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "SPDX expression is not valid".to_string(),
                     context: ValidationContext::default()
                 }]
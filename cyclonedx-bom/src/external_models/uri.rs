@@ -22,7 +22,7 @@ use packageurl::PackageUrl;
 use thiserror::Error;
 
 use crate::validation::{
-    FailureReason, Validate, ValidationContext, ValidationError, ValidationResult,
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationResult,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -30,10 +30,53 @@ pub struct Purl(pub(crate) String);
 
 impl Purl {
     pub fn new(package_type: &str, name: &str, version: &str) -> Result<Purl, UriError> {
-        match packageurl::PackageUrl::new(package_type, name) {
-            Ok(mut purl) => Ok(Self(purl.with_version(version.trim()).to_string())),
-            Err(e) => Err(UriError::InvalidPurl(e.to_string())),
+        Self::from_parts(package_type, None, name, version, &[], None)
+    }
+
+    /// Construct a `Purl` including the optional namespace, qualifiers and subpath components
+    /// ```
+    /// use cyclonedx_bom::external_models::uri::Purl;
+    ///
+    /// let purl = Purl::from_parts(
+    ///     "cargo",
+    ///     None,
+    ///     "cyclonedx-bom",
+    ///     "0.3.1",
+    ///     &[("repository_url", "https://example.com/index")],
+    ///     None,
+    /// ).expect("Failed to create purl");
+    /// assert_eq!(
+    ///     purl.to_string(),
+    ///     "pkg:cargo/cyclonedx-bom@0.3.1?repository_url=https://example.com/index"
+    /// );
+    /// ```
+    pub fn from_parts(
+        package_type: &str,
+        namespace: Option<&str>,
+        name: &str,
+        version: &str,
+        qualifiers: &[(&str, &str)],
+        subpath: Option<&str>,
+    ) -> Result<Purl, UriError> {
+        let mut purl = PackageUrl::new(package_type, name)
+            .map_err(|e| UriError::InvalidPurl(e.to_string()))?;
+        purl.with_version(version.trim());
+
+        if let Some(namespace) = namespace {
+            purl.with_namespace(namespace);
+        }
+
+        for (key, value) in qualifiers {
+            purl.add_qualifier(*key, *value)
+                .map_err(|e| UriError::InvalidPurl(e.to_string()))?;
+        }
+
+        if let Some(subpath) = subpath {
+            purl.with_subpath(subpath)
+                .map_err(|e| UriError::InvalidPurl(e.to_string()))?;
         }
+
+        Ok(Self(purl.to_string()))
     }
 }
 
@@ -52,6 +95,7 @@ impl Validate for Purl {
             Ok(_) => Ok(ValidationResult::Passed),
             Err(e) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: format!("Purl does not conform to Package URL spec: {}", e),
                     context,
                 }],
@@ -85,6 +129,7 @@ impl Validate for Uri {
             Ok(_) => Ok(ValidationResult::Passed),
             Err(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Uri does not conform to ISO 8601".to_string(),
                     context,
                 }],
@@ -123,6 +168,39 @@ mod test {
         assert_eq!(validation_result, ValidationResult::Passed);
     }
 
+    #[test]
+    fn it_should_build_purls_from_all_parts() {
+        let purl = Purl::from_parts(
+            "cargo",
+            Some("namespace"),
+            "cyclonedx-bom",
+            "0.3.1",
+            &[
+                ("vcs_url", "git+https://example.com/repo.git@abc123"),
+                ("download_url", "https://example.com/download"),
+            ],
+            Some("src/lib.rs"),
+        )
+        .expect("Failed to create purl");
+
+        assert_eq!(
+            purl.to_string(),
+            "pkg:cargo/namespace/cyclonedx-bom@0.3.1?download_url=https://example.com/download&vcs_url=git+https://example.com/repo.git%40abc123#src/lib.rs"
+        );
+        assert_eq!(
+            purl.validate_with_context(ValidationContext::default())
+                .expect("Error while validating"),
+            ValidationResult::Passed
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_qualifier_keys() {
+        let result = Purl::from_parts("cargo", None, "cyclonedx-bom", "0.3.1", &[("", "x")], None);
+
+        assert!(matches!(result, Err(UriError::InvalidPurl(_))));
+    }
+
     #[test]
     fn invalid_purls_should_fail_validation() {
         let validation_result = Purl("invalid purl".to_string())
@@ -133,6 +211,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Purl does not conform to Package URL spec: missing scheme"
                         .to_string(),
                     context: ValidationContext::default()
@@ -160,6 +239,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Uri does not conform to ISO 8601".to_string(),
                     context: ValidationContext::default()
                 }]
@@ -0,0 +1,90 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::io::Read;
+
+use serde::de::DeserializeOwned;
+use serde_path_to_error::{Path, Segment};
+
+use crate::errors::JsonReadError;
+
+/// Deserialize a JSON document, keeping track of where in the document an error occurred
+pub(crate) fn read_json_document<T: DeserializeOwned, R: Read>(
+    reader: R,
+) -> Result<T, JsonReadError> {
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let document = serde_path_to_error::deserialize(&mut deserializer).map_err(|error| {
+        let path = json_pointer(error.path());
+        JsonReadError::JsonElementReadError {
+            error: error.into_inner(),
+            path,
+        }
+    })?;
+    deserializer.end()?;
+    Ok(document)
+}
+
+/// Render the path as a [JSON pointer](https://www.rfc-editor.org/rfc/rfc6901), e.g.
+/// `/components/1532/licenses/0`
+pub(crate) fn json_pointer(path: &Path) -> String {
+    path.iter()
+        .filter_map(|segment| match segment {
+            Segment::Seq { index } => Some(index.to_string()),
+            Segment::Map { key } => Some(key.clone()),
+            Segment::Enum { variant } => Some(variant.clone()),
+            Segment::Unknown => None,
+        })
+        .map(|token| format!("/{}", token.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Document {
+        #[allow(dead_code)]
+        items: Vec<Item>,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Item {
+        #[allow(dead_code)]
+        #[serde(rename = "a/b")]
+        value: u32,
+    }
+
+    #[test]
+    fn it_should_report_the_json_pointer_of_the_failing_element() {
+        let input = r#"{ "items": [ { "a/b": 1 }, { "a/b": "two" } ] }"#;
+
+        let error =
+            read_json_document::<Document, _>(input.as_bytes()).expect_err("Expected an error");
+
+        match error {
+            JsonReadError::JsonElementReadError { path, error } => {
+                assert_eq!(path, "/items/1/a~1b");
+                assert_eq!(error.line(), 1);
+                assert_eq!(error.column(), 41);
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+    }
+}
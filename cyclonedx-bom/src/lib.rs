@@ -29,8 +29,8 @@
 //!
 //! - Construct SBOM documents that conform the CycloneDX specification
 //! - Parse and validate JSON and XML SBOM documents
-//! - Perform modifications to BOM documents (e.g. merging multiple BOMs using a variety of
-//!   algorithms)
+//! - Perform modifications to BOM documents (e.g. [merging](crate::merge) multiple BOMs using a
+//!   variety of algorithms)
 //!
 //! ## Read and validate an SBOM
 //!
@@ -53,6 +53,19 @@
 //! assert_eq!(validation_result, ValidationResult::Passed);
 //! ```
 //!
+//! Issues that do not make a BOM invalid, such as a library without a package URL, are reported
+//! as warnings in [`ValidationResult::PassedWithWarnings`](crate::validation::ValidationResult::PassedWithWarnings).
+//! The context of each reason can be displayed as a path, e.g. `Bom.components[3].purl`.
+//!
+//! If the format or specification version of the input is not known ahead of time, use
+//! [`Bom::parse`](crate::models::bom::Bom::parse), which detects both and returns the
+//! [`SpecVersion`](crate::models::bom::SpecVersion) it found alongside the BOM.
+//!
+//! With the `json-schema` feature, raw JSON documents can also be checked against the CycloneDX
+//! JSON schemas with `schema::validate_json`. There is no
+//! equivalent for XML: documents are not validated against the CycloneDX XSDs, so XML input is
+//! only checked by [`Validate`](crate::validation::Validate) after it has been parsed.
+//!
 //! ## Create and output an SBOM
 //!
 //! Given an output implements [std::io::Write], output the [`Bom`](crate::models::bom::Bom) as
@@ -70,7 +83,7 @@
 //!             .expect("Failed to create UrnUuid"),
 //!     ),
 //!     metadata: Some(Metadata {
-//!         tools: Some(Tools(vec![Tool {
+//!         tools: Some(Tools::List(vec![Tool {
 //!             name: Some(NormalizedString::new("my_tool")),
 //!             ..Tool::default()
 //!         }])),
@@ -125,12 +138,24 @@
 //! use cyclonedx_bom::prelude::*;
 //! ```
 
+pub mod conversion;
+pub mod diff;
 pub mod errors;
 pub mod external_models;
+pub mod merge;
 pub mod models;
 pub mod prelude;
+#[cfg(feature = "json-schema")]
+pub mod schema;
+#[cfg(feature = "signature")]
+pub mod signature;
+pub mod stream;
 pub mod validation;
 
+mod json;
+#[cfg(feature = "protobuf")]
+mod proto;
+mod spdx_document;
 mod specs;
 mod utilities;
 mod xml;
@@ -0,0 +1,934 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Combine multiple BOMs into a single BOM.
+//!
+//! Two strategies are available:
+//!
+//! - [`flat_merge`] places the components of every input next to each other at the top level of
+//!   the result, removing duplicates
+//! - [`hierarchical_merge`] nests the components of every input beneath that input's
+//!   `metadata.component`, keeping each input recognisable in the result
+//!
+//! Both strategies merge the components, services, external references, dependencies,
+//! compositions, properties, vulnerabilities, annotations and formulation of the inputs, as well as
+//! the tools in their metadata. Because a `bom-ref` has to be unique within a document, a `bom-ref`
+//! that has already been used by an earlier input is renamed by appending a numeric suffix (e.g.
+//! `pkg-a` becomes `pkg-a-1`), and all references to it from within the same input are rewritten
+//! to match.
+//!
+//! The remaining fields describe an input document rather than its contents, such as its
+//! `signature` or `metadata.timestamp`, and cannot be carried over to the result. They are reported
+//! as [`ConversionLoss`]es whose paths are prefixed with the index of the input, e.g.
+//! `boms[1].metadata.timestamp`.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::conversion::{ConversionLoss, ConversionLossKind};
+use crate::errors::MergeError;
+use crate::models::{
+    annotation::{Annotation, Annotations, Annotator},
+    bom::Bom,
+    component::{Component, Components},
+    composition::{BomReference, Compositions},
+    dependency::{Dependencies, Dependency},
+    external_reference::ExternalReferences,
+    formulation::{Formula, Formulation},
+    metadata::Metadata,
+    property::{Properties, Property},
+    service::{Service, Services},
+    signature::Signature,
+    tool::Tools,
+    vulnerability::Vulnerabilities,
+};
+
+/// Merge the BOMs into one, placing the components of all inputs at the top level.
+///
+/// The `metadata.component` of every input is included as a regular component. Components are
+/// considered duplicates of each other if they share a purl, or if they share a `bom-ref` and are
+/// otherwise identical; only the first occurrence is kept, the nested components of the others are
+/// merged into it, and references to the others are redirected to it.
+///
+/// Returns the merged BOM together with the fields of the inputs that could not be carried over.
+pub fn flat_merge(boms: Vec<Bom>) -> (Bom, Vec<ConversionLoss>) {
+    let mut merger = Merger::default();
+
+    for (index, bom) in boms.into_iter().enumerate() {
+        let mut bom_refs = HashMap::new();
+
+        let metadata = bom.metadata.unwrap_or_default();
+        merger.report_document_fields(index, &metadata, &bom.signature);
+        merger.merge_tools(metadata.tools);
+
+        let mut components: Vec<Component> = metadata.component.into_iter().collect();
+        components.extend(bom.components.map(|c| c.0).unwrap_or_default());
+
+        for component in components {
+            merger.add_flat_component(component, &mut bom_refs);
+        }
+
+        merger.merge_shared(
+            bom.services,
+            bom.external_references,
+            bom.properties,
+            &mut bom_refs,
+        );
+        merger.merge_references(
+            References {
+                dependencies: bom.dependencies,
+                compositions: bom.compositions,
+                vulnerabilities: bom.vulnerabilities,
+                annotations: bom.annotations,
+                formulation: bom.formulation,
+            },
+            &mut bom_refs,
+        );
+    }
+
+    merger.into_bom(None)
+}
+
+/// Merge the BOMs into one, nesting the components of every input beneath its
+/// `metadata.component`.
+///
+/// The optional `bom_subject` becomes the `metadata.component` of the result, and depends on the
+/// `metadata.component` of every input. Returns the merged BOM together with the fields of the
+/// inputs that could not be carried over.
+///
+/// # Errors
+///
+/// Returns an error if any of the inputs does not have a `metadata.component` to nest its
+/// components beneath
+pub fn hierarchical_merge(
+    boms: Vec<Bom>,
+    bom_subject: Option<Component>,
+) -> Result<(Bom, Vec<ConversionLoss>), MergeError> {
+    let mut merger = Merger::default();
+
+    let mut bom_subject = bom_subject;
+    if let Some(subject) = &mut bom_subject {
+        merger.claim_component(subject, &mut HashMap::new());
+    }
+
+    let mut parent_refs = Vec::new();
+    for (index, bom) in boms.into_iter().enumerate() {
+        let mut bom_refs = HashMap::new();
+
+        let metadata = bom.metadata.unwrap_or_default();
+        merger.report_document_fields(index, &metadata, &bom.signature);
+        merger.merge_tools(metadata.tools);
+        let mut parent = metadata
+            .component
+            .ok_or(MergeError::MissingMetadataComponent { index })?;
+
+        if let Some(components) = bom.components {
+            parent
+                .components
+                .get_or_insert_with(|| Components(Vec::new()))
+                .0
+                .extend(components.0);
+        }
+
+        merger.claim_component(&mut parent, &mut bom_refs);
+        if let Some(bom_ref) = &parent.bom_ref {
+            parent_refs.push(bom_ref.clone());
+        }
+        merger.components.push(parent);
+
+        merger.merge_shared(
+            bom.services,
+            bom.external_references,
+            bom.properties,
+            &mut bom_refs,
+        );
+        merger.merge_references(
+            References {
+                dependencies: bom.dependencies,
+                compositions: bom.compositions,
+                vulnerabilities: bom.vulnerabilities,
+                annotations: bom.annotations,
+                formulation: bom.formulation,
+            },
+            &mut bom_refs,
+        );
+    }
+
+    if let Some(subject_ref) = bom_subject.as_ref().and_then(|s| s.bom_ref.clone()) {
+        if !parent_refs.is_empty() {
+            merger.merge_dependency(Dependency {
+                dependency_ref: subject_ref,
+                dependencies: parent_refs,
+            });
+        }
+    }
+
+    Ok(merger.into_bom(bom_subject))
+}
+
+/// The parts of an input that refer to components and services by their `bom-ref`
+struct References {
+    dependencies: Option<Dependencies>,
+    compositions: Option<Compositions>,
+    vulnerabilities: Option<Vulnerabilities>,
+    annotations: Option<Annotations>,
+    formulation: Option<Formulation>,
+}
+
+/// The result of a merge that is being built up from its inputs
+#[derive(Default)]
+struct Merger {
+    bom_refs: HashSet<String>,
+    tools: Vec<crate::models::tool::Tool>,
+    tool_components: Vec<Component>,
+    tool_services: Vec<Service>,
+    components: Vec<Component>,
+    services: Vec<Service>,
+    external_references: Vec<crate::models::external_reference::ExternalReference>,
+    dependencies: Vec<Dependency>,
+    compositions: Vec<crate::models::composition::Composition>,
+    properties: Vec<Property>,
+    vulnerabilities: Vec<crate::models::vulnerability::Vulnerability>,
+    annotations: Vec<Annotation>,
+    formulation: Vec<Formula>,
+    losses: Vec<ConversionLoss>,
+}
+
+impl Merger {
+    /// Reserve a unique `bom-ref` for the value, renaming it if it has already been used, and
+    /// record the mapping from its original `bom-ref` for the current input
+    fn claim(&mut self, bom_ref: &mut Option<String>, bom_refs: &mut HashMap<String, String>) {
+        if let Some(original) = bom_ref.take() {
+            let mut unique = original.clone();
+            let mut suffix = 0;
+            while self.bom_refs.contains(&unique) {
+                suffix += 1;
+                unique = format!("{original}-{suffix}");
+            }
+
+            self.bom_refs.insert(unique.clone());
+            bom_refs.insert(original, unique.clone());
+            *bom_ref = Some(unique);
+        }
+    }
+
+    /// Like [`Merger::claim`], for the `bom-ref`s that the specification requires
+    fn claim_required(&mut self, bom_ref: &mut String, bom_refs: &mut HashMap<String, String>) {
+        let mut claimed = Some(std::mem::take(bom_ref));
+        self.claim(&mut claimed, bom_refs);
+        *bom_ref = claimed.unwrap_or_default();
+    }
+
+    fn claim_component(
+        &mut self,
+        component: &mut Component,
+        bom_refs: &mut HashMap<String, String>,
+    ) {
+        self.claim(&mut component.bom_ref, bom_refs);
+        if let Some(components) = &mut component.components {
+            for component in components.0.iter_mut() {
+                self.claim_component(component, bom_refs);
+            }
+        }
+    }
+
+    fn claim_service(&mut self, service: &mut Service, bom_refs: &mut HashMap<String, String>) {
+        self.claim(&mut service.bom_ref, bom_refs);
+        if let Some(services) = &mut service.services {
+            for service in services.0.iter_mut() {
+                self.claim_service(service, bom_refs);
+            }
+        }
+    }
+
+    fn claim_formula(&mut self, formula: &mut Formula, bom_refs: &mut HashMap<String, String>) {
+        self.claim(&mut formula.bom_ref, bom_refs);
+        if let Some(components) = &mut formula.components {
+            for component in components.0.iter_mut() {
+                self.claim_component(component, bom_refs);
+            }
+        }
+        if let Some(services) = &mut formula.services {
+            for service in services.0.iter_mut() {
+                self.claim_service(service, bom_refs);
+            }
+        }
+        for workflow in formula.workflows.iter_mut().flatten() {
+            self.claim_required(&mut workflow.bom_ref, bom_refs);
+            for task in workflow.tasks.iter_mut().flatten() {
+                self.claim_required(&mut task.bom_ref, bom_refs);
+            }
+        }
+    }
+
+    fn add_flat_component(&mut self, component: Component, bom_refs: &mut HashMap<String, String>) {
+        let mut components = std::mem::take(&mut self.components);
+        self.add_component(&mut components, component, bom_refs);
+        self.components = components;
+    }
+
+    /// Add the component to the list, unless it duplicates one that is already in it. The nested
+    /// components of a duplicate are merged into those of the component it duplicates.
+    fn add_component(
+        &mut self,
+        components: &mut Vec<Component>,
+        mut component: Component,
+        bom_refs: &mut HashMap<String, String>,
+    ) {
+        let duplicate = components.iter().position(|existing| {
+            let same_purl = component.purl.is_some() && existing.purl == component.purl;
+            let same_bom_ref = component.bom_ref.is_some()
+                && existing.bom_ref == component.bom_ref
+                && *existing == component;
+            same_purl || same_bom_ref || (component.bom_ref.is_none() && *existing == component)
+        });
+
+        match duplicate {
+            Some(index) => {
+                let existing = &mut components[index];
+                if let (Some(original), Some(existing)) = (&component.bom_ref, &existing.bom_ref) {
+                    bom_refs.insert(original.clone(), existing.clone());
+                }
+
+                if let Some(nested) = component.components {
+                    let mut existing_nested =
+                        existing.components.take().map(|c| c.0).unwrap_or_default();
+                    for component in nested.0 {
+                        self.add_component(&mut existing_nested, component, bom_refs);
+                    }
+                    components[index].components = non_empty(existing_nested).map(Components);
+                }
+            }
+            None => {
+                self.claim_component(&mut component, bom_refs);
+                components.push(component);
+            }
+        }
+    }
+
+    fn add_service(&mut self, mut service: Service, bom_refs: &mut HashMap<String, String>) {
+        let duplicate = self.services.iter().find(|existing| {
+            let same_bom_ref = service.bom_ref.is_some() && existing.bom_ref == service.bom_ref;
+            (same_bom_ref || service.bom_ref.is_none()) && **existing == service
+        });
+
+        match duplicate {
+            Some(existing) => {
+                if let (Some(original), Some(existing)) = (&service.bom_ref, &existing.bom_ref) {
+                    bom_refs.insert(original.clone(), existing.clone());
+                }
+            }
+            None => {
+                self.claim_service(&mut service, bom_refs);
+                self.services.push(service);
+            }
+        }
+    }
+
+    fn merge_tools(&mut self, tools: Option<Tools>) {
+        match tools {
+            Some(Tools::List(tools)) => {
+                for tool in tools {
+                    if !self.tools.contains(&tool) {
+                        self.tools.push(tool);
+                    }
+                }
+            }
+            Some(Tools::Object {
+                components,
+                services,
+            }) => {
+                for component in components.map(|c| c.0).unwrap_or_default() {
+                    if !self.tool_components.contains(&component) {
+                        self.tool_components.push(component);
+                    }
+                }
+                for service in services.map(|s| s.0).unwrap_or_default() {
+                    if !self.tool_services.contains(&service) {
+                        self.tool_services.push(service);
+                    }
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// The tools of all inputs, which are only listed as components and services if an input
+    /// already used that form
+    fn take_tools(&mut self) -> Option<Tools> {
+        let tools = std::mem::take(&mut self.tools);
+        if self.tool_components.is_empty() && self.tool_services.is_empty() {
+            return non_empty(tools).map(Tools::List);
+        }
+
+        let mut components = std::mem::take(&mut self.tool_components);
+        for component in tools.into_iter().map(Component::from) {
+            if !components.contains(&component) {
+                components.push(component);
+            }
+        }
+
+        Some(Tools::Object {
+            components: non_empty(components).map(Components),
+            services: non_empty(std::mem::take(&mut self.tool_services)).map(Services),
+        })
+    }
+
+    /// Report the fields that describe the input document itself, which have no place in the result
+    fn report_document_fields(
+        &mut self,
+        index: usize,
+        metadata: &Metadata,
+        signature: &Option<Signature>,
+    ) {
+        let fields = [
+            ("metadata.timestamp", metadata.timestamp.is_some()),
+            ("metadata.lifecycles", metadata.lifecycles.is_some()),
+            ("metadata.authors", metadata.authors.is_some()),
+            ("metadata.manufacture", metadata.manufacture.is_some()),
+            ("metadata.supplier", metadata.supplier.is_some()),
+            ("metadata.licenses", metadata.licenses.is_some()),
+            ("metadata.properties", metadata.properties.is_some()),
+            ("signature", signature.is_some()),
+        ];
+
+        for (field, present) in fields {
+            if present {
+                self.losses.push(ConversionLoss {
+                    path: format!("boms[{index}].{field}"),
+                    kind: ConversionLossKind::Dropped,
+                });
+            }
+        }
+    }
+
+    /// Merge the parts of an input that can be identified by their `bom-ref` or content
+    fn merge_shared(
+        &mut self,
+        services: Option<Services>,
+        external_references: Option<ExternalReferences>,
+        properties: Option<Properties>,
+        bom_refs: &mut HashMap<String, String>,
+    ) {
+        for service in services.map(|s| s.0).unwrap_or_default() {
+            self.add_service(service, bom_refs);
+        }
+
+        for external_reference in external_references.map(|e| e.0).unwrap_or_default() {
+            if !self.external_references.contains(&external_reference) {
+                self.external_references.push(external_reference);
+            }
+        }
+
+        for property in properties.map(|p| p.0).unwrap_or_default() {
+            if !self.properties.contains(&property) {
+                self.properties.push(property);
+            }
+        }
+    }
+
+    /// Merge the parts of an input that refer to components and services, rewriting their
+    /// references to any `bom-ref` that was renamed or deduplicated
+    fn merge_references(&mut self, references: References, bom_refs: &mut HashMap<String, String>) {
+        let mut annotations = references.annotations.map(|a| a.0).unwrap_or_default();
+        for annotation in annotations.iter_mut() {
+            self.claim(&mut annotation.bom_ref, bom_refs);
+            match &mut annotation.annotator {
+                Annotator::Component(component) => self.claim_component(component, bom_refs),
+                Annotator::Service(service) => self.claim_service(service, bom_refs),
+                Annotator::Organization(_) | Annotator::Individual(_) => {}
+            }
+        }
+
+        let mut formulation = references.formulation.map(|f| f.0).unwrap_or_default();
+        for formula in formulation.iter_mut() {
+            self.claim_formula(formula, bom_refs);
+        }
+
+        let bom_refs = &*bom_refs;
+        let rewrite = |bom_ref: String| bom_refs.get(&bom_ref).cloned().unwrap_or(bom_ref);
+        let rewrite_dependency = |dependency: Dependency| Dependency {
+            dependency_ref: rewrite(dependency.dependency_ref),
+            dependencies: dependency.dependencies.into_iter().map(rewrite).collect(),
+        };
+
+        for dependency in references.dependencies.map(|d| d.0).unwrap_or_default() {
+            self.merge_dependency(rewrite_dependency(dependency));
+        }
+
+        for mut composition in references.compositions.map(|c| c.0).unwrap_or_default() {
+            let rewrite_all = |references: Option<Vec<BomReference>>| {
+                references.map(|references| {
+                    references
+                        .into_iter()
+                        .map(|reference| BomReference(rewrite(reference.0)))
+                        .collect()
+                })
+            };
+            composition.assemblies = rewrite_all(composition.assemblies);
+            composition.dependencies = rewrite_all(composition.dependencies);
+
+            if !self.compositions.contains(&composition) {
+                self.compositions.push(composition);
+            }
+        }
+
+        for mut vulnerability in references.vulnerabilities.map(|v| v.0).unwrap_or_default() {
+            if let Some(affects) = &mut vulnerability.affects {
+                for target in affects.iter_mut() {
+                    target.bom_ref = rewrite(std::mem::take(&mut target.bom_ref));
+                }
+            }
+
+            if !self.vulnerabilities.contains(&vulnerability) {
+                self.vulnerabilities.push(vulnerability);
+            }
+        }
+
+        for mut annotation in annotations {
+            annotation.subjects = annotation.subjects.into_iter().map(rewrite).collect();
+            if !self.annotations.contains(&annotation) {
+                self.annotations.push(annotation);
+            }
+        }
+
+        for mut formula in formulation {
+            for workflow in formula.workflows.iter_mut().flatten() {
+                if let Some(task_dependencies) = &mut workflow.task_dependencies {
+                    task_dependencies.0 = std::mem::take(&mut task_dependencies.0)
+                        .into_iter()
+                        .map(rewrite_dependency)
+                        .collect();
+                }
+            }
+            self.formulation.push(formula);
+        }
+    }
+
+    fn merge_dependency(&mut self, dependency: Dependency) {
+        match self
+            .dependencies
+            .iter_mut()
+            .find(|existing| existing.dependency_ref == dependency.dependency_ref)
+        {
+            Some(existing) => {
+                for depends_on in dependency.dependencies {
+                    if !existing.dependencies.contains(&depends_on) {
+                        existing.dependencies.push(depends_on);
+                    }
+                }
+            }
+            None => self.dependencies.push(dependency),
+        }
+    }
+
+    fn into_bom(mut self, bom_subject: Option<Component>) -> (Bom, Vec<ConversionLoss>) {
+        let tools = self.take_tools();
+        let metadata = if tools.is_none() && bom_subject.is_none() {
+            None
+        } else {
+            Some(Metadata {
+                tools,
+                component: bom_subject,
+                ..Metadata::default()
+            })
+        };
+
+        let bom = Bom {
+            metadata,
+            components: non_empty(self.components).map(Components),
+            services: non_empty(self.services).map(Services),
+            external_references: non_empty(self.external_references).map(ExternalReferences),
+            dependencies: non_empty(self.dependencies).map(Dependencies),
+            compositions: non_empty(self.compositions).map(Compositions),
+            properties: non_empty(self.properties).map(Properties),
+            vulnerabilities: non_empty(self.vulnerabilities).map(Vulnerabilities),
+            annotations: non_empty(self.annotations).map(Annotations),
+            formulation: non_empty(self.formulation).map(Formulation),
+            ..Bom::default()
+        };
+
+        (bom, self.losses)
+    }
+}
+
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Purl},
+        models::{
+            component::Classification,
+            composition::{AggregateType, Composition},
+            formulation::{Task, Workflow},
+            organization::OrganizationalEntity,
+            signature::Algorithm,
+            tool::Tool,
+            vulnerability::{Vulnerability, VulnerabilityTarget},
+        },
+    };
+    use pretty_assertions::assert_eq;
+
+    fn component(name: &str, bom_ref: &str) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(bom_ref.to_string()),
+        );
+        component.purl = Some(Purl::new("cargo", name, "1.0.0").expect("Failed to create purl"));
+        component
+    }
+
+    fn dependency(dependency_ref: &str, dependencies: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: dependency_ref.to_string(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    fn bom(
+        metadata_component: Option<Component>,
+        components: Vec<Component>,
+        dependencies: Vec<Dependency>,
+    ) -> Bom {
+        Bom {
+            serial_number: None,
+            metadata: metadata_component.map(|component| Metadata {
+                component: Some(component),
+                ..Metadata::default()
+            }),
+            components: Some(Components(components)),
+            dependencies: Some(Dependencies(dependencies)),
+            ..Bom::default()
+        }
+    }
+
+    fn bom_refs(components: &Option<Components>) -> Vec<String> {
+        components
+            .as_ref()
+            .map(|c| c.0.iter().filter_map(|c| c.bom_ref.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn it_should_deduplicate_components_with_the_same_purl() {
+        let first = bom(
+            Some(component("app-one", "app-one")),
+            vec![component("shared", "shared-1")],
+            vec![dependency("app-one", &["shared-1"])],
+        );
+        let second = bom(
+            Some(component("app-two", "app-two")),
+            vec![component("shared", "shared-2")],
+            vec![dependency("app-two", &["shared-2"])],
+        );
+
+        let (merged, _) = flat_merge(vec![first, second]);
+
+        assert_eq!(
+            bom_refs(&merged.components),
+            vec!["app-one", "shared-1", "app-two"]
+        );
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![
+                dependency("app-one", &["shared-1"]),
+                dependency("app-two", &["shared-1"]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_rename_conflicting_bom_refs_and_rewrite_references() {
+        let mut first = bom(
+            None,
+            vec![component("first", "pkg")],
+            vec![dependency("pkg", &[])],
+        );
+        first.compositions = Some(Compositions(vec![Composition {
+            aggregate: AggregateType::Complete,
+            assemblies: Some(vec![BomReference("pkg".to_string())]),
+            dependencies: None,
+            signature: None,
+        }]));
+
+        let mut second = bom(
+            None,
+            vec![component("second", "pkg"), component("third", "pkg-1")],
+            vec![dependency("pkg", &["pkg-1"])],
+        );
+        second.compositions = Some(Compositions(vec![Composition {
+            aggregate: AggregateType::Incomplete,
+            assemblies: Some(vec![BomReference("pkg".to_string())]),
+            dependencies: None,
+            signature: None,
+        }]));
+        let mut vulnerability = Vulnerability::new(None, "CVE-2023-1234");
+        vulnerability.affects = Some(vec![VulnerabilityTarget::new("pkg-1")]);
+        second.vulnerabilities = Some(Vulnerabilities(vec![vulnerability]));
+
+        let (merged, _) = flat_merge(vec![first, second]);
+
+        assert_eq!(
+            bom_refs(&merged.components),
+            vec!["pkg", "pkg-1", "pkg-1-1"]
+        );
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![
+                dependency("pkg", &[]),
+                dependency("pkg-1", &["pkg-1-1"]),
+            ]))
+        );
+        assert_eq!(
+            merged.compositions.expect("Expected compositions").0[1].assemblies,
+            Some(vec![BomReference("pkg-1".to_string())])
+        );
+        assert_eq!(
+            merged.vulnerabilities.expect("Expected vulnerabilities").0[0].affects,
+            Some(vec![VulnerabilityTarget::new("pkg-1-1")])
+        );
+    }
+
+    #[test]
+    fn it_should_nest_components_beneath_their_metadata_component() {
+        let first = bom(
+            Some(component("app-one", "app")),
+            vec![component("shared", "shared")],
+            vec![dependency("app", &["shared"])],
+        );
+        let second = bom(
+            Some(component("app-two", "app")),
+            vec![component("shared", "shared")],
+            vec![dependency("app", &["shared"])],
+        );
+
+        let (merged, _) =
+            hierarchical_merge(vec![first, second], Some(component("product", "product")))
+                .expect("Failed to merge BOMs");
+
+        let components = merged.components.as_ref().expect("Expected components");
+        assert_eq!(bom_refs(&merged.components), vec!["app", "app-1"]);
+        assert_eq!(bom_refs(&components.0[0].components), vec!["shared"]);
+        assert_eq!(bom_refs(&components.0[1].components), vec!["shared-1"]);
+        assert_eq!(
+            merged
+                .metadata
+                .and_then(|metadata| metadata.component)
+                .and_then(|component| component.bom_ref),
+            Some("product".to_string())
+        );
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![
+                dependency("app", &["shared"]),
+                dependency("app-1", &["shared-1"]),
+                dependency("product", &["app", "app-1"]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_fail_to_nest_a_bom_without_a_metadata_component() {
+        let first = bom(Some(component("app", "app")), vec![], vec![]);
+        let second = bom(None, vec![component("shared", "shared")], vec![]);
+
+        let error = hierarchical_merge(vec![first, second], None).expect_err("Expected an error");
+
+        assert!(matches!(
+            error,
+            MergeError::MissingMetadataComponent { index: 1 }
+        ));
+    }
+
+    #[test]
+    fn it_should_list_plain_tools_as_components_when_an_input_uses_components() {
+        let with_tools = |tools: Tools| Bom {
+            metadata: Some(Metadata {
+                tools: Some(tools),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+        let first = with_tools(Tools::List(vec![Tool::new("vendor", "legacy", "1.0.0")]));
+        let second = with_tools(Tools::Object {
+            components: Some(Components(vec![Component::new(
+                Classification::Application,
+                "modern",
+                "2.0.0",
+                None,
+            )])),
+            services: None,
+        });
+
+        let (merged, _) = flat_merge(vec![first, second]);
+
+        let legacy = Component::from(Tool::new("vendor", "legacy", "1.0.0"));
+        assert_eq!(
+            merged.metadata.and_then(|m| m.tools),
+            Some(Tools::Object {
+                components: Some(Components(vec![
+                    Component::new(Classification::Application, "modern", "2.0.0", None),
+                    legacy,
+                ])),
+                services: None,
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_merge_the_nested_components_of_duplicates() {
+        let mut app = component("app", "app");
+        app.components = Some(Components(vec![component("inner", "inner")]));
+        let first = bom(None, vec![app], vec![dependency("app", &["inner"])]);
+
+        let mut duplicate = component("app", "app-2");
+        duplicate.components = Some(Components(vec![
+            component("inner", "inner-2"),
+            component("extra", "inner"),
+        ]));
+        let second = bom(
+            None,
+            vec![duplicate],
+            vec![dependency("app-2", &["inner-2", "inner"])],
+        );
+
+        let (merged, _) = flat_merge(vec![first, second]);
+
+        let components = merged.components.as_ref().expect("Expected components");
+        assert_eq!(bom_refs(&merged.components), vec!["app"]);
+        assert_eq!(
+            bom_refs(&components.0[0].components),
+            vec!["inner", "inner-1"]
+        );
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![dependency("app", &["inner", "inner-1"])]))
+        );
+    }
+
+    #[test]
+    fn it_should_merge_annotations_formulation_and_properties() {
+        let annotation = |subject: &str| Annotation {
+            bom_ref: None,
+            subjects: vec![subject.to_string()],
+            annotator: Annotator::Organization(OrganizationalEntity {
+                name: Some(NormalizedString::new("Acme")),
+                url: None,
+                contact: None,
+            }),
+            timestamp: DateTime("2024-01-01T00:00:00Z".to_string()),
+            text: "Reviewed".to_string(),
+            signature: None,
+        };
+        let task = |bom_ref: &str| Task {
+            bom_ref: bom_ref.to_string(),
+            uid: NormalizedString::new("compile"),
+            name: None,
+            description: None,
+            task_types: vec![],
+            steps: None,
+            time_start: None,
+            time_end: None,
+            properties: None,
+        };
+        let formula = |bom_ref: &str, task_ref: &str| Formula {
+            bom_ref: Some("formula".to_string()),
+            components: None,
+            services: None,
+            workflows: Some(vec![Workflow {
+                bom_ref: bom_ref.to_string(),
+                uid: NormalizedString::new("build"),
+                name: None,
+                description: None,
+                tasks: Some(vec![task(task_ref)]),
+                task_dependencies: Some(Dependencies(vec![dependency(bom_ref, &[task_ref])])),
+                task_types: vec![],
+                steps: None,
+                time_start: None,
+                time_end: None,
+                properties: None,
+            }]),
+            properties: None,
+        };
+        let property = || Property {
+            name: "origin".to_string(),
+            value: NormalizedString::new("merged"),
+        };
+
+        let with_extras = |bom: Bom| Bom {
+            properties: Some(Properties(vec![property()])),
+            annotations: Some(Annotations(vec![annotation("pkg")])),
+            formulation: Some(Formulation(vec![formula("build", "compile")])),
+            ..bom
+        };
+        let first = with_extras(bom(None, vec![component("first", "pkg")], vec![]));
+        let second = with_extras(bom(None, vec![component("second", "pkg")], vec![]));
+
+        let (merged, losses) = flat_merge(vec![first, second]);
+
+        assert_eq!(merged.properties, Some(Properties(vec![property()])));
+        assert_eq!(
+            merged.annotations,
+            Some(Annotations(vec![annotation("pkg"), annotation("pkg-1")]))
+        );
+        let mut renamed = formula("build-1", "compile-1");
+        renamed.bom_ref = Some("formula-1".to_string());
+        assert_eq!(
+            merged.formulation,
+            Some(Formulation(vec![formula("build", "compile"), renamed]))
+        );
+        assert_eq!(losses, vec![]);
+    }
+
+    #[test]
+    fn it_should_report_the_fields_that_describe_the_inputs() {
+        let first = bom(Some(component("app-one", "app-one")), vec![], vec![]);
+        let mut second = bom(Some(component("app-two", "app-two")), vec![], vec![]);
+        if let Some(metadata) = &mut second.metadata {
+            metadata.timestamp = Some(DateTime("2024-01-01T00:00:00Z".to_string()));
+        }
+        second.signature = Some(Signature::new(Algorithm::ES256, "c2lnbmF0dXJl"));
+
+        let (merged, losses) =
+            hierarchical_merge(vec![first, second], None).expect("Failed to merge BOMs");
+
+        assert_eq!(merged.signature, None);
+        assert_eq!(
+            losses,
+            vec![
+                ConversionLoss {
+                    path: "boms[1].metadata.timestamp".to_string(),
+                    kind: ConversionLossKind::Dropped,
+                },
+                ConversionLoss {
+                    path: "boms[1].signature".to_string(),
+                    kind: ConversionLossKind::Dropped,
+                },
+            ]
+        );
+    }
+}
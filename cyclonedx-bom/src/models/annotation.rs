@@ -0,0 +1,214 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::date_time::DateTime;
+use crate::models::component::Component;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::service::Service;
+use crate::models::signature::Signature;
+use crate::validation::{
+    Validate, ValidationContext, ValidationError, ValidationPathComponent, ValidationResult,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Annotations(pub Vec<Annotation>);
+
+impl Validate for Annotations {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, annotation) in self.0.iter().enumerate() {
+            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(annotation.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A comment, note, explanation, or similar textual content which provides additional context to
+/// the objects it refers to
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_annotationsType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Annotation {
+    pub bom_ref: Option<String>,
+    pub subjects: Vec<String>,
+    pub annotator: Annotator,
+    pub timestamp: DateTime,
+    pub text: String,
+    pub signature: Option<Signature>,
+}
+
+impl Validate for Annotation {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let annotator_context = context.extend_context_with_struct_field("Annotation", "annotator");
+        results.push(self.annotator.validate_with_context(annotator_context)?);
+
+        let timestamp_context = context.extend_context_with_struct_field("Annotation", "timestamp");
+        results.push(self.timestamp.validate_with_context(timestamp_context)?);
+
+        if let Some(signature) = &self.signature {
+            let context = context.extend_context_with_struct_field("Annotation", "signature");
+
+            results.push(signature.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The organization, person, component, or service which created the textual content of the
+/// annotation
+#[derive(Debug, PartialEq, Eq)]
+pub enum Annotator {
+    Organization(OrganizationalEntity),
+    Individual(OrganizationalContact),
+    Component(Box<Component>),
+    Service(Box<Service>),
+}
+
+impl Validate for Annotator {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Annotator::Organization(organization) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Organization".to_string(),
+                }]);
+                organization.validate_with_context(context)
+            }
+            Annotator::Individual(individual) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Individual".to_string(),
+                }]);
+                individual.validate_with_context(context)
+            }
+            Annotator::Component(component) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Component".to_string(),
+                }]);
+                component.validate_with_context(context)
+            }
+            Annotator::Service(service) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Service".to_string(),
+                }]);
+                service.validate_with_context(context)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        external_models::normalized_string::NormalizedString,
+        validation::{FailureReason, Severity},
+    };
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = Annotations(vec![Annotation {
+            bom_ref: Some("annotation-1".to_string()),
+            subjects: vec!["component-a".to_string()],
+            annotator: Annotator::Individual(OrganizationalContact::new("Jane Doe", None)),
+            timestamp: DateTime("2023-01-01T00:00:00Z".to_string()),
+            text: "This component is deprecated".to_string(),
+            signature: None,
+        }])
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = Annotations(vec![Annotation {
+            bom_ref: None,
+            subjects: vec!["component-a".to_string()],
+            annotator: Annotator::Organization(OrganizationalEntity {
+                name: Some(NormalizedString("invalid\tname".to_string())),
+                url: None,
+                contact: None,
+            }),
+            timestamp: DateTime("invalid date".to_string()),
+            text: "text".to_string(),
+            signature: None,
+        }])
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason {
+                        severity: Severity::Error,
+                        message:
+                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                                .to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Annotation".to_string(),
+                                field_name: "annotator".to_string()
+                            },
+                            ValidationPathComponent::EnumVariant {
+                                variant_name: "Organization".to_string()
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "OrganizationalEntity".to_string(),
+                                field_name: "name".to_string()
+                            },
+                        ])
+                    },
+                    FailureReason {
+                        severity: Severity::Error,
+                        message: "DateTime does not conform to ISO 8601".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Annotation".to_string(),
+                                field_name: "timestamp".to_string()
+                            },
+                        ])
+                    },
+                ]
+            }
+        );
+    }
+}
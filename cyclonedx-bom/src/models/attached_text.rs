@@ -18,7 +18,9 @@
 
 use crate::{
     external_models::normalized_string::NormalizedString,
-    validation::{FailureReason, Validate, ValidationContext, ValidationError, ValidationResult},
+    validation::{
+        FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationResult,
+    },
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -64,6 +66,7 @@ impl Validate for AttachedText {
 
                     results.push(ValidationResult::Failed {
                         reasons: vec![FailureReason {
+                            severity: Severity::Error,
                             message: "Content is not Base64 encoded".to_string(),
                             context,
                         }],
@@ -117,6 +120,7 @@ impl Validate for Encoding {
         match self {
             Encoding::UnknownEncoding(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Unknown encoding".to_string(),
                     context,
                 }],
@@ -177,6 +181,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -186,6 +191,7 @@ mod test {
                         }])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Content is not Base64 encoded".to_string(),
                         context: ValidationContext(vec![ValidationPathComponent::Struct {
                             struct_name: "AttachedText".to_string(),
@@ -211,6 +217,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Unknown encoding".to_string(),
                     context: ValidationContext(vec![ValidationPathComponent::Struct {
                         struct_name: "AttachedText".to_string(),
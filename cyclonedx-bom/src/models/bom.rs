@@ -21,20 +21,26 @@ use std::fmt;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
-use xml::{EmitterConfig, EventReader, EventWriter, ParserConfig};
+use xml::{EmitterConfig, EventReader, EventWriter};
 
+use crate::conversion::{downgrade, ConversionLoss};
+use crate::json::read_json_document;
+use crate::models::annotation::Annotations;
 use crate::models::component::{Component, Components};
 use crate::models::composition::{BomReference, Compositions};
 use crate::models::dependency::Dependencies;
 use crate::models::external_reference::ExternalReferences;
+use crate::models::formulation::Formulation;
 use crate::models::metadata::Metadata;
 use crate::models::property::Properties;
 use crate::models::service::{Service, Services};
+use crate::models::signature::Signature;
+use crate::models::vulnerability::Vulnerabilities;
 use crate::validation::{
-    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
     ValidationResult,
 };
-use crate::xml::{FromXmlDocument, ToXml};
+use crate::xml::{read_xml_document_with_position, ToXml};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Bom {
@@ -47,14 +53,18 @@ pub struct Bom {
     pub dependencies: Option<Dependencies>,
     pub compositions: Option<Compositions>,
     pub properties: Option<Properties>,
+    pub vulnerabilities: Option<Vulnerabilities>,
+    pub annotations: Option<Annotations>,
+    pub formulation: Option<Formulation>,
+    pub signature: Option<Signature>,
 }
 
 impl Bom {
     /// Parse the input as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
     pub fn parse_from_json_v1_3<R: std::io::Read>(
-        mut reader: R,
+        reader: R,
     ) -> Result<Self, crate::errors::JsonReadError> {
-        let bom: crate::specs::v1_3::bom::Bom = serde_json::from_reader(&mut reader)?;
+        let bom: crate::specs::v1_3::bom::Bom = read_json_document(reader)?;
         Ok(bom.into())
     }
 
@@ -62,9 +72,7 @@ impl Bom {
     pub fn parse_from_xml_v1_3<R: std::io::Read>(
         reader: R,
     ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_3::bom::Bom::read_xml_document(&mut event_reader)?;
+        let bom: crate::specs::v1_3::bom::Bom = read_xml_document_with_position(reader)?;
         Ok(bom.into())
     }
 
@@ -88,6 +96,297 @@ impl Bom {
         let bom: crate::specs::v1_3::bom::Bom = self.into();
         bom.write_xml_element(&mut event_writer)
     }
+
+    /// Parse the input as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
+    pub fn parse_from_json_v1_4<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_4::bom::Bom = read_json_document(reader)?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/)
+    pub fn parse_from_xml_v1_4<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let bom: crate::specs::v1_4::bom::Bom = read_xml_document_with_position(reader)?;
+        Ok(bom.into())
+    }
+
+    /// Output as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
+    pub fn output_as_json_v1_4<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        let bom: crate::specs::v1_4::bom::Bom = self.into();
+        serde_json::to_writer_pretty(writer, &bom)?;
+        Ok(())
+    }
+
+    /// Output as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/)
+    pub fn output_as_xml_v1_4<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let config = EmitterConfig::default().perform_indent(true);
+        let mut event_writer = EventWriter::new_with_config(writer, config);
+
+        let bom: crate::specs::v1_4::bom::Bom = self.into();
+        bom.write_xml_element(&mut event_writer)
+    }
+
+    /// Parse the input as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
+    pub fn parse_from_json_v1_5<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_5::bom::Bom = read_json_document(reader)?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/)
+    pub fn parse_from_xml_v1_5<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let bom: crate::specs::v1_5::bom::Bom = read_xml_document_with_position(reader)?;
+        Ok(bom.into())
+    }
+
+    /// Output as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
+    pub fn output_as_json_v1_5<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        let bom: crate::specs::v1_5::bom::Bom = self.into();
+        serde_json::to_writer_pretty(writer, &bom)?;
+        Ok(())
+    }
+
+    /// Output as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/)
+    pub fn output_as_xml_v1_5<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let config = EmitterConfig::default().perform_indent(true);
+        let mut event_writer = EventWriter::new_with_config(writer, config);
+
+        let bom: crate::specs::v1_5::bom::Bom = self.into();
+        bom.write_xml_element(&mut event_writer)
+    }
+
+    /// Parse the input as a Protocol Buffers message conforming to [version 1.3 of the specification](https://github.com/CycloneDX/specification/blob/master/schema/bom-1.3.proto)
+    #[cfg(feature = "protobuf")]
+    pub fn parse_from_protobuf_v1_3<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::ProtobufReadError> {
+        crate::proto::read_protobuf_document_v1_3(reader)
+    }
+
+    /// Output as a Protocol Buffers message conforming to [version 1.3 of the specification](https://github.com/CycloneDX/specification/blob/master/schema/bom-1.3.proto)
+    #[cfg(feature = "protobuf")]
+    pub fn output_as_protobuf_v1_3<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::ProtobufWriteError> {
+        crate::proto::write_protobuf_document_v1_3(self, writer)
+    }
+
+    /// Output as a JSON document conforming to [version 2.3 of the SPDX specification](https://spdx.github.io/spdx-spec/v2.3/),
+    /// returning every field that SPDX has no equivalent for and that was therefore dropped or
+    /// rewritten
+    pub fn output_as_spdx_json_v2_3<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<Vec<ConversionLoss>, crate::errors::SpdxWriteError> {
+        let (document, losses) = crate::spdx_document::export::export(self);
+        serde_json::to_writer_pretty(writer, &document)?;
+        Ok(losses)
+    }
+
+    /// Output as a tag-value document conforming to [version 2.3 of the SPDX specification](https://spdx.github.io/spdx-spec/v2.3/),
+    /// returning every field that SPDX has no equivalent for and that was therefore dropped or
+    /// rewritten
+    pub fn output_as_spdx_tag_value_v2_3<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<Vec<ConversionLoss>, crate::errors::SpdxWriteError> {
+        let (document, losses) = crate::spdx_document::export::export(self);
+        crate::spdx_document::tag_value::write_tag_value(&document, writer)?;
+        Ok(losses)
+    }
+
+    /// Parse the input as a JSON document conforming to [version 2.3 of the SPDX specification](https://spdx.github.io/spdx-spec/v2.3/),
+    /// returning the BOM together with every element of the document that the BOM has no
+    /// equivalent for
+    pub fn parse_from_spdx_json_v2_3<R: std::io::Read>(
+        reader: R,
+    ) -> Result<(Self, Vec<ConversionLoss>), crate::errors::SpdxReadError> {
+        let document = serde_json::from_reader(reader)?;
+        crate::spdx_document::import::import(document)
+    }
+
+    /// Parse the input as either a JSON or an XML document, detecting the version of the
+    /// specification from the `specVersion` field (JSON) or the namespace of the root element
+    /// (XML), and return the parsed BOM together with the detected version
+    pub fn parse<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<(Self, SpecVersion), crate::errors::BomReadError> {
+        let mut input = Vec::new();
+        reader.read_to_end(&mut input)?;
+
+        // skip a leading UTF-8 byte order mark, which neither parser accepts
+        let input = input.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(&input);
+
+        match input.iter().find(|byte| !byte.is_ascii_whitespace()) {
+            Some(b'{') => {
+                let spec_version = detect_json_spec_version(input)?;
+                let bom = match spec_version {
+                    SpecVersion::V1_3 => Self::parse_from_json_v1_3(input)?,
+                    SpecVersion::V1_4 => Self::parse_from_json_v1_4(input)?,
+                    SpecVersion::V1_5 => Self::parse_from_json_v1_5(input)?,
+                };
+                Ok((bom, spec_version))
+            }
+            Some(b'<') => {
+                let spec_version = detect_xml_spec_version(input)?;
+                let bom = match spec_version {
+                    SpecVersion::V1_3 => Self::parse_from_xml_v1_3(input)?,
+                    SpecVersion::V1_4 => Self::parse_from_xml_v1_4(input)?,
+                    SpecVersion::V1_5 => Self::parse_from_xml_v1_5(input)?,
+                };
+                Ok((bom, spec_version))
+            }
+            _ => Err(crate::errors::BomReadError::UnknownFormat),
+        }
+    }
+
+    /// Output in the given format as a document conforming to the given version of the
+    /// specification, returning every field that the version cannot represent and that was
+    /// therefore dropped or rewritten
+    pub fn output_as<W: std::io::Write>(
+        mut self,
+        format: OutputFormat,
+        spec_version: SpecVersion,
+        writer: &mut W,
+    ) -> Result<Vec<ConversionLoss>, crate::errors::BomWriteError> {
+        let losses = downgrade(&mut self, format, spec_version);
+
+        match (format, spec_version) {
+            (OutputFormat::Json, SpecVersion::V1_3) => self.output_as_json_v1_3(writer)?,
+            (OutputFormat::Json, SpecVersion::V1_4) => self.output_as_json_v1_4(writer)?,
+            (OutputFormat::Json, SpecVersion::V1_5) => self.output_as_json_v1_5(writer)?,
+            (OutputFormat::Xml, SpecVersion::V1_3) => self.output_as_xml_v1_3(writer)?,
+            (OutputFormat::Xml, SpecVersion::V1_4) => self.output_as_xml_v1_4(writer)?,
+            (OutputFormat::Xml, SpecVersion::V1_5) => self.output_as_xml_v1_5(writer)?,
+        }
+
+        Ok(losses)
+    }
+
+    /// Sort the components, dependencies and properties into a canonical order, so that BOMs with
+    /// the same content are always written identically
+    pub fn sort(&mut self) {
+        if let Some(metadata) = &mut self.metadata {
+            if let Some(component) = &mut metadata.component {
+                component.sort();
+            }
+            if let Some(properties) = &mut metadata.properties {
+                properties.sort();
+            }
+        }
+        if let Some(components) = &mut self.components {
+            components.sort();
+        }
+        if let Some(dependencies) = &mut self.dependencies {
+            dependencies.sort();
+        }
+        if let Some(properties) = &mut self.properties {
+            properties.sort();
+        }
+    }
+}
+
+fn detect_json_spec_version(input: &[u8]) -> Result<SpecVersion, crate::errors::BomReadError> {
+    #[derive(serde::Deserialize)]
+    struct VersionedDocument {
+        #[serde(rename = "specVersion")]
+        spec_version: Option<String>,
+    }
+
+    let document: VersionedDocument =
+        serde_json::from_slice(input).map_err(crate::errors::JsonReadError::from)?;
+    let spec_version = document
+        .spec_version
+        .ok_or(crate::errors::BomReadError::MissingSpecVersion)?;
+
+    SpecVersion::from_version_str(&spec_version).ok_or(
+        crate::errors::BomReadError::UnknownSpecVersion(spec_version),
+    )
+}
+
+fn detect_xml_spec_version(input: &[u8]) -> Result<SpecVersion, crate::errors::BomReadError> {
+    let mut event_reader = EventReader::new(input);
+    loop {
+        match event_reader.next() {
+            Ok(xml::reader::XmlEvent::StartElement { name, .. }) => {
+                let namespace = name
+                    .namespace
+                    .ok_or(crate::errors::BomReadError::MissingSpecVersion)?;
+                return namespace
+                    .strip_prefix(CYCLONEDX_XML_NAMESPACE_PREFIX)
+                    .and_then(SpecVersion::from_version_str)
+                    .ok_or(crate::errors::BomReadError::UnknownSpecVersion(namespace));
+            }
+            Ok(xml::reader::XmlEvent::EndDocument) => {
+                return Err(crate::errors::BomReadError::MissingSpecVersion)
+            }
+            Ok(_) => (),
+            Err(error) => {
+                return Err(crate::errors::XmlReadError::ElementReadError {
+                    error,
+                    element: "bom".to_string(),
+                }
+                .into())
+            }
+        }
+    }
+}
+
+const CYCLONEDX_XML_NAMESPACE_PREFIX: &str = "http://cyclonedx.org/schema/bom/";
+
+/// The versions of the CycloneDX specification supported by this library
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpecVersion {
+    V1_3,
+    V1_4,
+    V1_5,
+}
+
+impl SpecVersion {
+    fn from_version_str(version: &str) -> Option<Self> {
+        match version {
+            "1.3" => Some(Self::V1_3),
+            "1.4" => Some(Self::V1_4),
+            "1.5" => Some(Self::V1_5),
+            _ => None,
+        }
+    }
+}
+
+/// The document formats that a BOM can be output as
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OutputFormat {
+    Json,
+    Xml,
+}
+
+impl fmt::Display for SpecVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpecVersion::V1_3 => write!(f, "1.3"),
+            SpecVersion::V1_4 => write!(f, "1.4"),
+            SpecVersion::V1_5 => write!(f, "1.5"),
+        }
+    }
 }
 
 impl Default for Bom {
@@ -103,6 +402,10 @@ impl Default for Bom {
             dependencies: None,
             compositions: None,
             properties: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
+            signature: None,
         }
     }
 }
@@ -188,6 +491,7 @@ impl Validate for Bom {
 
                     results.push(ValidationResult::Failed {
                         reasons: vec![FailureReason {
+                            severity: Severity::Error,
                             message: "Dependency reference does not exist in the BOM".to_string(),
                             context: dependency_context,
                         }],
@@ -210,6 +514,7 @@ impl Validate for Bom {
 
                         results.push(ValidationResult::Failed {
                             reasons: vec![FailureReason {
+                                severity: Severity::Error,
                                 message: "Dependency reference does not exist in the BOM"
                                     .to_string(),
                                 context,
@@ -244,6 +549,7 @@ impl Validate for Bom {
                             ]);
                             results.push(ValidationResult::Failed {
                                 reasons: vec![FailureReason {
+                                    severity: Severity::Error,
                                     message: "Composition reference does not exist in the BOM"
                                         .to_string(),
                                     context: compositions_context,
@@ -267,6 +573,7 @@ impl Validate for Bom {
                             ]);
                             results.push(ValidationResult::Failed {
                                 reasons: vec![FailureReason {
+                                    severity: Severity::Error,
                                     message: "Composition reference does not exist in the BOM"
                                         .to_string(),
                                     context: compositions_context,
@@ -284,6 +591,103 @@ impl Validate for Bom {
             results.push(properties.validate_with_context(context)?);
         }
 
+        if let Some(vulnerabilities) = &self.vulnerabilities {
+            let context = context.extend_context_with_struct_field("Bom", "vulnerabilities");
+            let vulnerabilities_context = context.clone();
+
+            results.push(vulnerabilities.validate_with_context(context)?);
+
+            for (vulnerability_index, vulnerability) in vulnerabilities.0.iter().enumerate() {
+                if let Some(affects) = &vulnerability.affects {
+                    let context = vulnerabilities_context.extend_context(vec![
+                        ValidationPathComponent::Array {
+                            index: vulnerability_index,
+                        },
+                        ValidationPathComponent::Struct {
+                            struct_name: "Vulnerability".to_string(),
+                            field_name: "affects".to_string(),
+                        },
+                    ]);
+
+                    for (target_index, target) in affects.iter().enumerate() {
+                        if !bom_refs_context.contains(&target.bom_ref) {
+                            let context = context.extend_context(vec![
+                                ValidationPathComponent::Array {
+                                    index: target_index,
+                                },
+                                ValidationPathComponent::Struct {
+                                    struct_name: "VulnerabilityTarget".to_string(),
+                                    field_name: "bom_ref".to_string(),
+                                },
+                            ]);
+                            results.push(ValidationResult::Failed {
+                                reasons: vec![FailureReason {
+                                    severity: Severity::Error,
+                                    message: "Vulnerability target does not exist in the BOM"
+                                        .to_string(),
+                                    context,
+                                }],
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(annotations) = &self.annotations {
+            let context = context.extend_context_with_struct_field("Bom", "annotations");
+            let annotations_context = context.clone();
+
+            results.push(annotations.validate_with_context(context)?);
+
+            let vulnerability_bom_refs: HashSet<&String> = self
+                .vulnerabilities
+                .iter()
+                .flat_map(|vulnerabilities| vulnerabilities.0.iter())
+                .filter_map(|vulnerability| vulnerability.bom_ref.as_ref())
+                .collect();
+
+            for (annotation_index, annotation) in annotations.0.iter().enumerate() {
+                for (subject_index, subject) in annotation.subjects.iter().enumerate() {
+                    if !bom_refs_context.contains(subject)
+                        && !vulnerability_bom_refs.contains(subject)
+                    {
+                        let context = annotations_context.extend_context(vec![
+                            ValidationPathComponent::Array {
+                                index: annotation_index,
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Annotation".to_string(),
+                                field_name: "subjects".to_string(),
+                            },
+                            ValidationPathComponent::Array {
+                                index: subject_index,
+                            },
+                        ]);
+                        results.push(ValidationResult::Failed {
+                            reasons: vec![FailureReason {
+                                severity: Severity::Error,
+                                message: "Annotation subject does not exist in the BOM".to_string(),
+                                context,
+                            }],
+                        });
+                    }
+                }
+            }
+        }
+
+        if let Some(formulation) = &self.formulation {
+            let context = context.extend_context_with_struct_field("Bom", "formulation");
+
+            results.push(formulation.validate_with_context(context)?);
+        }
+
+        if let Some(signature) = &self.signature {
+            let context = context.extend_context_with_struct_field("Bom", "signature");
+
+            results.push(signature.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
@@ -321,6 +725,7 @@ fn validate_component_bom_refs(
             let context = context.extend_context_with_struct_field("Component", "bom_ref");
             results.push(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: format!(r#"Bom ref "{bom_ref}" is not unique"#),
                     context,
                 }],
@@ -362,6 +767,7 @@ fn validate_service_bom_refs(
             let context = context.extend_context_with_struct_field("Service", "bom_ref");
             results.push(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: format!(r#"Bom ref "{bom_ref}" is not unique"#),
                     context,
                 }],
@@ -408,6 +814,12 @@ impl UrnUuid {
     pub fn generate() -> Self {
         Self::from(uuid::Uuid::new_v4())
     }
+
+    /// Derive a name-based (version 5) UUID, so that the same namespace and name always give the
+    /// same serial number
+    pub fn new_v5(namespace: &uuid::Uuid, name: &[u8]) -> Self {
+        Self::from(uuid::Uuid::new_v5(namespace, name))
+    }
 }
 
 impl fmt::Display for UrnUuid {
@@ -431,6 +843,7 @@ impl Validate for UrnUuid {
             Ok(true) => Ok(ValidationResult::Passed),
             Ok(false) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "UrnUuid does not match regular expression".to_string(),
                     context,
                 }],
@@ -461,12 +874,15 @@ mod test {
     use crate::{
         external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
         models::{
+            annotation::{Annotation, Annotator},
             component::{Classification, Component},
             composition::{AggregateType, BomReference, Composition},
             dependency::Dependency,
             external_reference::{ExternalReference, ExternalReferenceType},
+            organization::OrganizationalContact,
             property::Property,
             service::Service,
+            vulnerability::{Vulnerability, VulnerabilityTarget},
         },
         validation::ValidationPathComponent,
     };
@@ -474,6 +890,38 @@ mod test {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    /// The path of the component at the index in the top-level components of the BOM
+    fn component_path(index: usize) -> Vec<ValidationPathComponent> {
+        vec![
+            ValidationPathComponent::Struct {
+                struct_name: "Bom".to_string(),
+                field_name: "components".to_string(),
+            },
+            ValidationPathComponent::Array { index },
+        ]
+    }
+
+    /// The warnings for a library component at the path that has neither licenses nor a purl
+    fn missing_package_details(path: Vec<ValidationPathComponent>) -> Vec<FailureReason> {
+        let warning = |message: &str, field_name: &str| {
+            let mut context = path.clone();
+            context.push(ValidationPathComponent::Struct {
+                struct_name: "Component".to_string(),
+                field_name: field_name.to_string(),
+            });
+            FailureReason {
+                severity: Severity::Warning,
+                message: message.to_string(),
+                context: ValidationContext(context),
+            }
+        };
+
+        vec![
+            warning("Component has no licenses", "licenses"),
+            warning("Component has no package URL", "purl"),
+        ]
+    }
+
     #[test]
     fn it_should_validate_an_empty_bom_as_passed() {
         let bom = Bom {
@@ -486,6 +934,10 @@ mod test {
             dependencies: None,
             compositions: None,
             properties: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
+            signature: None,
         };
 
         let actual = bom
@@ -510,6 +962,10 @@ mod test {
             }])),
             compositions: None,
             properties: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
+            signature: None,
         };
 
         let actual = bom.validate().expect("Failed to validate bom");
@@ -519,6 +975,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Dependency reference does not exist in the BOM".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -533,6 +990,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Dependency reference does not exist in the BOM".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -566,8 +1024,13 @@ mod test {
                 aggregate: AggregateType::Complete,
                 assemblies: Some(vec![BomReference("assembly".to_string())]),
                 dependencies: Some(vec![BomReference("dependencies".to_string())]),
+                signature: None,
             }])),
             properties: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
+            signature: None,
         };
 
         let actual = bom.validate().expect("Failed to validate bom");
@@ -577,6 +1040,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Composition reference does not exist in the BOM".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -592,6 +1056,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Composition reference does not exist in the BOM".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -611,6 +1076,127 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_validate_broken_vulnerability_target_refs_as_failed() {
+        let bom = Bom {
+            version: 1,
+            serial_number: None,
+            metadata: None,
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "name",
+                "version",
+                Some("component".to_string()),
+            )])),
+            services: None,
+            external_references: None,
+            dependencies: None,
+            compositions: None,
+            properties: None,
+            vulnerabilities: Some(Vulnerabilities(vec![Vulnerability {
+                affects: Some(vec![
+                    VulnerabilityTarget::new("component"),
+                    VulnerabilityTarget::new("missing"),
+                ]),
+                ..Vulnerability::new(None, "CVE-2021-44228")
+            }])),
+            annotations: None,
+            formulation: None,
+            signature: None,
+        };
+
+        let actual = bom.validate().expect("Failed to validate bom");
+
+        assert_eq!(
+            actual,
+            ValidationResult::Failed {
+                reasons: [
+                    missing_package_details(component_path(0)),
+                    vec![FailureReason {
+                        severity: Severity::Error,
+                        message: "Vulnerability target does not exist in the BOM".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "Bom".to_string(),
+                                field_name: "vulnerabilities".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Vulnerability".to_string(),
+                                field_name: "affects".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 1 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "VulnerabilityTarget".to_string(),
+                                field_name: "bom_ref".to_string(),
+                            },
+                        ])
+                    }],
+                ]
+                .concat()
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_validate_broken_annotation_subjects_as_failed() {
+        let bom = Bom {
+            version: 1,
+            serial_number: None,
+            metadata: None,
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "name",
+                "version",
+                Some("component".to_string()),
+            )])),
+            services: None,
+            external_references: None,
+            dependencies: None,
+            compositions: None,
+            properties: None,
+            vulnerabilities: None,
+            annotations: Some(Annotations(vec![Annotation {
+                bom_ref: None,
+                subjects: vec!["component".to_string(), "missing".to_string()],
+                annotator: Annotator::Individual(OrganizationalContact::new("Jane Doe", None)),
+                timestamp: DateTime("1970-01-01T00:00:00Z".to_string()),
+                text: "text".to_string(),
+                signature: None,
+            }])),
+            formulation: None,
+            signature: None,
+        };
+
+        let actual = bom.validate().expect("Failed to validate bom");
+
+        assert_eq!(
+            actual,
+            ValidationResult::Failed {
+                reasons: [
+                    missing_package_details(component_path(0)),
+                    vec![FailureReason {
+                        severity: Severity::Error,
+                        message: "Annotation subject does not exist in the BOM".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "Bom".to_string(),
+                                field_name: "annotations".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Annotation".to_string(),
+                                field_name: "subjects".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 1 },
+                        ])
+                    }],
+                ]
+                .concat()
+            }
+        );
+    }
+
     #[test]
     fn it_should_validate_a_bom_with_multiple_validation_issues_as_failed() {
         let bom = Bom {
@@ -618,6 +1204,7 @@ mod test {
             serial_number: Some(UrnUuid("invalid uuid".to_string())),
             metadata: Some(Metadata {
                 timestamp: Some(DateTime("invalid datetime".to_string())),
+                lifecycles: None,
                 tools: None,
                 authors: None,
                 component: None,
@@ -650,6 +1237,10 @@ mod test {
                 properties: None,
                 components: None,
                 evidence: None,
+                release_notes: None,
+                model_card: None,
+                data: None,
+                signature: None,
             }])),
             services: Some(Services(vec![Service {
                 bom_ref: None,
@@ -661,11 +1252,14 @@ mod test {
                 endpoints: None,
                 authenticated: None,
                 x_trust_boundary: None,
+                trust_zone: None,
                 data: None,
                 licenses: None,
                 external_references: None,
                 properties: None,
                 services: None,
+                release_notes: None,
+                signature: None,
             }])),
             external_references: Some(ExternalReferences(vec![ExternalReference {
                 external_reference_type: ExternalReferenceType::UnknownExternalReferenceType(
@@ -683,11 +1277,16 @@ mod test {
                 aggregate: AggregateType::UnknownAggregateType("unknown".to_string()),
                 assemblies: None,
                 dependencies: None,
+                signature: None,
             }])),
             properties: Some(Properties(vec![Property {
                 name: "name".to_string(),
                 value: NormalizedString("invalid\tvalue".to_string()),
             }])),
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
+            signature: None,
         };
 
         let actual = bom
@@ -699,6 +1298,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message: "UrnUuid does not match regular expression".to_string(),
                         context: ValidationContext(vec![ValidationPathComponent::Struct {
                             struct_name: "Bom".to_string(),
@@ -706,6 +1306,7 @@ mod test {
                         }])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "DateTime does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -719,6 +1320,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown classification".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -733,6 +1335,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -749,6 +1352,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown external reference type".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -763,6 +1367,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown aggregate type".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -777,6 +1382,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -822,6 +1428,7 @@ mod test {
             serial_number: None,
             metadata: Some(Metadata {
                 timestamp: None,
+                lifecycles: None,
                 tools: None,
                 authors: None,
                 component: Some(component_builder("metadata-component")),
@@ -847,6 +1454,10 @@ mod test {
             dependencies: None,
             compositions: None,
             properties: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
+            signature: None,
         }
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -854,106 +1465,364 @@ mod test {
         assert_eq!(
             validation_result,
             ValidationResult::Failed {
-                reasons: vec![
-                    FailureReason {
-                        message: r#"Bom ref "metadata-component" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
-                            ValidationPathComponent::Struct {
-                                struct_name: "Bom".to_string(),
-                                field_name: "components".to_string()
-                            },
-                            ValidationPathComponent::Array { index: 0 },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Component".to_string(),
-                                field_name: "bom_ref".to_string()
-                            },
-                        ])
-                    },
-                    FailureReason {
-                        message: r#"Bom ref "component-component" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
-                            ValidationPathComponent::Struct {
-                                struct_name: "Bom".to_string(),
-                                field_name: "components".to_string()
-                            },
-                            ValidationPathComponent::Array { index: 2 },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Component".to_string(),
-                                field_name: "bom_ref".to_string()
-                            },
-                        ])
-                    },
-                    FailureReason {
-                        message: r#"Bom ref "subcomponent-component" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
-                            ValidationPathComponent::Struct {
-                                struct_name: "Bom".to_string(),
-                                field_name: "components".to_string()
-                            },
-                            ValidationPathComponent::Array { index: 3 },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Component".to_string(),
-                                field_name: "components".to_string()
-                            },
-                            ValidationPathComponent::Array { index: 0 },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Component".to_string(),
-                                field_name: "bom_ref".to_string()
-                            },
-                        ])
-                    },
-                    FailureReason {
-                        message: r#"Bom ref "service-service" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
-                            ValidationPathComponent::Struct {
-                                struct_name: "Bom".to_string(),
-                                field_name: "services".to_string()
-                            },
-                            ValidationPathComponent::Array { index: 1 },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Service".to_string(),
-                                field_name: "bom_ref".to_string()
-                            },
-                        ])
-                    },
-                    FailureReason {
-                        message: r#"Bom ref "subservice-service" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
-                            ValidationPathComponent::Struct {
-                                struct_name: "Bom".to_string(),
-                                field_name: "services".to_string()
-                            },
-                            ValidationPathComponent::Array { index: 2 },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Service".to_string(),
-                                field_name: "services".to_string()
-                            },
-                            ValidationPathComponent::Array { index: 0 },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Service".to_string(),
-                                field_name: "bom_ref".to_string()
-                            },
-                        ])
-                    },
-                    FailureReason {
-                        message: r#"Bom ref "component-service" is not unique"#.to_string(),
-                        context: ValidationContext(vec![
-                            ValidationPathComponent::Struct {
-                                struct_name: "Bom".to_string(),
-                                field_name: "services".to_string()
-                            },
-                            ValidationPathComponent::Array { index: 3 },
-                            ValidationPathComponent::Struct {
-                                struct_name: "Service".to_string(),
-                                field_name: "bom_ref".to_string()
-                            },
-                        ])
-                    },
+                reasons: [
+                    missing_package_details(vec![
+                        ValidationPathComponent::Struct {
+                            struct_name: "Bom".to_string(),
+                            field_name: "metadata".to_string(),
+                        },
+                        ValidationPathComponent::Struct {
+                            struct_name: "Metadata".to_string(),
+                            field_name: "component".to_string(),
+                        },
+                    ]),
+                    missing_package_details(component_path(0)),
+                    missing_package_details(component_path(1)),
+                    missing_package_details(component_path(2)),
+                    missing_package_details(component_path(3)),
+                    missing_package_details(
+                        [
+                            component_path(3),
+                            vec![
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Component".to_string(),
+                                    field_name: "components".to_string(),
+                                },
+                                ValidationPathComponent::Array { index: 0 },
+                            ],
+                        ]
+                        .concat(),
+                    ),
+                    missing_package_details(component_path(4)),
+                    vec![
+                        FailureReason {
+                            severity: Severity::Error,
+                            message: r#"Bom ref "metadata-component" is not unique"#.to_string(),
+                            context: ValidationContext(vec![
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Bom".to_string(),
+                                    field_name: "components".to_string()
+                                },
+                                ValidationPathComponent::Array { index: 0 },
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Component".to_string(),
+                                    field_name: "bom_ref".to_string()
+                                },
+                            ])
+                        },
+                        FailureReason {
+                            severity: Severity::Error,
+                            message: r#"Bom ref "component-component" is not unique"#.to_string(),
+                            context: ValidationContext(vec![
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Bom".to_string(),
+                                    field_name: "components".to_string()
+                                },
+                                ValidationPathComponent::Array { index: 2 },
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Component".to_string(),
+                                    field_name: "bom_ref".to_string()
+                                },
+                            ])
+                        },
+                        FailureReason {
+                            severity: Severity::Error,
+                            message: r#"Bom ref "subcomponent-component" is not unique"#
+                                .to_string(),
+                            context: ValidationContext(vec![
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Bom".to_string(),
+                                    field_name: "components".to_string()
+                                },
+                                ValidationPathComponent::Array { index: 3 },
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Component".to_string(),
+                                    field_name: "components".to_string()
+                                },
+                                ValidationPathComponent::Array { index: 0 },
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Component".to_string(),
+                                    field_name: "bom_ref".to_string()
+                                },
+                            ])
+                        },
+                        FailureReason {
+                            severity: Severity::Error,
+                            message: r#"Bom ref "service-service" is not unique"#.to_string(),
+                            context: ValidationContext(vec![
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Bom".to_string(),
+                                    field_name: "services".to_string()
+                                },
+                                ValidationPathComponent::Array { index: 1 },
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Service".to_string(),
+                                    field_name: "bom_ref".to_string()
+                                },
+                            ])
+                        },
+                        FailureReason {
+                            severity: Severity::Error,
+                            message: r#"Bom ref "subservice-service" is not unique"#.to_string(),
+                            context: ValidationContext(vec![
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Bom".to_string(),
+                                    field_name: "services".to_string()
+                                },
+                                ValidationPathComponent::Array { index: 2 },
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Service".to_string(),
+                                    field_name: "services".to_string()
+                                },
+                                ValidationPathComponent::Array { index: 0 },
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Service".to_string(),
+                                    field_name: "bom_ref".to_string()
+                                },
+                            ])
+                        },
+                        FailureReason {
+                            severity: Severity::Error,
+                            message: r#"Bom ref "component-service" is not unique"#.to_string(),
+                            context: ValidationContext(vec![
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Bom".to_string(),
+                                    field_name: "services".to_string()
+                                },
+                                ValidationPathComponent::Array { index: 3 },
+                                ValidationPathComponent::Struct {
+                                    struct_name: "Service".to_string(),
+                                    field_name: "bom_ref".to_string()
+                                },
+                            ])
+                        },
+                    ],
                 ]
+                .concat()
             },
         );
     }
 
+    #[test]
+    fn it_should_parse_a_json_document_and_detect_the_spec_version() {
+        let input = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.4",
+  "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+  "version": 1
+}"#;
+
+        let (bom, spec_version) = Bom::parse(input.as_bytes()).expect("Failed to parse BOM");
+
+        assert_eq!(spec_version, SpecVersion::V1_4);
+        assert_eq!(bom.version, 1);
+    }
+
+    #[test]
+    fn it_should_parse_an_xml_document_and_detect_the_spec_version() {
+        let input = r#"<?xml version="1.0"?>
+<bom serialNumber="urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79" version="1" xmlns="http://cyclonedx.org/schema/bom/1.5">
+</bom>"#;
+
+        let input = format!("\u{feff}{input}");
+
+        let (bom, spec_version) = Bom::parse(input.as_bytes()).expect("Failed to parse BOM");
+
+        assert_eq!(spec_version, SpecVersion::V1_5);
+        assert_eq!(bom.version, 1);
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_an_unknown_json_spec_version() {
+        let input = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "2.0",
+  "version": 1
+}"#;
+
+        let error = Bom::parse(input.as_bytes()).expect_err("Expected an unknown version");
+
+        assert!(
+            matches!(error, crate::errors::BomReadError::UnknownSpecVersion(version) if version == "2.0")
+        );
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_an_unknown_xml_spec_version() {
+        let input = r#"<?xml version="1.0"?>
+<bom version="1" xmlns="http://cyclonedx.org/schema/bom/1.1">
+</bom>"#;
+
+        let error = Bom::parse(input.as_bytes()).expect_err("Expected an unknown version");
+
+        assert!(
+            matches!(error, crate::errors::BomReadError::UnknownSpecVersion(namespace) if namespace == "http://cyclonedx.org/schema/bom/1.1")
+        );
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_a_document_without_a_spec_version() {
+        let error = Bom::parse(r#"{"bomFormat": "CycloneDX", "version": 1}"#.as_bytes())
+            .expect_err("Expected a missing version");
+
+        assert!(matches!(
+            error,
+            crate::errors::BomReadError::MissingSpecVersion
+        ));
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_a_document_in_an_unknown_format() {
+        let error = Bom::parse("bomFormat: CycloneDX".as_bytes()).expect_err("Expected an error");
+
+        assert!(matches!(error, crate::errors::BomReadError::UnknownFormat));
+    }
+
+    #[test]
+    fn it_should_report_the_position_of_an_xml_read_error() {
+        let input = r#"<?xml version="1.0"?>
+<bom version="1" xmlns="http://cyclonedx.org/schema/bom/1.4">
+  <components>
+    <component type="library">
+  </components>
+</bom>"#;
+
+        let error = Bom::parse_from_xml_v1_4(input.as_bytes()).expect_err("Expected an error");
+        let position = error.position().expect("Expected a position");
+
+        assert_eq!(position.line, 5);
+        assert_eq!(position.column, 3);
+        assert_eq!(
+            position.offset,
+            (input.find("</components>").unwrap() + "</components>".len()) as u64
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_json_pointer_of_a_json_read_error() {
+        let input = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.4",
+  "version": 1,
+  "components": [{ "type": "library", "name": 42 }]
+}"#;
+
+        let error = Bom::parse_from_json_v1_4(input.as_bytes()).expect_err("Expected an error");
+
+        assert!(matches!(
+            error,
+            crate::errors::JsonReadError::JsonElementReadError { path, .. } if path == "/components/0/name"
+        ));
+    }
+
+    #[test]
+    fn it_should_sort_components_dependencies_and_properties() {
+        let component = |name: &str, version: &str| {
+            Component::new(
+                Classification::Library,
+                name,
+                version,
+                Some(format!("{}@{}", name, version)),
+            )
+        };
+        let mut parent = component("b", "1.0.0");
+        parent.properties = Some(Properties(vec![
+            Property::new("name", "b"),
+            Property::new("name", "a"),
+        ]));
+        parent.components = Some(Components(vec![
+            component("d", "1.0.0"),
+            component("c", "1.0.0"),
+        ]));
+
+        let mut bom = Bom {
+            components: Some(Components(vec![
+                parent,
+                component("a", "2.0.0"),
+                component("a", "10.0.0"),
+            ])),
+            dependencies: Some(Dependencies(vec![
+                Dependency {
+                    dependency_ref: "b@1.0.0".to_string(),
+                    dependencies: vec!["c@1.0.0".to_string(), "a@2.0.0".to_string()],
+                },
+                Dependency {
+                    dependency_ref: "a@10.0.0".to_string(),
+                    dependencies: vec![],
+                },
+            ])),
+            properties: Some(Properties(vec![
+                Property::new("z", "value"),
+                Property::new("a", "value"),
+            ])),
+            ..Bom::default()
+        };
+        bom.sort();
+
+        let components = bom.components.expect("Missing components");
+        let names: Vec<String> = components
+            .0
+            .iter()
+            .map(|component| component.bom_ref.clone().unwrap_or_default())
+            .collect();
+        assert_eq!(names, vec!["a@10.0.0", "a@2.0.0", "b@1.0.0"]);
+
+        let parent = &components.0[2];
+        assert_eq!(
+            parent.properties,
+            Some(Properties(vec![
+                Property::new("name", "a"),
+                Property::new("name", "b"),
+            ]))
+        );
+        assert_eq!(
+            parent
+                .components
+                .as_ref()
+                .map(|components| components.0[0].name.to_string()),
+            Some("c".to_string())
+        );
+
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                Dependency {
+                    dependency_ref: "a@10.0.0".to_string(),
+                    dependencies: vec![],
+                },
+                Dependency {
+                    dependency_ref: "b@1.0.0".to_string(),
+                    dependencies: vec!["a@2.0.0".to_string(), "c@1.0.0".to_string()],
+                },
+            ]))
+        );
+        assert_eq!(
+            bom.properties,
+            Some(Properties(vec![
+                Property::new("a", "value"),
+                Property::new("z", "value"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_derive_a_name_based_uuid() {
+        let serial_number = UrnUuid::new_v5(&uuid::Uuid::NAMESPACE_DNS, b"python.org");
+
+        assert_eq!(
+            serial_number.to_string(),
+            "urn:uuid:886313e1-3b8a-5372-9b90-0c9aee199e5d"
+        );
+        assert_eq!(
+            UrnUuid::new_v5(&uuid::Uuid::NAMESPACE_DNS, b"python.org"),
+            serial_number
+        );
+        assert_ne!(
+            UrnUuid::new_v5(&uuid::Uuid::NAMESPACE_DNS, b"rust-lang.org"),
+            serial_number
+        );
+    }
+
     #[test]
     fn valid_uuids_should_pass_validation() {
         let validation_result = UrnUuid(format!("urn:uuid:{}", uuid::Uuid::new_v4()))
@@ -973,6 +1842,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "UrnUuid does not match regular expression".to_string(),
                     context: ValidationContext::default()
                 }]
@@ -19,8 +19,8 @@
 use crate::{
     external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
     validation::{
-        FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
-        ValidationResult,
+        FailureReason, Severity, Validate, ValidationContext, ValidationError,
+        ValidationPathComponent, ValidationResult,
     },
 };
 
@@ -274,6 +274,7 @@ impl Validate for IssueClassification {
         match self {
             IssueClassification::UnknownIssueClassification(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Unknown issue classification".to_string(),
                     context,
                 }],
@@ -390,6 +391,7 @@ impl Validate for PatchClassification {
         match self {
             PatchClassification::UnknownPatchClassification(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Unknown patch classification".to_string(),
                     context,
                 }],
@@ -485,6 +487,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -497,6 +500,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Uri does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -507,6 +511,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "DateTime does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -521,6 +526,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -537,6 +543,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -553,6 +560,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "DateTime does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -567,6 +575,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -583,6 +592,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -599,6 +609,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -677,6 +688,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown patch classification".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -687,6 +699,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -707,6 +720,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Uri does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -721,6 +735,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown issue classification".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -736,6 +751,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -753,6 +769,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -770,6 +787,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -787,6 +805,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -808,6 +827,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Uri does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -827,6 +847,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Uri does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -16,17 +16,23 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::fmt;
+
 use once_cell::sync::Lazy;
 use regex::Regex;
 
 use crate::models::attached_text::AttachedText;
 use crate::models::code::{Commits, Patches};
+use crate::models::data::ComponentData;
 use crate::models::external_reference::ExternalReferences;
 use crate::models::hash::Hashes;
 use crate::models::license::Licenses;
+use crate::models::modelcard::ModelCard;
 use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
-use crate::validation::{FailureReason, ValidationPathComponent};
+use crate::models::release_notes::ReleaseNotes;
+use crate::models::signature::Signature;
+use crate::validation::{FailureReason, Severity, ValidationPathComponent};
 use crate::{
     external_models::{
         normalized_string::NormalizedString,
@@ -60,6 +66,10 @@ pub struct Component {
     pub properties: Option<Properties>,
     pub components: Option<Components>,
     pub evidence: Option<ComponentEvidence>,
+    pub release_notes: Option<ReleaseNotes>,
+    pub model_card: Option<ModelCard>,
+    pub data: Option<Vec<ComponentData>>,
+    pub signature: Option<Signature>,
 }
 
 impl Component {
@@ -93,7 +103,39 @@ impl Component {
             properties: None,
             components: None,
             evidence: None,
+            release_notes: None,
+            model_card: None,
+            data: None,
+            signature: None,
+        }
+    }
+
+    /// Sort the properties and nested components of the component into a canonical order
+    pub fn sort(&mut self) {
+        if let Some(properties) = &mut self.properties {
+            properties.sort();
         }
+        if let Some(components) = &mut self.components {
+            components.sort();
+        }
+    }
+
+    fn sort_key(&self) -> (&str, &str, Option<&str>, Option<&str>) {
+        (
+            &self.name.0,
+            &self.version.0,
+            self.bom_ref.as_deref(),
+            self.purl.as_ref().map(|purl| purl.0.as_str()),
+        )
+    }
+
+    /// Libraries and frameworks are expected to be distributed as packages with a package URL
+    /// and licenses
+    fn is_package(&self) -> bool {
+        matches!(
+            self.component_type,
+            Classification::Library | Classification::Framework
+        )
     }
 }
 
@@ -174,6 +216,16 @@ impl Validate for Component {
             results.push(licenses.validate_with_context(context)?);
         }
 
+        if self.is_package() && self.licenses.as_ref().map_or(true, |l| l.0.is_empty()) {
+            results.push(ValidationResult::PassedWithWarnings {
+                warnings: vec![FailureReason {
+                    severity: Severity::Warning,
+                    message: "Component has no licenses".to_string(),
+                    context: context.extend_context_with_struct_field("Component", "licenses"),
+                }],
+            });
+        }
+
         if let Some(copyright) = &self.copyright {
             let context = context.extend_context_with_struct_field("Component", "copyright");
 
@@ -192,6 +244,16 @@ impl Validate for Component {
             results.push(purl.validate_with_context(context)?);
         }
 
+        if self.is_package() && self.purl.is_none() {
+            results.push(ValidationResult::PassedWithWarnings {
+                warnings: vec![FailureReason {
+                    severity: Severity::Warning,
+                    message: "Component has no package URL".to_string(),
+                    context: context.extend_context_with_struct_field("Component", "purl"),
+                }],
+            });
+        }
+
         if let Some(swid) = &self.swid {
             let context = context.extend_context_with_struct_field("Component", "swid");
 
@@ -229,6 +291,34 @@ impl Validate for Component {
             results.push(evidence.validate_with_context(context)?);
         }
 
+        if let Some(release_notes) = &self.release_notes {
+            let context = context.extend_context_with_struct_field("Component", "release_notes");
+
+            results.push(release_notes.validate_with_context(context)?);
+        }
+
+        if let Some(model_card) = &self.model_card {
+            let context = context.extend_context_with_struct_field("Component", "model_card");
+
+            results.push(model_card.validate_with_context(context)?);
+        }
+
+        if let Some(data) = &self.data {
+            let context = context.extend_context_with_struct_field("Component", "data");
+
+            for (index, data) in data.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(data.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(signature) = &self.signature {
+            let context = context.extend_context_with_struct_field("Component", "signature");
+
+            results.push(signature.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
@@ -238,6 +328,18 @@ impl Validate for Component {
 #[derive(Debug, PartialEq, Eq)]
 pub struct Components(pub Vec<Component>);
 
+impl Components {
+    /// Sort the components by name, version, bom-ref and package URL, along with everything
+    /// nested in them
+    pub fn sort(&mut self) {
+        for component in &mut self.0 {
+            component.sort();
+        }
+        self.0
+            .sort_by(|left, right| left.sort_key().cmp(&right.sort_key()));
+    }
+}
+
 impl Validate for Components {
     fn validate_with_context(
         &self,
@@ -266,6 +368,10 @@ pub enum Classification {
     Device,
     Firmware,
     File,
+    Platform,
+    DeviceDriver,
+    MachineLearningModel,
+    Data,
     #[doc(hidden)]
     UnknownClassification(String),
 }
@@ -281,6 +387,10 @@ impl ToString for Classification {
             Classification::Device => "device",
             Classification::Firmware => "firmware",
             Classification::File => "file",
+            Classification::Platform => "platform",
+            Classification::DeviceDriver => "device-driver",
+            Classification::MachineLearningModel => "machine-learning-model",
+            Classification::Data => "data",
             Classification::UnknownClassification(uc) => uc,
         }
         .to_string()
@@ -298,6 +408,10 @@ impl Classification {
             "device" => Self::Device,
             "firmware" => Self::Firmware,
             "file" => Self::File,
+            "platform" => Self::Platform,
+            "device-driver" => Self::DeviceDriver,
+            "machine-learning-model" => Self::MachineLearningModel,
+            "data" => Self::Data,
             unknown => Self::UnknownClassification(unknown.to_string()),
         }
     }
@@ -311,6 +425,7 @@ impl Validate for Classification {
         match self {
             Classification::UnknownClassification(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Unknown classification".to_string(),
                     context,
                 }],
@@ -360,6 +475,7 @@ impl Validate for Scope {
         match self {
             Scope::UnknownScope(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Unknown scope".to_string(),
                     context,
                 }],
@@ -387,6 +503,7 @@ impl Validate for MimeType {
                 } else {
                     Ok(ValidationResult::Failed {
                         reasons: vec![FailureReason {
+                            severity: Severity::Error,
                             message: "MimeType does not match regular expression".to_string(),
                             context,
                         }],
@@ -454,6 +571,7 @@ impl Validate for Cpe {
                 } else {
                     Ok(ValidationResult::Failed {
                         reasons: vec![FailureReason {
+                            severity: Severity::Error,
                             message: "Cpe does not match regular expression".to_string(),
                             context,
                         }],
@@ -469,6 +587,9 @@ impl Validate for Cpe {
 pub struct ComponentEvidence {
     pub licenses: Option<Licenses>,
     pub copyright: Option<CopyrightTexts>,
+    pub identity: Option<Identity>,
+    pub occurrences: Option<Occurrences>,
+    pub callstack: Option<Callstack>,
 }
 
 impl Validate for ComponentEvidence {
@@ -491,12 +612,303 @@ impl Validate for ComponentEvidence {
             results.push(copyright.validate_with_context(context)?);
         }
 
+        if let Some(identity) = &self.identity {
+            let context = context.extend_context_with_struct_field("ComponentEvidence", "identity");
+
+            results.push(identity.validate_with_context(context)?);
+        }
+
+        if let Some(occurrences) = &self.occurrences {
+            let context =
+                context.extend_context_with_struct_field("ComponentEvidence", "occurrences");
+
+            results.push(occurrences.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
     }
 }
 
+/// Evidence that substantiates the identity of a component
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_componentEvidenceType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Identity {
+    pub field: IdentityField,
+    pub confidence: Option<ConfidenceScore>,
+    pub methods: Option<Vec<IdentityMethod>>,
+    pub tools: Option<Vec<String>>,
+}
+
+impl Validate for Identity {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let field_context = context.extend_context_with_struct_field("Identity", "field");
+        results.push(self.field.validate_with_context(field_context)?);
+
+        if let Some(confidence) = &self.confidence {
+            let context = context.extend_context_with_struct_field("Identity", "confidence");
+
+            results.push(confidence.validate_with_context(context)?);
+        }
+
+        if let Some(methods) = &self.methods {
+            let context = context.extend_context_with_struct_field("Identity", "methods");
+
+            for (index, method) in methods.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(method.validate_with_context(context)?);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IdentityField {
+    Group,
+    Name,
+    Version,
+    Purl,
+    Cpe,
+    Swid,
+    Hash,
+    #[doc(hidden)]
+    UnknownIdentityField(String),
+}
+
+impl fmt::Display for IdentityField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let field = match self {
+            IdentityField::Group => "group",
+            IdentityField::Name => "name",
+            IdentityField::Version => "version",
+            IdentityField::Purl => "purl",
+            IdentityField::Cpe => "cpe",
+            IdentityField::Swid => "swid",
+            IdentityField::Hash => "hash",
+            IdentityField::UnknownIdentityField(field) => field,
+        };
+        write!(f, "{}", field)
+    }
+}
+
+impl IdentityField {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "group" => Self::Group,
+            "name" => Self::Name,
+            "version" => Self::Version,
+            "purl" => Self::Purl,
+            "cpe" => Self::Cpe,
+            "swid" => Self::Swid,
+            "hash" => Self::Hash,
+            unknown => Self::UnknownIdentityField(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for IdentityField {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            IdentityField::UnknownIdentityField(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Unknown identity field".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A technique used to establish the identity of a component, with the confidence in its result
+#[derive(Debug, PartialEq, Eq)]
+pub struct IdentityMethod {
+    pub technique: IdentityTechnique,
+    pub confidence: ConfidenceScore,
+    pub value: Option<String>,
+}
+
+impl Validate for IdentityMethod {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let technique_context =
+            context.extend_context_with_struct_field("IdentityMethod", "technique");
+        let confidence_context =
+            context.extend_context_with_struct_field("IdentityMethod", "confidence");
+
+        Ok(self
+            .technique
+            .validate_with_context(technique_context)?
+            .merge(self.confidence.validate_with_context(confidence_context)?))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum IdentityTechnique {
+    SourceCodeAnalysis,
+    BinaryAnalysis,
+    ManifestAnalysis,
+    AstFingerprint,
+    HashComparison,
+    Instrumentation,
+    DynamicAnalysis,
+    Filename,
+    Attestation,
+    Other,
+    #[doc(hidden)]
+    UnknownIdentityTechnique(String),
+}
+
+impl fmt::Display for IdentityTechnique {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let technique = match self {
+            IdentityTechnique::SourceCodeAnalysis => "source-code-analysis",
+            IdentityTechnique::BinaryAnalysis => "binary-analysis",
+            IdentityTechnique::ManifestAnalysis => "manifest-analysis",
+            IdentityTechnique::AstFingerprint => "ast-fingerprint",
+            IdentityTechnique::HashComparison => "hash-comparison",
+            IdentityTechnique::Instrumentation => "instrumentation",
+            IdentityTechnique::DynamicAnalysis => "dynamic-analysis",
+            IdentityTechnique::Filename => "filename",
+            IdentityTechnique::Attestation => "attestation",
+            IdentityTechnique::Other => "other",
+            IdentityTechnique::UnknownIdentityTechnique(technique) => technique,
+        };
+        write!(f, "{}", technique)
+    }
+}
+
+impl IdentityTechnique {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "source-code-analysis" => Self::SourceCodeAnalysis,
+            "binary-analysis" => Self::BinaryAnalysis,
+            "manifest-analysis" => Self::ManifestAnalysis,
+            "ast-fingerprint" => Self::AstFingerprint,
+            "hash-comparison" => Self::HashComparison,
+            "instrumentation" => Self::Instrumentation,
+            "dynamic-analysis" => Self::DynamicAnalysis,
+            "filename" => Self::Filename,
+            "attestation" => Self::Attestation,
+            "other" => Self::Other,
+            unknown => Self::UnknownIdentityTechnique(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for IdentityTechnique {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            IdentityTechnique::UnknownIdentityTechnique(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Unknown identity technique".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A confidence value between 0 and 1, where 1 is 100% confidence
+#[derive(Debug, PartialEq)]
+pub struct ConfidenceScore(pub(crate) f32);
+
+// Scores outside of 0..=1, including NaN, fail validation, so equality is total for any valid score
+impl Eq for ConfidenceScore {}
+
+impl ConfidenceScore {
+    pub fn to_f32(&self) -> f32 {
+        self.0
+    }
+}
+
+impl From<f32> for ConfidenceScore {
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+impl Validate for ConfidenceScore {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        if (0.0..=1.0).contains(&self.0) {
+            Ok(ValidationResult::Passed)
+        } else {
+            Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Confidence must be between 0 and 1".to_string(),
+                    context,
+                }],
+            })
+        }
+    }
+}
+
+/// The locations where the component was found
+#[derive(Debug, PartialEq, Eq)]
+pub struct Occurrences(pub Vec<Occurrence>);
+
+impl Validate for Occurrences {
+    fn validate_with_context(
+        &self,
+        _context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        Ok(ValidationResult::default())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Occurrence {
+    pub bom_ref: Option<String>,
+    pub location: String,
+}
+
+/// The call stack through which the component was reached, e.g. as found by a static analysis
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_callstack)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Callstack {
+    pub frames: Option<Vec<Frame>>,
+}
+
+/// A single frame of a [`Callstack`]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub package: Option<String>,
+    pub module: String,
+    pub function: Option<String>,
+    pub parameters: Option<Vec<String>>,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    pub full_filename: Option<String>,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Pedigree {
     pub ancestors: Option<Components>,
@@ -677,7 +1089,14 @@ mod test {
                     "MIT".to_string(),
                 ))])),
                 copyright: Some(CopyrightTexts(vec![Copyright("copyright".to_string())])),
+                identity: None,
+                occurrences: None,
+                callstack: None,
             }),
+            release_notes: None,
+            model_card: None,
+            data: None,
+            signature: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -765,7 +1184,14 @@ mod test {
                     "invalid license".to_string(),
                 ))])),
                 copyright: Some(CopyrightTexts(vec![Copyright("copyright".to_string())])),
+                identity: None,
+                occurrences: None,
+                callstack: None,
             }),
+            release_notes: None,
+            model_card: None,
+            data: None,
+            signature: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -775,6 +1201,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown classification".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -785,6 +1212,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "MimeType does not match regular expression".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -795,6 +1223,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -811,6 +1240,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -823,6 +1253,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -835,6 +1266,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -847,6 +1279,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -859,6 +1292,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -871,6 +1305,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -883,6 +1318,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown scope".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -893,6 +1329,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "HashValue does not match regular expression".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -908,6 +1345,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "SPDX expression is not valid".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -922,6 +1360,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -934,6 +1373,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Cpe does not match regular expression".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -944,6 +1384,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Purl does not conform to Package URL spec: missing scheme"
                             .to_string(),
                         context: ValidationContext(vec![
@@ -955,6 +1396,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -975,6 +1417,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Uri does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -989,6 +1432,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown classification".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -1008,6 +1452,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown classification".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -1027,6 +1472,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown classification".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -1046,6 +1492,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -1067,6 +1514,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown patch classification".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -1086,6 +1534,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown external reference type".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -1101,6 +1550,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -1118,6 +1568,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown classification".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -1133,6 +1584,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "SPDX expression is not valid".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -1155,6 +1607,48 @@ mod test {
         );
     }
 
+    #[test]
+    fn libraries_without_purl_or_licenses_should_pass_with_warnings() {
+        let validation_result = Components(vec![Component::new(
+            Classification::Library,
+            "name",
+            "1.0.0",
+            None,
+        )])
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::PassedWithWarnings {
+                warnings: vec![
+                    FailureReason {
+                        severity: Severity::Warning,
+                        message: "Component has no licenses".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Component".to_string(),
+                                field_name: "licenses".to_string()
+                            },
+                        ])
+                    },
+                    FailureReason {
+                        severity: Severity::Warning,
+                        message: "Component has no package URL".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Component".to_string(),
+                                field_name: "purl".to_string()
+                            },
+                        ])
+                    },
+                ]
+            }
+        );
+    }
+
     fn invalid_component() -> Component {
         Component {
             component_type: Classification::UnknownClassification("unknown".to_string()),
@@ -1180,6 +1674,10 @@ mod test {
             properties: None,
             components: None,
             evidence: None,
+            release_notes: None,
+            model_card: None,
+            data: None,
+            signature: None,
         }
     }
 }
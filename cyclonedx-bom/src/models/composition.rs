@@ -16,8 +16,9 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::models::signature::Signature;
 use crate::validation::{
-    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
     ValidationResult,
 };
 
@@ -26,6 +27,7 @@ pub struct Composition {
     pub aggregate: AggregateType,
     pub assemblies: Option<Vec<BomReference>>,
     pub dependencies: Option<Vec<BomReference>>,
+    pub signature: Option<Signature>,
 }
 
 impl Validate for Composition {
@@ -40,6 +42,12 @@ impl Validate for Composition {
 
         results.push(self.aggregate.validate_with_context(aggregate_context)?);
 
+        if let Some(signature) = &self.signature {
+            let context = context.extend_context_with_struct_field("Composition", "signature");
+
+            results.push(signature.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
@@ -117,6 +125,7 @@ impl Validate for AggregateType {
         match self {
             AggregateType::UnknownAggregateType(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Unknown aggregate type".to_string(),
                     context,
                 }],
@@ -140,6 +149,7 @@ mod test {
             aggregate: AggregateType::Complete,
             assemblies: Some(vec![BomReference("reference".to_string())]),
             dependencies: Some(vec![BomReference("reference".to_string())]),
+            signature: None,
         }])
         .validate()
         .expect("Error while validating");
@@ -153,6 +163,7 @@ mod test {
             aggregate: AggregateType::UnknownAggregateType("unknown aggregate type".to_string()),
             assemblies: Some(vec![BomReference("reference".to_string())]),
             dependencies: Some(vec![BomReference("reference".to_string())]),
+            signature: None,
         }])
         .validate()
         .expect("Error while validating");
@@ -161,6 +172,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Unknown aggregate type".to_string(),
                     context: ValidationContext(vec![
                         ValidationPathComponent::Array { index: 0 },
@@ -0,0 +1,351 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::fmt;
+
+use crate::external_models::{normalized_string::NormalizedString, uri::Uri};
+use crate::models::attached_text::AttachedText;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::property::Properties;
+use crate::validation::{
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// Describes the data a component carries, such as a dataset used to train a machine learning model
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_componentDataType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct ComponentData {
+    pub bom_ref: Option<String>,
+    pub data_type: ComponentDataType,
+    pub name: Option<NormalizedString>,
+    pub contents: Option<DataContents>,
+    pub classification: Option<NormalizedString>,
+    pub sensitive_data: Option<Vec<String>>,
+    pub description: Option<String>,
+    pub governance: Option<DataGovernance>,
+}
+
+impl ComponentData {
+    /// Construct a `ComponentData` of the given type
+    /// ```
+    /// use cyclonedx_bom::models::data::{ComponentData, ComponentDataType};
+    ///
+    /// let data = ComponentData::new(ComponentDataType::Dataset, Some("training-data".to_string()));
+    /// ```
+    pub fn new(data_type: ComponentDataType, bom_ref: Option<String>) -> Self {
+        Self {
+            bom_ref,
+            data_type,
+            name: None,
+            contents: None,
+            classification: None,
+            sensitive_data: None,
+            description: None,
+            governance: None,
+        }
+    }
+}
+
+impl Validate for ComponentData {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let data_type_context =
+            context.extend_context_with_struct_field("ComponentData", "data_type");
+        results.push(self.data_type.validate_with_context(data_type_context)?);
+
+        if let Some(name) = &self.name {
+            let context = context.extend_context_with_struct_field("ComponentData", "name");
+
+            results.push(name.validate_with_context(context)?);
+        }
+
+        if let Some(contents) = &self.contents {
+            let context = context.extend_context_with_struct_field("ComponentData", "contents");
+
+            results.push(contents.validate_with_context(context)?);
+        }
+
+        if let Some(classification) = &self.classification {
+            let context =
+                context.extend_context_with_struct_field("ComponentData", "classification");
+
+            results.push(classification.validate_with_context(context)?);
+        }
+
+        if let Some(governance) = &self.governance {
+            let context = context.extend_context_with_struct_field("ComponentData", "governance");
+
+            results.push(governance.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ComponentDataType {
+    SourceCode,
+    Configuration,
+    Dataset,
+    Definition,
+    Other,
+    #[doc(hidden)]
+    UnknownComponentDataType(String),
+}
+
+impl fmt::Display for ComponentDataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data_type = match self {
+            ComponentDataType::SourceCode => "source-code",
+            ComponentDataType::Configuration => "configuration",
+            ComponentDataType::Dataset => "dataset",
+            ComponentDataType::Definition => "definition",
+            ComponentDataType::Other => "other",
+            ComponentDataType::UnknownComponentDataType(data_type) => data_type,
+        };
+        write!(f, "{}", data_type)
+    }
+}
+
+impl ComponentDataType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "source-code" => Self::SourceCode,
+            "configuration" => Self::Configuration,
+            "dataset" => Self::Dataset,
+            "definition" => Self::Definition,
+            "other" => Self::Other,
+            unknown => Self::UnknownComponentDataType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ComponentDataType {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ComponentDataType::UnknownComponentDataType(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Unknown component data type".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// The contents or references to the contents of the data being described
+#[derive(Debug, PartialEq, Eq)]
+pub struct DataContents {
+    pub attachment: Option<AttachedText>,
+    pub url: Option<Uri>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for DataContents {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(attachment) = &self.attachment {
+            let context = context.extend_context_with_struct_field("DataContents", "attachment");
+
+            results.push(attachment.validate_with_context(context)?);
+        }
+
+        if let Some(url) = &self.url {
+            let context = context.extend_context_with_struct_field("DataContents", "url");
+
+            results.push(url.validate_with_context(context)?);
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("DataContents", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The parties responsible for the governance of the data
+#[derive(Debug, PartialEq, Eq)]
+pub struct DataGovernance {
+    pub custodians: Option<Vec<DataGovernanceResponsibleParty>>,
+    pub stewards: Option<Vec<DataGovernanceResponsibleParty>>,
+    pub owners: Option<Vec<DataGovernanceResponsibleParty>>,
+}
+
+impl Validate for DataGovernance {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (field_name, parties) in [
+            ("custodians", &self.custodians),
+            ("stewards", &self.stewards),
+            ("owners", &self.owners),
+        ] {
+            if let Some(parties) = parties {
+                let context =
+                    context.extend_context_with_struct_field("DataGovernance", field_name);
+
+                for (index, party) in parties.iter().enumerate() {
+                    let context =
+                        context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                    results.push(party.validate_with_context(context)?);
+                }
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DataGovernanceResponsibleParty {
+    Organization(OrganizationalEntity),
+    Contact(OrganizationalContact),
+}
+
+impl Validate for DataGovernanceResponsibleParty {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            DataGovernanceResponsibleParty::Organization(organization) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Organization".to_string(),
+                }]);
+                organization.validate_with_context(context)
+            }
+            DataGovernanceResponsibleParty::Contact(contact) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Contact".to_string(),
+                }]);
+                contact.validate_with_context(context)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = ComponentData {
+            bom_ref: Some("data-1".to_string()),
+            data_type: ComponentDataType::Dataset,
+            name: Some(NormalizedString::new("training data")),
+            contents: Some(DataContents {
+                attachment: None,
+                url: Some(Uri("https://example.com/data.csv".to_string())),
+                properties: None,
+            }),
+            classification: Some(NormalizedString::new("public")),
+            sensitive_data: Some(vec!["PII".to_string()]),
+            description: Some("description".to_string()),
+            governance: Some(DataGovernance {
+                custodians: None,
+                stewards: None,
+                owners: Some(vec![DataGovernanceResponsibleParty::Contact(
+                    OrganizationalContact::new("Jane Doe", None),
+                )]),
+            }),
+        }
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = ComponentData {
+            bom_ref: None,
+            data_type: ComponentDataType::UnknownComponentDataType("unknown".to_string()),
+            name: None,
+            contents: Some(DataContents {
+                attachment: None,
+                url: Some(Uri("invalid uri".to_string())),
+                properties: None,
+            }),
+            classification: None,
+            sensitive_data: None,
+            description: None,
+            governance: None,
+        }
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason {
+                        severity: Severity::Error,
+                        message: "Unknown component data type".to_string(),
+                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                            struct_name: "ComponentData".to_string(),
+                            field_name: "data_type".to_string()
+                        }])
+                    },
+                    FailureReason {
+                        severity: Severity::Error,
+                        message: "Uri does not conform to ISO 8601".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "ComponentData".to_string(),
+                                field_name: "contents".to_string()
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "DataContents".to_string(),
+                                field_name: "url".to_string()
+                            },
+                        ])
+                    },
+                ]
+            }
+        );
+    }
+}
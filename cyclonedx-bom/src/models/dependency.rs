@@ -17,7 +17,18 @@
  */
 
 #[derive(Debug, PartialEq, Eq)]
-pub struct Dependencies(pub(crate) Vec<Dependency>);
+pub struct Dependencies(pub Vec<Dependency>);
+
+impl Dependencies {
+    /// Sort the dependencies by their reference, and the references each one depends on
+    pub fn sort(&mut self) {
+        for dependency in &mut self.0 {
+            dependency.dependencies.sort();
+        }
+        self.0
+            .sort_by(|left, right| left.dependency_ref.cmp(&right.dependency_ref));
+    }
+}
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Dependency {
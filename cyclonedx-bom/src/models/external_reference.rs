@@ -19,7 +19,7 @@
 use crate::external_models::uri::Uri;
 use crate::models::hash::Hashes;
 use crate::validation::{
-    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
     ValidationResult,
 };
 
@@ -110,6 +110,30 @@ pub enum ExternalReferenceType {
     License,
     BuildMeta,
     BuildSystem,
+    ReleaseNotes,
+    DistributionIntake,
+    SecurityContact,
+    ModelCard,
+    Log,
+    Configuration,
+    Evidence,
+    Formulation,
+    Attestation,
+    ThreatModel,
+    AdversaryModel,
+    RiskAssessment,
+    VulnerabilityAssertion,
+    ExploitabilityStatement,
+    PentestReport,
+    StaticAnalysisReport,
+    DynamicAnalysisReport,
+    RuntimeAnalysisReport,
+    ComponentAnalysisReport,
+    MaturityReport,
+    CertificationReport,
+    CodifiedInfrastructure,
+    QualityMetrics,
+    Poam,
     Other,
     #[doc(hidden)]
     UnknownExternalReferenceType(String),
@@ -132,6 +156,30 @@ impl ToString for ExternalReferenceType {
             ExternalReferenceType::License => "license",
             ExternalReferenceType::BuildMeta => "build-meta",
             ExternalReferenceType::BuildSystem => "build-system",
+            ExternalReferenceType::ReleaseNotes => "release-notes",
+            ExternalReferenceType::DistributionIntake => "distribution-intake",
+            ExternalReferenceType::SecurityContact => "security-contact",
+            ExternalReferenceType::ModelCard => "model-card",
+            ExternalReferenceType::Log => "log",
+            ExternalReferenceType::Configuration => "configuration",
+            ExternalReferenceType::Evidence => "evidence",
+            ExternalReferenceType::Formulation => "formulation",
+            ExternalReferenceType::Attestation => "attestation",
+            ExternalReferenceType::ThreatModel => "threat-model",
+            ExternalReferenceType::AdversaryModel => "adversary-model",
+            ExternalReferenceType::RiskAssessment => "risk-assessment",
+            ExternalReferenceType::VulnerabilityAssertion => "vulnerability-assertion",
+            ExternalReferenceType::ExploitabilityStatement => "exploitability-statement",
+            ExternalReferenceType::PentestReport => "pentest-report",
+            ExternalReferenceType::StaticAnalysisReport => "static-analysis-report",
+            ExternalReferenceType::DynamicAnalysisReport => "dynamic-analysis-report",
+            ExternalReferenceType::RuntimeAnalysisReport => "runtime-analysis-report",
+            ExternalReferenceType::ComponentAnalysisReport => "component-analysis-report",
+            ExternalReferenceType::MaturityReport => "maturity-report",
+            ExternalReferenceType::CertificationReport => "certification-report",
+            ExternalReferenceType::CodifiedInfrastructure => "codified-infrastructure",
+            ExternalReferenceType::QualityMetrics => "quality-metrics",
+            ExternalReferenceType::Poam => "poam",
             ExternalReferenceType::Other => "other",
             ExternalReferenceType::UnknownExternalReferenceType(un) => un,
         }
@@ -156,6 +204,30 @@ impl ExternalReferenceType {
             "license" => Self::License,
             "build-meta" => Self::BuildMeta,
             "build-system" => Self::BuildSystem,
+            "release-notes" => Self::ReleaseNotes,
+            "distribution-intake" => Self::DistributionIntake,
+            "security-contact" => Self::SecurityContact,
+            "model-card" => Self::ModelCard,
+            "log" => Self::Log,
+            "configuration" => Self::Configuration,
+            "evidence" => Self::Evidence,
+            "formulation" => Self::Formulation,
+            "attestation" => Self::Attestation,
+            "threat-model" => Self::ThreatModel,
+            "adversary-model" => Self::AdversaryModel,
+            "risk-assessment" => Self::RiskAssessment,
+            "vulnerability-assertion" => Self::VulnerabilityAssertion,
+            "exploitability-statement" => Self::ExploitabilityStatement,
+            "pentest-report" => Self::PentestReport,
+            "static-analysis-report" => Self::StaticAnalysisReport,
+            "dynamic-analysis-report" => Self::DynamicAnalysisReport,
+            "runtime-analysis-report" => Self::RuntimeAnalysisReport,
+            "component-analysis-report" => Self::ComponentAnalysisReport,
+            "maturity-report" => Self::MaturityReport,
+            "certification-report" => Self::CertificationReport,
+            "codified-infrastructure" => Self::CodifiedInfrastructure,
+            "quality-metrics" => Self::QualityMetrics,
+            "poam" => Self::Poam,
             "other" => Self::Other,
             unknown => Self::UnknownExternalReferenceType(unknown.to_string()),
         }
@@ -171,6 +243,7 @@ impl Validate for ExternalReferenceType {
             ExternalReferenceType::UnknownExternalReferenceType(_) => {
                 Ok(ValidationResult::Failed {
                     reasons: vec![FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown external reference type".to_string(),
                         context,
                     }],
@@ -224,6 +297,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown external reference type".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -234,6 +308,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Uri does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -244,6 +319,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "HashValue does not match regular expression".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
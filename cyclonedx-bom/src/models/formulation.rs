@@ -0,0 +1,519 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::fmt;
+
+use crate::external_models::{date_time::DateTime, normalized_string::NormalizedString};
+use crate::models::component::Components;
+use crate::models::dependency::Dependencies;
+use crate::models::property::Properties;
+use crate::models::service::Services;
+use crate::validation::{
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// Describes how a component or service was manufactured or deployed
+#[derive(Debug, PartialEq, Eq)]
+pub struct Formulation(pub Vec<Formula>);
+
+impl Validate for Formulation {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, formula) in self.0.iter().enumerate() {
+            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(formula.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The components, services and workflows used to manufacture or deploy a component or service
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_formulaType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Formula {
+    pub bom_ref: Option<String>,
+    pub components: Option<Components>,
+    pub services: Option<Services>,
+    pub workflows: Option<Vec<Workflow>>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Formula {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(components) = &self.components {
+            let context = context.extend_context_with_struct_field("Formula", "components");
+
+            results.push(components.validate_with_context(context)?);
+        }
+
+        if let Some(services) = &self.services {
+            let context = context.extend_context_with_struct_field("Formula", "services");
+
+            results.push(services.validate_with_context(context)?);
+        }
+
+        if let Some(workflows) = &self.workflows {
+            let context = context.extend_context_with_struct_field("Formula", "workflows");
+
+            for (index, workflow) in workflows.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(workflow.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Formula", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A specialized orchestration task
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_workflowType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Workflow {
+    pub bom_ref: String,
+    pub uid: NormalizedString,
+    pub name: Option<NormalizedString>,
+    pub description: Option<String>,
+    pub tasks: Option<Vec<Task>>,
+    pub task_dependencies: Option<Dependencies>,
+    pub task_types: Vec<TaskType>,
+    pub steps: Option<Vec<Step>>,
+    pub time_start: Option<DateTime>,
+    pub time_end: Option<DateTime>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Workflow {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let uid_context = context.extend_context_with_struct_field("Workflow", "uid");
+        results.push(self.uid.validate_with_context(uid_context)?);
+
+        if let Some(name) = &self.name {
+            let context = context.extend_context_with_struct_field("Workflow", "name");
+
+            results.push(name.validate_with_context(context)?);
+        }
+
+        if let Some(tasks) = &self.tasks {
+            let context = context.extend_context_with_struct_field("Workflow", "tasks");
+
+            for (index, task) in tasks.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(task.validate_with_context(context)?);
+            }
+        }
+
+        let task_types_context = context.extend_context_with_struct_field("Workflow", "task_types");
+        for (index, task_type) in self.task_types.iter().enumerate() {
+            let context =
+                task_types_context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(task_type.validate_with_context(context)?);
+        }
+
+        if let Some(steps) = &self.steps {
+            let context = context.extend_context_with_struct_field("Workflow", "steps");
+
+            for (index, step) in steps.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(step.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(time_start) = &self.time_start {
+            let context = context.extend_context_with_struct_field("Workflow", "time_start");
+
+            results.push(time_start.validate_with_context(context)?);
+        }
+
+        if let Some(time_end) = &self.time_end {
+            let context = context.extend_context_with_struct_field("Workflow", "time_end");
+
+            results.push(time_end.validate_with_context(context)?);
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Workflow", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// Describes the inputs, sequence of steps and resources used to accomplish a task and its output
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_taskType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Task {
+    pub bom_ref: String,
+    pub uid: NormalizedString,
+    pub name: Option<NormalizedString>,
+    pub description: Option<String>,
+    pub task_types: Vec<TaskType>,
+    pub steps: Option<Vec<Step>>,
+    pub time_start: Option<DateTime>,
+    pub time_end: Option<DateTime>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Task {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let uid_context = context.extend_context_with_struct_field("Task", "uid");
+        results.push(self.uid.validate_with_context(uid_context)?);
+
+        if let Some(name) = &self.name {
+            let context = context.extend_context_with_struct_field("Task", "name");
+
+            results.push(name.validate_with_context(context)?);
+        }
+
+        let task_types_context = context.extend_context_with_struct_field("Task", "task_types");
+        for (index, task_type) in self.task_types.iter().enumerate() {
+            let context =
+                task_types_context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(task_type.validate_with_context(context)?);
+        }
+
+        if let Some(steps) = &self.steps {
+            let context = context.extend_context_with_struct_field("Task", "steps");
+
+            for (index, step) in steps.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(step.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(time_start) = &self.time_start {
+            let context = context.extend_context_with_struct_field("Task", "time_start");
+
+            results.push(time_start.validate_with_context(context)?);
+        }
+
+        if let Some(time_end) = &self.time_end {
+            let context = context.extend_context_with_struct_field("Task", "time_end");
+
+            results.push(time_end.validate_with_context(context)?);
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Task", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// Executes specific commands or tools in order to accomplish its owning task as part of a sequence
+#[derive(Debug, PartialEq, Eq)]
+pub struct Step {
+    pub name: Option<NormalizedString>,
+    pub description: Option<String>,
+    pub commands: Option<Vec<Command>>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Step {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(name) = &self.name {
+            let context = context.extend_context_with_struct_field("Step", "name");
+
+            results.push(name.validate_with_context(context)?);
+        }
+
+        if let Some(commands) = &self.commands {
+            let context = context.extend_context_with_struct_field("Step", "commands");
+
+            for (index, command) in commands.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(command.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Step", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Command {
+    pub executed: Option<String>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for Command {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match &self.properties {
+            Some(properties) => {
+                let context = context.extend_context_with_struct_field("Command", "properties");
+                properties.validate_with_context(context)
+            }
+            None => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TaskType {
+    Copy,
+    Clone,
+    Lint,
+    Scan,
+    Merge,
+    Build,
+    Test,
+    Deliver,
+    Deploy,
+    Release,
+    Clean,
+    Other,
+    #[doc(hidden)]
+    UnknownTaskType(String),
+}
+
+impl fmt::Display for TaskType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let task_type = match self {
+            TaskType::Copy => "copy",
+            TaskType::Clone => "clone",
+            TaskType::Lint => "lint",
+            TaskType::Scan => "scan",
+            TaskType::Merge => "merge",
+            TaskType::Build => "build",
+            TaskType::Test => "test",
+            TaskType::Deliver => "deliver",
+            TaskType::Deploy => "deploy",
+            TaskType::Release => "release",
+            TaskType::Clean => "clean",
+            TaskType::Other => "other",
+            TaskType::UnknownTaskType(task_type) => task_type,
+        };
+        write!(f, "{}", task_type)
+    }
+}
+
+impl TaskType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "copy" => Self::Copy,
+            "clone" => Self::Clone,
+            "lint" => Self::Lint,
+            "scan" => Self::Scan,
+            "merge" => Self::Merge,
+            "build" => Self::Build,
+            "test" => Self::Test,
+            "deliver" => Self::Deliver,
+            "deploy" => Self::Deploy,
+            "release" => Self::Release,
+            "clean" => Self::Clean,
+            "other" => Self::Other,
+            unknown => Self::UnknownTaskType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for TaskType {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            TaskType::UnknownTaskType(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Unknown task type".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn example_workflow(task_type: TaskType, time_start: &str) -> Workflow {
+        Workflow {
+            bom_ref: "workflow-1".to_string(),
+            uid: NormalizedString::new("8edb2b08-e2c7-11ed-b5ea-0242ac120002"),
+            name: Some(NormalizedString::new("My workflow")),
+            description: None,
+            tasks: Some(vec![Task {
+                bom_ref: "task-1".to_string(),
+                uid: NormalizedString::new("task-uid-1"),
+                name: None,
+                description: None,
+                task_types: vec![TaskType::Build],
+                steps: Some(vec![Step {
+                    name: Some(NormalizedString::new("compile")),
+                    description: None,
+                    commands: Some(vec![Command {
+                        executed: Some("cargo build".to_string()),
+                        properties: None,
+                    }]),
+                    properties: None,
+                }]),
+                time_start: None,
+                time_end: None,
+                properties: None,
+            }]),
+            task_dependencies: None,
+            task_types: vec![task_type],
+            steps: None,
+            time_start: Some(DateTime(time_start.to_string())),
+            time_end: None,
+            properties: None,
+        }
+    }
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = Formulation(vec![Formula {
+            bom_ref: Some("formula-1".to_string()),
+            components: None,
+            services: None,
+            workflows: Some(vec![example_workflow(
+                TaskType::Build,
+                "2023-01-01T00:00:00Z",
+            )]),
+            properties: None,
+        }])
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = Formulation(vec![Formula {
+            bom_ref: None,
+            components: None,
+            services: None,
+            workflows: Some(vec![example_workflow(
+                TaskType::UnknownTaskType("unknown".to_string()),
+                "invalid date",
+            )]),
+            properties: None,
+        }])
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason {
+                        severity: Severity::Error,
+                        message: "Unknown task type".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Formula".to_string(),
+                                field_name: "workflows".to_string()
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Workflow".to_string(),
+                                field_name: "task_types".to_string()
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                        ])
+                    },
+                    FailureReason {
+                        severity: Severity::Error,
+                        message: "DateTime does not conform to ISO 8601".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Formula".to_string(),
+                                field_name: "workflows".to_string()
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Workflow".to_string(),
+                                field_name: "time_start".to_string()
+                            },
+                        ])
+                    },
+                ]
+            }
+        );
+    }
+}
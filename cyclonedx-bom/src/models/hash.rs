@@ -20,7 +20,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 
 use crate::validation::{
-    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
     ValidationResult,
 };
 
@@ -147,6 +147,7 @@ impl Validate for HashAlgorithm {
         match self {
             HashAlgorithm::UnknownHashAlgorithm(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Unknown HashAlgorithm".to_string(),
                     context,
                 }],
@@ -160,6 +161,18 @@ impl Validate for HashAlgorithm {
 #[derive(Debug, PartialEq, Eq)]
 pub struct HashValue(pub(crate) String);
 
+impl HashValue {
+    /// Construct a `HashValue` from the hex encoded digest of a hash
+    /// ```
+    /// use cyclonedx_bom::models::hash::HashValue;
+    ///
+    /// let hash_value = HashValue::new("a3bf4f1b2b0b822cd15d6c15b0f00a089f86d081884c7d659a2feaa0c55ad015");
+    /// ```
+    pub fn new(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
 impl Validate for HashValue {
     fn validate_with_context(
         &self,
@@ -178,6 +191,7 @@ impl Validate for HashValue {
                 } else {
                     Ok(ValidationResult::Failed {
                         reasons: vec![FailureReason {
+                            severity: Severity::Error,
                             message: "HashValue does not match regular expression".to_string(),
                             context,
                         }],
@@ -220,6 +234,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown HashAlgorithm".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -230,6 +245,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "HashValue does not match regular expression".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -17,16 +17,20 @@
  */
 
 use std::convert::TryFrom;
+use std::fmt;
 
 use crate::external_models::spdx::SpdxIdentifierError;
 use crate::external_models::{
+    date_time::DateTime,
     normalized_string::NormalizedString,
     spdx::{SpdxExpression, SpdxIdentifier},
     uri::Uri,
 };
 use crate::models::attached_text::AttachedText;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
 use crate::validation::{
-    Validate, ValidationContext, ValidationError, ValidationPathComponent, ValidationResult,
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
 };
 
 /// Represents whether a license is a named license or an SPDX license expression
@@ -72,6 +76,7 @@ pub struct License {
     pub license_identifier: LicenseIdentifier,
     pub text: Option<AttachedText>,
     pub url: Option<Uri>,
+    pub licensing: Option<Box<Licensing>>,
 }
 
 impl License {
@@ -86,6 +91,7 @@ impl License {
             license_identifier: LicenseIdentifier::Name(NormalizedString::new(license)),
             text: None,
             url: None,
+            licensing: None,
         }
     }
 
@@ -102,6 +108,7 @@ impl License {
             )?),
             text: None,
             url: None,
+            licensing: None,
         })
     }
 }
@@ -133,12 +140,217 @@ impl Validate for License {
             results.push(url.validate_with_context(context)?);
         }
 
+        if let Some(licensing) = &self.licensing {
+            let context = context.extend_context_with_struct_field("License", "licensing");
+
+            results.push(licensing.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The terms under which a commercial or proprietary license was granted
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_licenseType)
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Licensing {
+    pub alt_ids: Option<Vec<NormalizedString>>,
+    pub licensor: Option<LicenseParty>,
+    pub licensee: Option<LicenseParty>,
+    pub purchaser: Option<LicenseParty>,
+    pub purchase_order: Option<String>,
+    pub license_types: Option<Vec<LicenseType>>,
+    pub last_renewal: Option<DateTime>,
+    pub expiration: Option<DateTime>,
+}
+
+impl Validate for Licensing {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(alt_ids) = &self.alt_ids {
+            let context = context.extend_context_with_struct_field("Licensing", "alt_ids");
+
+            for (index, alt_id) in alt_ids.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(alt_id.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(licensor) = &self.licensor {
+            let context = context.extend_context_with_struct_field("Licensing", "licensor");
+
+            results.push(licensor.validate_with_context(context)?);
+        }
+
+        if let Some(licensee) = &self.licensee {
+            let context = context.extend_context_with_struct_field("Licensing", "licensee");
+
+            results.push(licensee.validate_with_context(context)?);
+        }
+
+        if let Some(purchaser) = &self.purchaser {
+            let context = context.extend_context_with_struct_field("Licensing", "purchaser");
+
+            results.push(purchaser.validate_with_context(context)?);
+        }
+
+        if let Some(license_types) = &self.license_types {
+            let context = context.extend_context_with_struct_field("Licensing", "license_types");
+
+            for (index, license_type) in license_types.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(license_type.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(last_renewal) = &self.last_renewal {
+            let context = context.extend_context_with_struct_field("Licensing", "last_renewal");
+
+            results.push(last_renewal.validate_with_context(context)?);
+        }
+
+        if let Some(expiration) = &self.expiration {
+            let context = context.extend_context_with_struct_field("Licensing", "expiration");
+
+            results.push(expiration.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
     }
 }
 
+/// The organization or individual that grants, holds, or purchased a license
+#[derive(Debug, PartialEq, Eq)]
+pub enum LicenseParty {
+    Organization(OrganizationalEntity),
+    Individual(OrganizationalContact),
+}
+
+impl Validate for LicenseParty {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            LicenseParty::Organization(organization) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Organization".to_string(),
+                }]);
+                organization.validate_with_context(context)
+            }
+            LicenseParty::Individual(individual) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Individual".to_string(),
+                }]);
+                individual.validate_with_context(context)
+            }
+        }
+    }
+}
+
+/// The type of a commercial or proprietary license
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_licenseTypeEnum)
+#[derive(Debug, PartialEq, Eq)]
+pub enum LicenseType {
+    Academic,
+    Appliance,
+    ClientAccess,
+    ConcurrentUser,
+    CorePoints,
+    CustomMetric,
+    Device,
+    Evaluation,
+    NamedUser,
+    NodeLocked,
+    Oem,
+    Perpetual,
+    ProcessorPoints,
+    Subscription,
+    User,
+    Other,
+    #[doc(hidden)]
+    UnknownLicenseType(String),
+}
+
+impl fmt::Display for LicenseType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let license_type = match self {
+            LicenseType::Academic => "academic",
+            LicenseType::Appliance => "appliance",
+            LicenseType::ClientAccess => "client-access",
+            LicenseType::ConcurrentUser => "concurrent-user",
+            LicenseType::CorePoints => "core-points",
+            LicenseType::CustomMetric => "custom-metric",
+            LicenseType::Device => "device",
+            LicenseType::Evaluation => "evaluation",
+            LicenseType::NamedUser => "named-user",
+            LicenseType::NodeLocked => "node-locked",
+            LicenseType::Oem => "oem",
+            LicenseType::Perpetual => "perpetual",
+            LicenseType::ProcessorPoints => "processor-points",
+            LicenseType::Subscription => "subscription",
+            LicenseType::User => "user",
+            LicenseType::Other => "other",
+            LicenseType::UnknownLicenseType(license_type) => license_type,
+        };
+        write!(f, "{}", license_type)
+    }
+}
+
+impl LicenseType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "academic" => Self::Academic,
+            "appliance" => Self::Appliance,
+            "client-access" => Self::ClientAccess,
+            "concurrent-user" => Self::ConcurrentUser,
+            "core-points" => Self::CorePoints,
+            "custom-metric" => Self::CustomMetric,
+            "device" => Self::Device,
+            "evaluation" => Self::Evaluation,
+            "named-user" => Self::NamedUser,
+            "node-locked" => Self::NodeLocked,
+            "oem" => Self::Oem,
+            "perpetual" => Self::Perpetual,
+            "processor-points" => Self::ProcessorPoints,
+            "subscription" => Self::Subscription,
+            "user" => Self::User,
+            "other" => Self::Other,
+            unknown => Self::UnknownLicenseType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for LicenseType {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            LicenseType::UnknownLicenseType(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Unknown license type".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Licenses(pub Vec<LicenseChoice>);
 
@@ -195,8 +407,6 @@ impl Validate for LicenseIdentifier {
 
 #[cfg(test)]
 mod test {
-    use crate::validation::FailureReason;
-
     use super::*;
     use pretty_assertions::assert_eq;
 
@@ -219,6 +429,7 @@ mod test {
             )),
             text: None,
             url: None,
+            licensing: None,
         })])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -227,6 +438,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
                     context: ValidationContext(vec![
@@ -253,6 +465,7 @@ mod test {
             license_identifier: LicenseIdentifier::SpdxId(SpdxIdentifier("Apache=2.0".to_string())),
             text: None,
             url: None,
+            licensing: None,
         })])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -261,6 +474,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "SPDX identifier is not valid".to_string(),
                     context: ValidationContext(vec![
                         ValidationPathComponent::Array { index: 0 },
@@ -292,6 +506,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "SPDX expression is not valid".to_string(),
                     context: ValidationContext(vec![
                         ValidationPathComponent::Array { index: 0 },
@@ -314,6 +529,7 @@ mod test {
                 )),
                 text: None,
                 url: None,
+                licensing: None,
             }),
             LicenseChoice::License(License {
                 license_identifier: LicenseIdentifier::SpdxId(SpdxIdentifier(
@@ -321,6 +537,7 @@ mod test {
                 )),
                 text: None,
                 url: None,
+                licensing: None,
             }),
         ])
         .validate_with_context(ValidationContext::default())
@@ -331,6 +548,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -349,6 +567,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "SPDX identifier is not valid".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 2 },
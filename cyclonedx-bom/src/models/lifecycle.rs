@@ -0,0 +1,207 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::fmt;
+
+use crate::external_models::normalized_string::NormalizedString;
+use crate::validation::{
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// The product lifecycle phases that the BOM describes
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_metadata)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lifecycles(pub Vec<Lifecycle>);
+
+impl Validate for Lifecycles {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, lifecycle) in self.0.iter().enumerate() {
+            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(lifecycle.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A lifecycle is either one of the pre-defined phases or a custom named phase
+#[derive(Debug, PartialEq, Eq)]
+pub enum Lifecycle {
+    Phase(LifecyclePhase),
+    Description {
+        name: NormalizedString,
+        description: Option<String>,
+    },
+}
+
+impl Validate for Lifecycle {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Lifecycle::Phase(phase) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Phase".to_string(),
+                }]);
+                phase.validate_with_context(context)
+            }
+            Lifecycle::Description { name, .. } => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Description".to_string(),
+                }]);
+                name.validate_with_context(context)
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LifecyclePhase {
+    Design,
+    PreBuild,
+    Build,
+    PostBuild,
+    Operations,
+    Discovery,
+    Decommission,
+    #[doc(hidden)]
+    UnknownLifecyclePhase(String),
+}
+
+impl fmt::Display for LifecyclePhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phase = match self {
+            LifecyclePhase::Design => "design",
+            LifecyclePhase::PreBuild => "pre-build",
+            LifecyclePhase::Build => "build",
+            LifecyclePhase::PostBuild => "post-build",
+            LifecyclePhase::Operations => "operations",
+            LifecyclePhase::Discovery => "discovery",
+            LifecyclePhase::Decommission => "decommission",
+            LifecyclePhase::UnknownLifecyclePhase(phase) => phase,
+        };
+        write!(f, "{}", phase)
+    }
+}
+
+impl LifecyclePhase {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "design" => Self::Design,
+            "pre-build" => Self::PreBuild,
+            "build" => Self::Build,
+            "post-build" => Self::PostBuild,
+            "operations" => Self::Operations,
+            "discovery" => Self::Discovery,
+            "decommission" => Self::Decommission,
+            unknown => Self::UnknownLifecyclePhase(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for LifecyclePhase {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            LifecyclePhase::UnknownLifecyclePhase(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Unknown lifecycle phase".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = Lifecycles(vec![
+            Lifecycle::Phase(LifecyclePhase::Build),
+            Lifecycle::Description {
+                name: NormalizedString::new("platform-integration-testing"),
+                description: Some("Integration testing specific to the runtime platform".into()),
+            },
+        ])
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = Lifecycles(vec![
+            Lifecycle::Phase(LifecyclePhase::UnknownLifecyclePhase("unknown".to_string())),
+            Lifecycle::Description {
+                name: NormalizedString("spaces and\ttabs".to_string()),
+                description: None,
+            },
+        ])
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason {
+                        severity: Severity::Error,
+                        message: "Unknown lifecycle phase".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::EnumVariant {
+                                variant_name: "Phase".to_string()
+                            },
+                        ])
+                    },
+                    FailureReason {
+                        severity: Severity::Error,
+                        message:
+                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                                .to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 1 },
+                            ValidationPathComponent::EnumVariant {
+                                variant_name: "Description".to_string()
+                            },
+                        ])
+                    },
+                ]
+            }
+        );
+    }
+}
@@ -21,6 +21,7 @@ use thiserror::Error;
 use crate::external_models::date_time::{DateTime, DateTimeError};
 use crate::models::component::Component;
 use crate::models::license::Licenses;
+use crate::models::lifecycle::Lifecycles;
 use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
 use crate::models::property::Properties;
 use crate::models::tool::Tools;
@@ -34,6 +35,7 @@ use crate::validation::{
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct Metadata {
     pub timestamp: Option<DateTime>,
+    pub lifecycles: Option<Lifecycles>,
     pub tools: Option<Tools>,
     pub authors: Option<Vec<OrganizationalContact>>,
     pub component: Option<Component>,
@@ -78,6 +80,12 @@ impl Validate for Metadata {
             results.push(timestamp.validate_with_context(context)?);
         }
 
+        if let Some(lifecycles) = &self.lifecycles {
+            let context = context.extend_context_with_struct_field("Metadata", "lifecycles");
+
+            results.push(lifecycles.validate_with_context(context)?);
+        }
+
         if let Some(tools) = &self.tools {
             let context = context.extend_context_with_struct_field("Metadata", "tools");
 
@@ -146,7 +154,7 @@ mod test {
         models::{
             component::Classification, license::LicenseChoice, property::Property, tool::Tool,
         },
-        validation::FailureReason,
+        validation::{FailureReason, Severity},
     };
 
     use super::*;
@@ -156,11 +164,12 @@ mod test {
     fn valid_metadata_should_pass_validation() {
         let validation_result = Metadata {
             timestamp: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
-            tools: Some(Tools(vec![Tool {
+            tools: Some(Tools::List(vec![Tool {
                 vendor: Some(NormalizedString::new("vendor")),
                 name: None,
                 version: None,
                 hashes: None,
+                external_references: None,
             }])),
             authors: Some(vec![OrganizationalContact {
                 name: Some(NormalizedString::new("name")),
@@ -191,6 +200,10 @@ mod test {
                 properties: None,
                 components: None,
                 evidence: None,
+                release_notes: None,
+                model_card: None,
+                data: None,
+                signature: None,
             }),
             manufacture: Some(OrganizationalEntity {
                 name: Some(NormalizedString::new("name")),
@@ -209,6 +222,7 @@ mod test {
                 name: "name".to_string(),
                 value: NormalizedString::new("value"),
             }])),
+            lifecycles: None,
         }
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -220,11 +234,12 @@ mod test {
     fn invalid_metadata_should_fail_validation() {
         let validation_result = Metadata {
             timestamp: Some(DateTime("invalid date".to_string())),
-            tools: Some(Tools(vec![Tool {
+            tools: Some(Tools::List(vec![Tool {
                 vendor: Some(NormalizedString("invalid\tvendor".to_string())),
                 name: None,
                 version: None,
                 hashes: None,
+                external_references: None,
             }])),
             authors: Some(vec![OrganizationalContact {
                 name: Some(NormalizedString("invalid\tname".to_string())),
@@ -255,6 +270,10 @@ mod test {
                 properties: None,
                 components: None,
                 evidence: None,
+                release_notes: None,
+                model_card: None,
+                data: None,
+                signature: None,
             }),
             manufacture: Some(OrganizationalEntity {
                 name: Some(NormalizedString("invalid\tname".to_string())),
@@ -273,6 +292,7 @@ mod test {
                 name: "name".to_string(),
                 value: NormalizedString("invalid\tvalue".to_string()),
             }])),
+            lifecycles: None,
         }
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -282,6 +302,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message: "DateTime does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![ValidationPathComponent::Struct {
                             struct_name: "Metadata".to_string(),
@@ -289,6 +310,7 @@ mod test {
                         }])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -305,6 +327,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -321,6 +344,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown classification".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -334,6 +358,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -349,6 +374,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -364,6 +390,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "SPDX expression is not valid".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -377,6 +404,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
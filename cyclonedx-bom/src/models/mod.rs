@@ -16,17 +16,25 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+pub mod annotation;
 pub mod attached_text;
 pub mod bom;
 pub mod code;
 pub mod component;
 pub mod composition;
+pub mod data;
 pub mod dependency;
 pub mod external_reference;
+pub mod formulation;
 pub mod hash;
 pub mod license;
+pub mod lifecycle;
 pub mod metadata;
+pub mod modelcard;
 pub mod organization;
 pub mod property;
+pub mod release_notes;
 pub mod service;
+pub mod signature;
 pub mod tool;
+pub mod vulnerability;
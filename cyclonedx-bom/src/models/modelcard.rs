@@ -0,0 +1,340 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::fmt;
+
+use crate::models::data::ComponentData;
+use crate::models::property::Properties;
+use crate::validation::{
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// A model card describes the intended uses of a machine learning model, potential limitations,
+/// biases, ethical considerations, training parameters and performance metrics
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_modelCardType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct ModelCard {
+    pub bom_ref: Option<String>,
+    pub model_parameters: Option<ModelParameters>,
+    pub quantitative_analysis: Option<QuantitativeAnalysis>,
+    pub considerations: Option<Considerations>,
+    pub properties: Option<Properties>,
+}
+
+impl Validate for ModelCard {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(model_parameters) = &self.model_parameters {
+            let context = context.extend_context_with_struct_field("ModelCard", "model_parameters");
+
+            results.push(model_parameters.validate_with_context(context)?);
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("ModelCard", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// Hyper-parameters for construction of the model
+#[derive(Debug, PartialEq, Eq)]
+pub struct ModelParameters {
+    pub approach: Option<ApproachType>,
+    pub task: Option<String>,
+    pub architecture_family: Option<String>,
+    pub model_architecture: Option<String>,
+    pub datasets: Option<Vec<ModelDataset>>,
+    pub inputs: Option<Vec<MLParameter>>,
+    pub outputs: Option<Vec<MLParameter>>,
+}
+
+impl Validate for ModelParameters {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(approach) = &self.approach {
+            let context = context.extend_context_with_struct_field("ModelParameters", "approach");
+
+            results.push(approach.validate_with_context(context)?);
+        }
+
+        if let Some(datasets) = &self.datasets {
+            let context = context.extend_context_with_struct_field("ModelParameters", "datasets");
+
+            for (index, dataset) in datasets.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(dataset.validate_with_context(context)?);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The learning approach used to train the model
+#[derive(Debug, PartialEq, Eq)]
+pub enum ApproachType {
+    Supervised,
+    Unsupervised,
+    ReinforcementLearning,
+    SemiSupervised,
+    SelfSupervised,
+    #[doc(hidden)]
+    UnknownApproachType(String),
+}
+
+impl fmt::Display for ApproachType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let approach = match self {
+            ApproachType::Supervised => "supervised",
+            ApproachType::Unsupervised => "unsupervised",
+            ApproachType::ReinforcementLearning => "reinforcement-learning",
+            ApproachType::SemiSupervised => "semi-supervised",
+            ApproachType::SelfSupervised => "self-supervised",
+            ApproachType::UnknownApproachType(approach) => approach,
+        };
+        write!(f, "{}", approach)
+    }
+}
+
+impl ApproachType {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "supervised" => Self::Supervised,
+            "unsupervised" => Self::Unsupervised,
+            "reinforcement-learning" => Self::ReinforcementLearning,
+            "semi-supervised" => Self::SemiSupervised,
+            "self-supervised" => Self::SelfSupervised,
+            unknown => Self::UnknownApproachType(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ApproachType {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ApproachType::UnknownApproachType(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Unknown approach type".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A dataset used to train and evaluate the model, either described inline or referenced by its
+/// bom-ref
+#[derive(Debug, PartialEq, Eq)]
+pub enum ModelDataset {
+    Inline(Box<ComponentData>),
+    Reference(String),
+}
+
+impl Validate for ModelDataset {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ModelDataset::Inline(data) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Inline".to_string(),
+                }]);
+                data.validate_with_context(context)
+            }
+            ModelDataset::Reference(_) => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// The input or output format of the model
+#[derive(Debug, PartialEq, Eq)]
+pub struct MLParameter {
+    pub format: Option<String>,
+}
+
+/// Quantitative analysis of the performance of the model
+#[derive(Debug, PartialEq, Eq)]
+pub struct QuantitativeAnalysis {
+    pub performance_metrics: Option<Vec<PerformanceMetric>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct PerformanceMetric {
+    pub metric_type: Option<String>,
+    pub value: Option<String>,
+    pub slice: Option<String>,
+    pub confidence_interval: Option<ConfidenceInterval>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConfidenceInterval {
+    pub lower_bound: Option<String>,
+    pub upper_bound: Option<String>,
+}
+
+/// What considerations should be taken into account regarding the model's construction, training,
+/// and application
+#[derive(Debug, PartialEq, Eq)]
+pub struct Considerations {
+    pub users: Option<Vec<String>>,
+    pub use_cases: Option<Vec<String>>,
+    pub technical_limitations: Option<Vec<String>>,
+    pub performance_tradeoffs: Option<Vec<String>>,
+    pub ethical_considerations: Option<Vec<EthicalConsideration>>,
+    pub fairness_assessments: Option<Vec<FairnessAssessment>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct EthicalConsideration {
+    pub name: Option<String>,
+    pub mitigation_strategy: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FairnessAssessment {
+    pub group_at_risk: Option<String>,
+    pub benefits: Option<String>,
+    pub harms: Option<String>,
+    pub mitigation_strategy: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::models::data::ComponentDataType;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = ModelCard {
+            bom_ref: Some("model-card-1".to_string()),
+            model_parameters: Some(ModelParameters {
+                approach: Some(ApproachType::Supervised),
+                task: Some("classification".to_string()),
+                architecture_family: None,
+                model_architecture: None,
+                datasets: Some(vec![ModelDataset::Reference("dataset-1".to_string())]),
+                inputs: Some(vec![MLParameter {
+                    format: Some("image".to_string()),
+                }]),
+                outputs: None,
+            }),
+            quantitative_analysis: None,
+            considerations: None,
+            properties: None,
+        }
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = ModelCard {
+            bom_ref: None,
+            model_parameters: Some(ModelParameters {
+                approach: Some(ApproachType::UnknownApproachType("unknown".to_string())),
+                task: None,
+                architecture_family: None,
+                model_architecture: None,
+                datasets: Some(vec![ModelDataset::Inline(Box::new(ComponentData::new(
+                    ComponentDataType::UnknownComponentDataType("unknown".to_string()),
+                    None,
+                )))]),
+                inputs: None,
+                outputs: None,
+            }),
+            quantitative_analysis: None,
+            considerations: None,
+            properties: None,
+        }
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason {
+                        severity: Severity::Error,
+                        message: "Unknown approach type".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "ModelCard".to_string(),
+                                field_name: "model_parameters".to_string()
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "ModelParameters".to_string(),
+                                field_name: "approach".to_string()
+                            },
+                        ])
+                    },
+                    FailureReason {
+                        severity: Severity::Error,
+                        message: "Unknown component data type".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "ModelCard".to_string(),
+                                field_name: "model_parameters".to_string()
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "ModelParameters".to_string(),
+                                field_name: "datasets".to_string()
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::EnumVariant {
+                                variant_name: "Inline".to_string()
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "ComponentData".to_string(),
+                                field_name: "data_type".to_string()
+                            },
+                        ])
+                    },
+                ]
+            }
+        );
+    }
+}
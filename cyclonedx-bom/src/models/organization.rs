@@ -141,7 +141,7 @@ impl Validate for OrganizationalEntity {
 
 #[cfg(test)]
 mod test {
-    use crate::validation::{FailureReason, ValidationPathComponent};
+    use crate::validation::{FailureReason, Severity, ValidationPathComponent};
 
     use super::*;
     use pretty_assertions::assert_eq;
@@ -173,6 +173,7 @@ mod test {
             actual,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
                     context: ValidationContext(vec![ValidationPathComponent::Struct {
@@ -203,6 +204,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -212,6 +214,7 @@ mod test {
                         }])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -221,6 +224,7 @@ mod test {
                         }])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -248,6 +252,7 @@ mod test {
             actual,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
                     context: ValidationContext(vec![ValidationPathComponent::Struct {
@@ -278,6 +283,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -287,6 +293,7 @@ mod test {
                         }])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Uri does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Struct {
@@ -297,6 +304,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
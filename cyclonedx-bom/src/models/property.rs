@@ -31,6 +31,14 @@ use crate::{
 #[derive(Debug, PartialEq, Eq)]
 pub struct Properties(pub Vec<Property>);
 
+impl Properties {
+    /// Sort the properties by name and value
+    pub fn sort(&mut self) {
+        self.0
+            .sort_by(|left, right| (&left.name, &left.value.0).cmp(&(&right.name, &right.value.0)));
+    }
+}
+
 impl Validate for Properties {
     fn validate_with_context(
         &self,
@@ -94,7 +102,7 @@ impl Validate for Property {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::validation::FailureReason;
+    use crate::validation::{FailureReason, Severity};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -122,6 +130,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
                     context: ValidationContext(vec![
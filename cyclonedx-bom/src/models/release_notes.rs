@@ -0,0 +1,325 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri};
+use crate::models::attached_text::AttachedText;
+use crate::models::code::Issue;
+use crate::models::property::Properties;
+use crate::validation::{
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// Represents the release notes of a component or service
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_releaseNotesType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct ReleaseNotes {
+    pub release_type: NormalizedString,
+    pub title: Option<NormalizedString>,
+    pub featured_image: Option<Uri>,
+    pub social_image: Option<Uri>,
+    pub description: Option<String>,
+    pub timestamp: Option<DateTime>,
+    pub aliases: Option<Vec<NormalizedString>>,
+    pub tags: Option<Vec<NormalizedString>>,
+    pub resolves: Option<Vec<Issue>>,
+    pub notes: Option<Vec<Note>>,
+    pub properties: Option<Properties>,
+}
+
+impl ReleaseNotes {
+    /// Construct `ReleaseNotes` with a release type, such as `major`, `minor`, `patch`, `pre-release` or `internal`
+    /// ```
+    /// use cyclonedx_bom::models::release_notes::ReleaseNotes;
+    ///
+    /// let release_notes = ReleaseNotes::new("major");
+    /// ```
+    pub fn new(release_type: &str) -> Self {
+        Self {
+            release_type: NormalizedString::new(release_type),
+            title: None,
+            featured_image: None,
+            social_image: None,
+            description: None,
+            timestamp: None,
+            aliases: None,
+            tags: None,
+            resolves: None,
+            notes: None,
+            properties: None,
+        }
+    }
+}
+
+impl Validate for ReleaseNotes {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let release_type_context =
+            context.extend_context_with_struct_field("ReleaseNotes", "release_type");
+
+        results.push(
+            self.release_type
+                .validate_with_context(release_type_context)?,
+        );
+
+        if let Some(title) = &self.title {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "title");
+
+            results.push(title.validate_with_context(context)?);
+        }
+
+        if let Some(featured_image) = &self.featured_image {
+            let context =
+                context.extend_context_with_struct_field("ReleaseNotes", "featured_image");
+
+            results.push(featured_image.validate_with_context(context)?);
+        }
+
+        if let Some(social_image) = &self.social_image {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "social_image");
+
+            results.push(social_image.validate_with_context(context)?);
+        }
+
+        if let Some(timestamp) = &self.timestamp {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "timestamp");
+
+            results.push(timestamp.validate_with_context(context)?);
+        }
+
+        if let Some(aliases) = &self.aliases {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "aliases");
+
+            for (index, alias) in aliases.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(alias.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(tags) = &self.tags {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "tags");
+
+            for (index, tag) in tags.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(tag.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(resolves) = &self.resolves {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "resolves");
+
+            for (index, issue) in resolves.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(issue.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(notes) = &self.notes {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "notes");
+
+            for (index, note) in notes.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(note.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("ReleaseNotes", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A note containing the locale and content of a release
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_releaseNotesType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Note {
+    pub locale: Option<Locale>,
+    pub text: AttachedText,
+}
+
+impl Validate for Note {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(locale) = &self.locale {
+            let context = context.extend_context_with_struct_field("Note", "locale");
+
+            results.push(locale.validate_with_context(context)?);
+        }
+
+        let text_context = context.extend_context_with_struct_field("Note", "text");
+
+        results.push(self.text.validate_with_context(text_context)?);
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// An ISO-639 language code, optionally followed by an ISO-3166 country code, e.g. `en-US`
+#[derive(Debug, PartialEq, Eq)]
+pub struct Locale(pub(crate) String);
+
+impl Locale {
+    pub fn new(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl Validate for Locale {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        static LOCALE_REGEX: Lazy<Result<Regex, regex::Error>> =
+            Lazy::new(|| Regex::new(r"^([a-z]{2})(-[A-Z]{2})?$"));
+
+        match LOCALE_REGEX.as_ref() {
+            Ok(regex) => {
+                if regex.is_match(&self.0) {
+                    Ok(ValidationResult::Passed)
+                } else {
+                    Ok(ValidationResult::Failed {
+                        reasons: vec![FailureReason {
+                            severity: Severity::Error,
+                            message: "Locale does not match regular expression".to_string(),
+                            context,
+                        }],
+                    })
+                }
+            }
+            Err(e) => Err(e.clone().into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = ReleaseNotes {
+            release_type: NormalizedString::new("major"),
+            title: Some(NormalizedString::new("Title")),
+            featured_image: Some(Uri("https://example.com/featured.png".to_string())),
+            social_image: None,
+            description: Some("Description".to_string()),
+            timestamp: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
+            aliases: Some(vec![NormalizedString::new("Release")]),
+            tags: Some(vec![NormalizedString::new("tag")]),
+            resolves: None,
+            notes: Some(vec![Note {
+                locale: Some(Locale::new("en-US")),
+                text: AttachedText::new(None, "note"),
+            }]),
+            properties: None,
+        }
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = ReleaseNotes {
+            release_type: NormalizedString("spaces and\ttabs".to_string()),
+            title: None,
+            featured_image: None,
+            social_image: None,
+            description: None,
+            timestamp: Some(DateTime("invalid date".to_string())),
+            aliases: None,
+            tags: None,
+            resolves: None,
+            notes: Some(vec![Note {
+                locale: Some(Locale::new("english")),
+                text: AttachedText::new(None, "note"),
+            }]),
+            properties: None,
+        }
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason {
+                        severity: Severity::Error,
+                        message:
+                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                                .to_string(),
+                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                            struct_name: "ReleaseNotes".to_string(),
+                            field_name: "release_type".to_string(),
+                        }])
+                    },
+                    FailureReason {
+                        severity: Severity::Error,
+                        message: "DateTime does not conform to ISO 8601".to_string(),
+                        context: ValidationContext(vec![ValidationPathComponent::Struct {
+                            struct_name: "ReleaseNotes".to_string(),
+                            field_name: "timestamp".to_string(),
+                        }])
+                    },
+                    FailureReason {
+                        severity: Severity::Error,
+                        message: "Locale does not match regular expression".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "ReleaseNotes".to_string(),
+                                field_name: "notes".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Note".to_string(),
+                                field_name: "locale".to_string(),
+                            }
+                        ])
+                    }
+                ]
+            }
+        );
+    }
+}
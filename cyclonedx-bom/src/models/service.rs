@@ -21,8 +21,10 @@ use crate::models::external_reference::ExternalReferences;
 use crate::models::license::Licenses;
 use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
+use crate::models::release_notes::ReleaseNotes;
+use crate::models::signature::Signature;
 use crate::validation::{
-    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationPathComponent,
     ValidationResult,
 };
 
@@ -40,11 +42,14 @@ pub struct Service {
     pub endpoints: Option<Vec<Uri>>,
     pub authenticated: Option<bool>,
     pub x_trust_boundary: Option<bool>,
+    pub trust_zone: Option<NormalizedString>,
     pub data: Option<Vec<DataClassification>>,
     pub licenses: Option<Licenses>,
     pub external_references: Option<ExternalReferences>,
     pub properties: Option<Properties>,
     pub services: Option<Services>,
+    pub release_notes: Option<ReleaseNotes>,
+    pub signature: Option<Signature>,
 }
 
 impl Service {
@@ -65,11 +70,14 @@ impl Service {
             endpoints: None,
             authenticated: None,
             x_trust_boundary: None,
+            trust_zone: None,
             data: None,
             licenses: None,
             external_references: None,
             properties: None,
             services: None,
+            release_notes: None,
+            signature: None,
         }
     }
 }
@@ -122,6 +130,12 @@ impl Validate for Service {
             }
         }
 
+        if let Some(trust_zone) = &self.trust_zone {
+            let context = context.extend_context_with_struct_field("Service", "trust_zone");
+
+            results.push(trust_zone.validate_with_context(context)?);
+        }
+
         if let Some(data) = &self.data {
             for (index, classification) in data.iter().enumerate() {
                 let context = context.extend_context(vec![
@@ -160,6 +174,18 @@ impl Validate for Service {
             results.push(services.validate_with_context(context)?);
         }
 
+        if let Some(release_notes) = &self.release_notes {
+            let context = context.extend_context_with_struct_field("Service", "release_notes");
+
+            results.push(release_notes.validate_with_context(context)?);
+        }
+
+        if let Some(signature) = &self.signature {
+            let context = context.extend_context_with_struct_field("Service", "signature");
+
+            results.push(signature.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
@@ -267,6 +293,7 @@ impl Validate for DataFlowType {
         match self {
             DataFlowType::UnknownDataFlow(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "Unknown data flow type".to_string(),
                     context,
                 }],
@@ -306,6 +333,7 @@ mod test {
             endpoints: Some(vec![Uri("https://example.com".to_string())]),
             authenticated: Some(true),
             x_trust_boundary: Some(true),
+            trust_zone: Some(NormalizedString::new("trust zone")),
             data: Some(vec![DataClassification {
                 flow: DataFlowType::Inbound,
                 classification: NormalizedString::new("classification"),
@@ -324,6 +352,8 @@ mod test {
                 value: NormalizedString::new("value"),
             }])),
             services: Some(Services(vec![])),
+            release_notes: None,
+            signature: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -347,6 +377,7 @@ mod test {
             endpoints: Some(vec![Uri("invalid url".to_string())]),
             authenticated: Some(true),
             x_trust_boundary: Some(true),
+            trust_zone: Some(NormalizedString("invalid\ttrust zone".to_string())),
             data: Some(vec![DataClassification {
                 flow: DataFlowType::UnknownDataFlow("unknown".to_string()),
                 classification: NormalizedString("invalid\tclassification".to_string()),
@@ -376,12 +407,17 @@ mod test {
                 endpoints: None,
                 authenticated: None,
                 x_trust_boundary: None,
+                trust_zone: None,
                 data: None,
                 licenses: None,
                 external_references: None,
                 properties: None,
                 services: None,
+                release_notes: None,
+                signature: None,
             }])),
+            release_notes: None,
+            signature: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -391,6 +427,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -407,6 +444,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -419,6 +457,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -431,6 +470,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -443,6 +483,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -455,6 +496,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Uri does not conform to ISO 8601".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -466,6 +508,20 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
+                        message:
+                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                                .to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Service".to_string(),
+                                field_name: "trust_zone".to_string()
+                            },
+                        ])
+                    },
+                    FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown data flow type".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -481,6 +537,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -498,6 +555,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "SPDX expression is not valid".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -512,6 +570,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message: "Unknown external reference type".to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
@@ -527,6 +586,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -544,6 +604,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
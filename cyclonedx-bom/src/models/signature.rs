@@ -0,0 +1,184 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::fmt;
+
+use crate::validation::{
+    FailureReason, Severity, Validate, ValidationContext, ValidationError, ValidationResult,
+};
+
+/// Enveloped signature in [JSON Signature Format (JSF)](https://cyberphone.github.io/doc/security/jsf.html)
+///
+/// Defined via the [CycloneDX JSON schema](https://cyclonedx.org/docs/1.4/json/#signature)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub algorithm: Algorithm,
+    pub value: String,
+}
+
+impl Signature {
+    /// Construct a `Signature` from the algorithm and the base64url encoded signature value
+    /// ```
+    /// use cyclonedx_bom::models::signature::{Algorithm, Signature};
+    ///
+    /// let signature = Signature::new(Algorithm::ES256, "tqIVq7Wf_pS8d7eJWbxOdJFLVGD4PeGz8vq_BuQ8CCM");
+    /// ```
+    pub fn new(algorithm: Algorithm, value: &str) -> Self {
+        Self {
+            algorithm,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Validate for Signature {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let algorithm_context = context.extend_context_with_struct_field("Signature", "algorithm");
+
+        self.algorithm.validate_with_context(algorithm_context)
+    }
+}
+
+/// Algorithm used to create a JSF signature
+///
+/// Defined via the [CycloneDX JSON schema](https://cyclonedx.org/docs/1.4/json/#signature_algorithm)
+#[derive(Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    RS256,
+    RS384,
+    RS512,
+    PS256,
+    PS384,
+    PS512,
+    ES256,
+    ES384,
+    ES512,
+    Ed25519,
+    Ed448,
+    HS256,
+    HS384,
+    HS512,
+    #[doc(hidden)]
+    UnknownAlgorithm(String),
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let algorithm = match self {
+            Algorithm::RS256 => "RS256",
+            Algorithm::RS384 => "RS384",
+            Algorithm::RS512 => "RS512",
+            Algorithm::PS256 => "PS256",
+            Algorithm::PS384 => "PS384",
+            Algorithm::PS512 => "PS512",
+            Algorithm::ES256 => "ES256",
+            Algorithm::ES384 => "ES384",
+            Algorithm::ES512 => "ES512",
+            Algorithm::Ed25519 => "Ed25519",
+            Algorithm::Ed448 => "Ed448",
+            Algorithm::HS256 => "HS256",
+            Algorithm::HS384 => "HS384",
+            Algorithm::HS512 => "HS512",
+            Algorithm::UnknownAlgorithm(un) => un,
+        };
+        write!(f, "{}", algorithm)
+    }
+}
+
+impl Algorithm {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "RS256" => Self::RS256,
+            "RS384" => Self::RS384,
+            "RS512" => Self::RS512,
+            "PS256" => Self::PS256,
+            "PS384" => Self::PS384,
+            "PS512" => Self::PS512,
+            "ES256" => Self::ES256,
+            "ES384" => Self::ES384,
+            "ES512" => Self::ES512,
+            "Ed25519" => Self::Ed25519,
+            "Ed448" => Self::Ed448,
+            "HS256" => Self::HS256,
+            "HS384" => Self::HS384,
+            "HS512" => Self::HS512,
+            unknown => Self::UnknownAlgorithm(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for Algorithm {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Algorithm::UnknownAlgorithm(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Unknown signature algorithm".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::ValidationPathComponent;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = Signature::new(Algorithm::Ed25519, "signature value")
+            .validate()
+            .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_fail_validation_for_unknown_algorithms() {
+        let validation_result = Signature {
+            algorithm: Algorithm::new_unchecked("unknown"),
+            value: "signature value".to_string(),
+        }
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Unknown signature algorithm".to_string(),
+                    context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        struct_name: "Signature".to_string(),
+                        field_name: "algorithm".to_string(),
+                    }])
+                }]
+            }
+        );
+    }
+}
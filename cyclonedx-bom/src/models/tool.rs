@@ -17,7 +17,11 @@
  */
 
 use crate::external_models::normalized_string::NormalizedString;
+use crate::models::component::{Classification, Component, Components};
+use crate::models::external_reference::ExternalReferences;
 use crate::models::hash::Hashes;
+use crate::models::organization::OrganizationalEntity;
+use crate::models::service::{Service, Services};
 use crate::validation::{
     Validate, ValidationContext, ValidationError, ValidationPathComponent, ValidationResult,
 };
@@ -31,6 +35,7 @@ pub struct Tool {
     pub name: Option<NormalizedString>,
     pub version: Option<NormalizedString>,
     pub hashes: Option<Hashes>,
+    pub external_references: Option<ExternalReferences>,
 }
 
 impl Tool {
@@ -46,6 +51,7 @@ impl Tool {
             name: Some(NormalizedString::new(name)),
             version: Some(NormalizedString::new(version)),
             hashes: None,
+            external_references: None,
         }
     }
 }
@@ -81,14 +87,98 @@ impl Validate for Tool {
             results.push(hashes.validate_with_context(context)?);
         }
 
+        if let Some(external_references) = &self.external_references {
+            let context = context.extend_context_with_struct_field("Tool", "external_references");
+
+            results.push(external_references.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
     }
 }
 
+/// The tools used to create the BOM
+///
+/// Version 1.5 of the specification describes tools as components and services, and deprecates
+/// the plain list of tools used by the earlier versions.
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_metadata)
 #[derive(Debug, PartialEq, Eq)]
-pub struct Tools(pub Vec<Tool>);
+pub enum Tools {
+    /// The deprecated list of tools
+    List(Vec<Tool>),
+    /// Tools described as components and services, only supported by version 1.5
+    Object {
+        components: Option<Components>,
+        services: Option<Services>,
+    },
+}
+
+impl Tools {
+    /// The tools as the list form that every version of the specification supports
+    ///
+    /// Components and services keep their name, version, hashes and external references, the
+    /// supplier or provider becomes the vendor, and everything else is left out.
+    pub(crate) fn into_list(self) -> Vec<Tool> {
+        match self {
+            Tools::List(tools) => tools,
+            Tools::Object {
+                components,
+                services,
+            } => {
+                let components = components.map(|c| c.0).unwrap_or_default();
+                let services = services.map(|s| s.0).unwrap_or_default();
+                components
+                    .into_iter()
+                    .map(Tool::from)
+                    .chain(services.into_iter().map(Tool::from))
+                    .collect()
+            }
+        }
+    }
+}
+
+impl From<Component> for Tool {
+    fn from(component: Component) -> Self {
+        Self {
+            vendor: component.supplier.and_then(|supplier| supplier.name),
+            name: Some(component.name),
+            version: Some(component.version),
+            hashes: component.hashes,
+            external_references: component.external_references,
+        }
+    }
+}
+
+impl From<Service> for Tool {
+    fn from(service: Service) -> Self {
+        Self {
+            vendor: service.provider.and_then(|provider| provider.name),
+            name: Some(service.name),
+            version: service.version,
+            hashes: None,
+            external_references: service.external_references,
+        }
+    }
+}
+
+impl From<Tool> for Component {
+    fn from(tool: Tool) -> Self {
+        let mut component = Component::new(Classification::Application, "", "", None);
+        component.name = tool.name.unwrap_or_default();
+        component.version = tool.version.unwrap_or_default();
+        component.supplier = tool.vendor.map(|vendor| OrganizationalEntity {
+            name: Some(vendor),
+            url: None,
+            contact: None,
+        });
+        component.hashes = tool.hashes;
+        component.external_references = tool.external_references;
+        component
+    }
+}
 
 impl Validate for Tools {
     fn validate_with_context(
@@ -97,10 +187,30 @@ impl Validate for Tools {
     ) -> Result<ValidationResult, ValidationError> {
         let mut results: Vec<ValidationResult> = vec![];
 
-        for (index, tool) in self.0.iter().enumerate() {
-            let tool_context =
-                context.extend_context(vec![ValidationPathComponent::Array { index }]);
-            results.push(tool.validate_with_context(tool_context)?);
+        match self {
+            Tools::List(tools) => {
+                for (index, tool) in tools.iter().enumerate() {
+                    let tool_context =
+                        context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                    results.push(tool.validate_with_context(tool_context)?);
+                }
+            }
+            Tools::Object {
+                components,
+                services,
+            } => {
+                if let Some(components) = components {
+                    let context = context.extend_context_with_struct_field("Tools", "components");
+
+                    results.push(components.validate_with_context(context)?);
+                }
+
+                if let Some(services) = services {
+                    let context = context.extend_context_with_struct_field("Tools", "services");
+
+                    results.push(services.validate_with_context(context)?);
+                }
+            }
         }
 
         Ok(results
@@ -111,18 +221,19 @@ impl Validate for Tools {
 
 #[cfg(test)]
 mod test {
-    use crate::validation::FailureReason;
+    use crate::validation::{FailureReason, Severity};
 
     use super::*;
     use pretty_assertions::assert_eq;
 
     #[test]
     fn it_should_pass_validation() {
-        let validation_result = Tools(vec![Tool {
+        let validation_result = Tools::List(vec![Tool {
             vendor: Some(NormalizedString("no_whitespace".to_string())),
             name: None,
             version: None,
             hashes: None,
+            external_references: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -132,11 +243,12 @@ mod test {
 
     #[test]
     fn it_should_fail_validation() {
-        let validation_result = Tools(vec![Tool {
+        let validation_result = Tools::List(vec![Tool {
             vendor: Some(NormalizedString("spaces and\ttabs".to_string())),
             name: None,
             version: None,
             hashes: None,
+            external_references: None,
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -145,6 +257,7 @@ mod test {
             validation_result,
             ValidationResult::Failed {
                 reasons: vec![FailureReason {
+                    severity: Severity::Error,
                     message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                         .to_string(),
                     context: ValidationContext(vec![
@@ -161,24 +274,27 @@ mod test {
 
     #[test]
     fn it_should_merge_validations_correctly() {
-        let validation_result = Tools(vec![
+        let validation_result = Tools::List(vec![
             Tool {
                 vendor: Some(NormalizedString("no_whitespace".to_string())),
                 name: None,
                 version: None,
                 hashes: None,
+                external_references: None,
             },
             Tool {
                 vendor: Some(NormalizedString("spaces and\ttabs".to_string())),
                 name: None,
                 version: None,
                 hashes: None,
+                external_references: None,
             },
             Tool {
                 vendor: None,
                 name: Some(NormalizedString("spaces and\ttabs".to_string())),
                 version: None,
                 hashes: None,
+                external_references: None,
             },
         ])
         .validate_with_context(ValidationContext::default())
@@ -189,6 +305,7 @@ mod test {
             ValidationResult::Failed {
                 reasons: vec![
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -201,6 +318,7 @@ mod test {
                         ])
                     },
                     FailureReason {
+                        severity: Severity::Error,
                         message:
                             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
                                 .to_string(),
@@ -216,4 +334,63 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn it_should_validate_components_and_services() {
+        let mut service = Service::new("service", None);
+        service.name = NormalizedString("spaces and\ttabs".to_string());
+        let validation_result = Tools::Object {
+            components: None,
+            services: Some(Services(vec![service])),
+        }
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                        .to_string(),
+                    context: ValidationContext(vec![
+                        ValidationPathComponent::Struct {
+                            struct_name: "Tools".to_string(),
+                            field_name: "services".to_string(),
+                        },
+                        ValidationPathComponent::Array { index: 0 },
+                        ValidationPathComponent::Struct {
+                            struct_name: "Service".to_string(),
+                            field_name: "name".to_string(),
+                        }
+                    ])
+                }]
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_convert_components_and_services_into_tools() {
+        let mut component = Component::new(Classification::Application, "tool", "1.0.0", None);
+        component.supplier = Some(OrganizationalEntity {
+            name: Some(NormalizedString::new("vendor")),
+            url: None,
+            contact: None,
+        });
+        let tools = Tools::Object {
+            components: Some(Components(vec![component])),
+            services: Some(Services(vec![Service::new("service", None)])),
+        };
+
+        assert_eq!(
+            tools.into_list(),
+            vec![
+                Tool::new("vendor", "tool", "1.0.0"),
+                Tool {
+                    name: Some(NormalizedString::new("service")),
+                    ..Tool::default()
+                },
+            ]
+        );
+    }
 }
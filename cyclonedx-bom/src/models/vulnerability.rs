@@ -0,0 +1,1332 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::fmt;
+
+use crate::external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri};
+use crate::models::attached_text::AttachedText;
+use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
+use crate::models::property::Properties;
+use crate::models::tool::Tools;
+use crate::validation::{
+    FailureReason, Severity as ValidationSeverity, Validate, ValidationContext, ValidationError,
+    ValidationPathComponent, ValidationResult,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Vulnerabilities(pub Vec<Vulnerability>);
+
+impl Validate for Vulnerabilities {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, vulnerability) in self.0.iter().enumerate() {
+            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(vulnerability.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// Represents a vulnerability that affects one or more components or services of the BOM
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Vulnerability {
+    pub bom_ref: Option<String>,
+    pub id: Option<NormalizedString>,
+    pub source: Option<VulnerabilitySource>,
+    pub references: Option<Vec<VulnerabilityReference>>,
+    pub ratings: Option<Vec<VulnerabilityRating>>,
+    pub cwes: Option<Vec<u32>>,
+    pub description: Option<String>,
+    pub detail: Option<String>,
+    pub recommendation: Option<String>,
+    pub workaround: Option<String>,
+    pub proof_of_concept: Option<ProofOfConcept>,
+    pub advisories: Option<Vec<Advisory>>,
+    pub created: Option<DateTime>,
+    pub published: Option<DateTime>,
+    pub updated: Option<DateTime>,
+    pub rejected: Option<DateTime>,
+    pub credits: Option<VulnerabilityCredits>,
+    pub tools: Option<Tools>,
+    pub analysis: Option<VulnerabilityAnalysis>,
+    pub affects: Option<Vec<VulnerabilityTarget>>,
+    pub properties: Option<Properties>,
+}
+
+impl Vulnerability {
+    /// Construct a `Vulnerability` with an optional BOM reference and its identifier, such as a CVE
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::Vulnerability;
+    ///
+    /// let vulnerability = Vulnerability::new(Some("vulnerability-1".to_string()), "CVE-2021-44228");
+    /// ```
+    pub fn new(bom_ref: Option<String>, id: &str) -> Self {
+        Self {
+            bom_ref,
+            id: Some(NormalizedString::new(id)),
+            source: None,
+            references: None,
+            ratings: None,
+            cwes: None,
+            description: None,
+            detail: None,
+            recommendation: None,
+            workaround: None,
+            proof_of_concept: None,
+            advisories: None,
+            created: None,
+            published: None,
+            updated: None,
+            rejected: None,
+            credits: None,
+            tools: None,
+            analysis: None,
+            affects: None,
+            properties: None,
+        }
+    }
+}
+
+impl Validate for Vulnerability {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(id) = &self.id {
+            let context = context.extend_context_with_struct_field("Vulnerability", "id");
+
+            results.push(id.validate_with_context(context)?);
+        }
+
+        if let Some(source) = &self.source {
+            let context = context.extend_context_with_struct_field("Vulnerability", "source");
+
+            results.push(source.validate_with_context(context)?);
+        }
+
+        if let Some(references) = &self.references {
+            let context = context.extend_context_with_struct_field("Vulnerability", "references");
+
+            for (index, reference) in references.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(reference.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(ratings) = &self.ratings {
+            let context = context.extend_context_with_struct_field("Vulnerability", "ratings");
+
+            for (index, rating) in ratings.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(rating.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(proof_of_concept) = &self.proof_of_concept {
+            let context =
+                context.extend_context_with_struct_field("Vulnerability", "proof_of_concept");
+
+            results.push(proof_of_concept.validate_with_context(context)?);
+        }
+
+        if let Some(advisories) = &self.advisories {
+            let context = context.extend_context_with_struct_field("Vulnerability", "advisories");
+
+            for (index, advisory) in advisories.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(advisory.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(created) = &self.created {
+            let context = context.extend_context_with_struct_field("Vulnerability", "created");
+
+            results.push(created.validate_with_context(context)?);
+        }
+
+        if let Some(published) = &self.published {
+            let context = context.extend_context_with_struct_field("Vulnerability", "published");
+
+            results.push(published.validate_with_context(context)?);
+        }
+
+        if let Some(updated) = &self.updated {
+            let context = context.extend_context_with_struct_field("Vulnerability", "updated");
+
+            results.push(updated.validate_with_context(context)?);
+        }
+
+        if let Some(rejected) = &self.rejected {
+            let context = context.extend_context_with_struct_field("Vulnerability", "rejected");
+
+            results.push(rejected.validate_with_context(context)?);
+        }
+
+        if let Some(credits) = &self.credits {
+            let context = context.extend_context_with_struct_field("Vulnerability", "credits");
+
+            results.push(credits.validate_with_context(context)?);
+        }
+
+        if let Some(tools) = &self.tools {
+            let context = context.extend_context_with_struct_field("Vulnerability", "tools");
+
+            results.push(tools.validate_with_context(context)?);
+        }
+
+        if let Some(analysis) = &self.analysis {
+            let context = context.extend_context_with_struct_field("Vulnerability", "analysis");
+
+            results.push(analysis.validate_with_context(context)?);
+        }
+
+        if let Some(affects) = &self.affects {
+            let context = context.extend_context_with_struct_field("Vulnerability", "affects");
+
+            for (index, target) in affects.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(target.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Vulnerability", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// Evidence used to reproduce a vulnerability
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_vulnerabilityType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProofOfConcept {
+    pub reproduction_steps: Option<String>,
+    pub environment: Option<String>,
+    pub supporting_material: Option<Vec<AttachedText>>,
+}
+
+impl Validate for ProofOfConcept {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(supporting_material) = &self.supporting_material {
+            let context =
+                context.extend_context_with_struct_field("ProofOfConcept", "supporting_material");
+
+            for (index, attachment) in supporting_material.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(attachment.validate_with_context(context)?);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The source that published a vulnerability or a rating, such as NVD or GitHub Advisories
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilitySourceType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilitySource {
+    pub name: Option<NormalizedString>,
+    pub url: Option<Uri>,
+}
+
+impl VulnerabilitySource {
+    /// Construct a `VulnerabilitySource` with a name and an optional URL
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::VulnerabilitySource;
+    ///
+    /// let source = VulnerabilitySource::new("NVD", Some("https://nvd.nist.gov/vuln/detail/CVE-2021-44228"));
+    /// ```
+    pub fn new(name: &str, url: Option<&str>) -> Self {
+        Self {
+            name: Some(NormalizedString::new(name)),
+            url: url.map(|url| Uri(url.to_string())),
+        }
+    }
+}
+
+impl Validate for VulnerabilitySource {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(name) = &self.name {
+            let context = context.extend_context_with_struct_field("VulnerabilitySource", "name");
+
+            results.push(name.validate_with_context(context)?);
+        }
+
+        if let Some(url) = &self.url {
+            let context = context.extend_context_with_struct_field("VulnerabilitySource", "url");
+
+            results.push(url.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A reference to the same vulnerability published by another source
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilityReference {
+    pub id: NormalizedString,
+    pub source: VulnerabilitySource,
+}
+
+impl Validate for VulnerabilityReference {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let id_context = context.extend_context_with_struct_field("VulnerabilityReference", "id");
+        let source_context =
+            context.extend_context_with_struct_field("VulnerabilityReference", "source");
+
+        Ok(self
+            .id
+            .validate_with_context(id_context)?
+            .merge(self.source.validate_with_context(source_context)?))
+    }
+}
+
+/// The severity or risk rating of a vulnerability
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_ratingType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilityRating {
+    pub source: Option<VulnerabilitySource>,
+    pub score: Option<Score>,
+    pub severity: Option<Severity>,
+    pub method: Option<ScoreMethod>,
+    pub vector: Option<NormalizedString>,
+    pub justification: Option<String>,
+}
+
+impl VulnerabilityRating {
+    /// Construct a `VulnerabilityRating` from a score, severity and scoring method
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::{Score, ScoreMethod, Severity, VulnerabilityRating};
+    ///
+    /// let rating = VulnerabilityRating::new(Some(Score::from(9.8)), Some(Severity::Critical), Some(ScoreMethod::CVSSv31));
+    /// ```
+    pub fn new(
+        score: Option<Score>,
+        severity: Option<Severity>,
+        method: Option<ScoreMethod>,
+    ) -> Self {
+        Self {
+            source: None,
+            score,
+            severity,
+            method,
+            vector: None,
+            justification: None,
+        }
+    }
+}
+
+impl Validate for VulnerabilityRating {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(source) = &self.source {
+            let context = context.extend_context_with_struct_field("VulnerabilityRating", "source");
+
+            results.push(source.validate_with_context(context)?);
+        }
+
+        if let Some(score) = &self.score {
+            let context = context.extend_context_with_struct_field("VulnerabilityRating", "score");
+
+            results.push(score.validate_with_context(context)?);
+        }
+
+        if let Some(severity) = &self.severity {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityRating", "severity");
+
+            results.push(severity.validate_with_context(context)?);
+        }
+
+        if let Some(method) = &self.method {
+            let context = context.extend_context_with_struct_field("VulnerabilityRating", "method");
+
+            results.push(method.validate_with_context(context)?);
+        }
+
+        if let Some(vector) = &self.vector {
+            let context = context.extend_context_with_struct_field("VulnerabilityRating", "vector");
+
+            results.push(vector.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The numerical score of a rating
+#[derive(Debug, PartialEq)]
+pub struct Score(pub(crate) f32);
+
+// Scores that are not finite fail validation, so equality is total for any valid score
+impl Eq for Score {}
+
+impl Score {
+    pub fn to_f32(&self) -> f32 {
+        self.0
+    }
+}
+
+impl From<f32> for Score {
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Validate for Score {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        if self.0.is_finite() && self.0 >= 0.0 {
+            Ok(ValidationResult::Passed)
+        } else {
+            Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: ValidationSeverity::Error,
+                    message: "Score must be a non-negative number".to_string(),
+                    context,
+                }],
+            })
+        }
+    }
+}
+
+/// Textual representation of the severity of a vulnerability
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_severityType)
+#[derive(Debug, PartialEq, Eq)]
+pub enum Severity {
+    Critical,
+    High,
+    Medium,
+    Low,
+    Info,
+    None,
+    Unknown,
+    #[doc(hidden)]
+    UnknownSeverity(String),
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self {
+            Severity::Critical => "critical",
+            Severity::High => "high",
+            Severity::Medium => "medium",
+            Severity::Low => "low",
+            Severity::Info => "info",
+            Severity::None => "none",
+            Severity::Unknown => "unknown",
+            Severity::UnknownSeverity(un) => un,
+        };
+        write!(f, "{}", severity)
+    }
+}
+
+impl Severity {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "critical" => Self::Critical,
+            "high" => Self::High,
+            "medium" => Self::Medium,
+            "low" => Self::Low,
+            "info" => Self::Info,
+            "none" => Self::None,
+            "unknown" => Self::Unknown,
+            unknown => Self::UnknownSeverity(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for Severity {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Severity::UnknownSeverity(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: ValidationSeverity::Error,
+                    message: "Unknown severity".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// The risk scoring methodology or standard used for a rating
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_scoreSourceType)
+#[derive(Debug, PartialEq, Eq)]
+pub enum ScoreMethod {
+    CVSSv2,
+    CVSSv3,
+    CVSSv31,
+    CVSSv4,
+    OWASP,
+    SSVC,
+    Other,
+    #[doc(hidden)]
+    UnknownScoreMethod(String),
+}
+
+impl fmt::Display for ScoreMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let method = match self {
+            ScoreMethod::CVSSv2 => "CVSSv2",
+            ScoreMethod::CVSSv3 => "CVSSv3",
+            ScoreMethod::CVSSv31 => "CVSSv31",
+            ScoreMethod::CVSSv4 => "CVSSv4",
+            ScoreMethod::OWASP => "OWASP",
+            ScoreMethod::SSVC => "SSVC",
+            ScoreMethod::Other => "other",
+            ScoreMethod::UnknownScoreMethod(un) => un,
+        };
+        write!(f, "{}", method)
+    }
+}
+
+impl ScoreMethod {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "CVSSv2" => Self::CVSSv2,
+            "CVSSv3" => Self::CVSSv3,
+            "CVSSv31" => Self::CVSSv31,
+            "CVSSv4" => Self::CVSSv4,
+            "OWASP" => Self::OWASP,
+            "SSVC" => Self::SSVC,
+            "other" => Self::Other,
+            unknown => Self::UnknownScoreMethod(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ScoreMethod {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ScoreMethod::UnknownScoreMethod(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: ValidationSeverity::Error,
+                    message: "Unknown score method".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// An advisory published about a vulnerability
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_advisoryType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Advisory {
+    pub title: Option<NormalizedString>,
+    pub url: Uri,
+}
+
+impl Validate for Advisory {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(title) = &self.title {
+            let context = context.extend_context_with_struct_field("Advisory", "title");
+
+            results.push(title.validate_with_context(context)?);
+        }
+
+        let url_context = context.extend_context_with_struct_field("Advisory", "url");
+
+        results.push(self.url.validate_with_context(url_context)?);
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The organizations and individuals credited with finding a vulnerability
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilityCredits {
+    pub organizations: Option<Vec<OrganizationalEntity>>,
+    pub individuals: Option<Vec<OrganizationalContact>>,
+}
+
+impl Validate for VulnerabilityCredits {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(organizations) = &self.organizations {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityCredits", "organizations");
+
+            for (index, organization) in organizations.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(organization.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(individuals) = &self.individuals {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityCredits", "individuals");
+
+            for (index, individual) in individuals.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(individual.validate_with_context(context)?);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// The impact analysis of a vulnerability, as performed by the author of the BOM
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilityAnalysis {
+    pub state: Option<ImpactAnalysisState>,
+    pub justification: Option<ImpactAnalysisJustification>,
+    pub responses: Option<Vec<ImpactAnalysisResponse>>,
+    pub detail: Option<String>,
+    pub first_issued: Option<DateTime>,
+    pub last_updated: Option<DateTime>,
+}
+
+impl Validate for VulnerabilityAnalysis {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(state) = &self.state {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityAnalysis", "state");
+
+            results.push(state.validate_with_context(context)?);
+        }
+
+        if let Some(justification) = &self.justification {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityAnalysis", "justification");
+
+            results.push(justification.validate_with_context(context)?);
+        }
+
+        if let Some(responses) = &self.responses {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityAnalysis", "responses");
+
+            for (index, response) in responses.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(response.validate_with_context(context)?);
+            }
+        }
+
+        if let Some(first_issued) = &self.first_issued {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityAnalysis", "first_issued");
+
+            results.push(first_issued.validate_with_context(context)?);
+        }
+
+        if let Some(last_updated) = &self.last_updated {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityAnalysis", "last_updated");
+
+            results.push(last_updated.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// Declares the current state of an occurrence of a vulnerability
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisStateType)
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImpactAnalysisState {
+    Resolved,
+    ResolvedWithPedigree,
+    Exploitable,
+    InTriage,
+    FalsePositive,
+    NotAffected,
+    #[doc(hidden)]
+    UnknownImpactAnalysisState(String),
+}
+
+impl fmt::Display for ImpactAnalysisState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self {
+            ImpactAnalysisState::Resolved => "resolved",
+            ImpactAnalysisState::ResolvedWithPedigree => "resolved_with_pedigree",
+            ImpactAnalysisState::Exploitable => "exploitable",
+            ImpactAnalysisState::InTriage => "in_triage",
+            ImpactAnalysisState::FalsePositive => "false_positive",
+            ImpactAnalysisState::NotAffected => "not_affected",
+            ImpactAnalysisState::UnknownImpactAnalysisState(un) => un,
+        };
+        write!(f, "{}", state)
+    }
+}
+
+impl ImpactAnalysisState {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "resolved" => Self::Resolved,
+            "resolved_with_pedigree" => Self::ResolvedWithPedigree,
+            "exploitable" => Self::Exploitable,
+            "in_triage" => Self::InTriage,
+            "false_positive" => Self::FalsePositive,
+            "not_affected" => Self::NotAffected,
+            unknown => Self::UnknownImpactAnalysisState(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ImpactAnalysisState {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ImpactAnalysisState::UnknownImpactAnalysisState(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: ValidationSeverity::Error,
+                    message: "Unknown impact analysis state".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// The rationale of why the impact analysis state was asserted
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisJustificationType)
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImpactAnalysisJustification {
+    CodeNotPresent,
+    CodeNotReachable,
+    RequiresConfiguration,
+    RequiresDependency,
+    RequiresEnvironment,
+    ProtectedByCompiler,
+    ProtectedAtRuntime,
+    ProtectedAtPerimeter,
+    ProtectedByMitigatingControl,
+    #[doc(hidden)]
+    UnknownImpactAnalysisJustification(String),
+}
+
+impl fmt::Display for ImpactAnalysisJustification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let justification = match self {
+            ImpactAnalysisJustification::CodeNotPresent => "code_not_present",
+            ImpactAnalysisJustification::CodeNotReachable => "code_not_reachable",
+            ImpactAnalysisJustification::RequiresConfiguration => "requires_configuration",
+            ImpactAnalysisJustification::RequiresDependency => "requires_dependency",
+            ImpactAnalysisJustification::RequiresEnvironment => "requires_environment",
+            ImpactAnalysisJustification::ProtectedByCompiler => "protected_by_compiler",
+            ImpactAnalysisJustification::ProtectedAtRuntime => "protected_at_runtime",
+            ImpactAnalysisJustification::ProtectedAtPerimeter => "protected_at_perimeter",
+            ImpactAnalysisJustification::ProtectedByMitigatingControl => {
+                "protected_by_mitigating_control"
+            }
+            ImpactAnalysisJustification::UnknownImpactAnalysisJustification(un) => un,
+        };
+        write!(f, "{}", justification)
+    }
+}
+
+impl ImpactAnalysisJustification {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "code_not_present" => Self::CodeNotPresent,
+            "code_not_reachable" => Self::CodeNotReachable,
+            "requires_configuration" => Self::RequiresConfiguration,
+            "requires_dependency" => Self::RequiresDependency,
+            "requires_environment" => Self::RequiresEnvironment,
+            "protected_by_compiler" => Self::ProtectedByCompiler,
+            "protected_at_runtime" => Self::ProtectedAtRuntime,
+            "protected_at_perimeter" => Self::ProtectedAtPerimeter,
+            "protected_by_mitigating_control" => Self::ProtectedByMitigatingControl,
+            unknown => Self::UnknownImpactAnalysisJustification(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ImpactAnalysisJustification {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ImpactAnalysisJustification::UnknownImpactAnalysisJustification(_) => {
+                Ok(ValidationResult::Failed {
+                    reasons: vec![FailureReason {
+                        severity: ValidationSeverity::Error,
+                        message: "Unknown impact analysis justification".to_string(),
+                        context,
+                    }],
+                })
+            }
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A response to a vulnerability by the manufacturer, supplier, or project responsible for the affected component or service
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImpactAnalysisResponse {
+    CanNotFix,
+    WillNotFix,
+    Update,
+    Rollback,
+    WorkaroundAvailable,
+    #[doc(hidden)]
+    UnknownImpactAnalysisResponse(String),
+}
+
+impl fmt::Display for ImpactAnalysisResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let response = match self {
+            ImpactAnalysisResponse::CanNotFix => "can_not_fix",
+            ImpactAnalysisResponse::WillNotFix => "will_not_fix",
+            ImpactAnalysisResponse::Update => "update",
+            ImpactAnalysisResponse::Rollback => "rollback",
+            ImpactAnalysisResponse::WorkaroundAvailable => "workaround_available",
+            ImpactAnalysisResponse::UnknownImpactAnalysisResponse(un) => un,
+        };
+        write!(f, "{}", response)
+    }
+}
+
+impl ImpactAnalysisResponse {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "can_not_fix" => Self::CanNotFix,
+            "will_not_fix" => Self::WillNotFix,
+            "update" => Self::Update,
+            "rollback" => Self::Rollback,
+            "workaround_available" => Self::WorkaroundAvailable,
+            unknown => Self::UnknownImpactAnalysisResponse(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for ImpactAnalysisResponse {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            ImpactAnalysisResponse::UnknownImpactAnalysisResponse(_) => {
+                Ok(ValidationResult::Failed {
+                    reasons: vec![FailureReason {
+                        severity: ValidationSeverity::Error,
+                        message: "Unknown impact analysis response".to_string(),
+                        context,
+                    }],
+                })
+            }
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// A component or service affected by a vulnerability, identified by its BOM reference
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
+#[derive(Debug, PartialEq, Eq)]
+pub struct VulnerabilityTarget {
+    pub bom_ref: String,
+    pub versions: Option<Vec<AffectedVersion>>,
+}
+
+impl VulnerabilityTarget {
+    /// Construct a `VulnerabilityTarget` that refers to the `bom-ref` of a component or service
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::VulnerabilityTarget;
+    ///
+    /// let target = VulnerabilityTarget::new("pkg:cargo/log4rs@1.2.0");
+    /// ```
+    pub fn new(bom_ref: &str) -> Self {
+        Self {
+            bom_ref: bom_ref.to_string(),
+            versions: None,
+        }
+    }
+}
+
+impl Validate for VulnerabilityTarget {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(versions) = &self.versions {
+            let context =
+                context.extend_context_with_struct_field("VulnerabilityTarget", "versions");
+
+            for (index, version) in versions.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                results.push(version.validate_with_context(context)?);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A single version or a range of versions of a target, along with whether it is affected
+#[derive(Debug, PartialEq, Eq)]
+pub struct AffectedVersion {
+    pub version_range: VersionRange,
+    pub status: Option<AffectedStatus>,
+}
+
+impl Validate for AffectedVersion {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let version_range_context =
+            context.extend_context_with_struct_field("AffectedVersion", "version_range");
+
+        results.push(
+            self.version_range
+                .validate_with_context(version_range_context)?,
+        );
+
+        if let Some(status) = &self.status {
+            let context = context.extend_context_with_struct_field("AffectedVersion", "status");
+
+            results.push(status.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// Either a single version, or a version range in [vers](https://github.com/package-url/purl-spec/blob/master/VERSION-RANGE-SPEC.rst) syntax
+#[derive(Debug, PartialEq, Eq)]
+pub enum VersionRange {
+    Version(NormalizedString),
+    Range(NormalizedString),
+}
+
+impl Validate for VersionRange {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            VersionRange::Version(version) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Version".to_string(),
+                }]);
+                version.validate_with_context(context)
+            }
+            VersionRange::Range(range) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Range".to_string(),
+                }]);
+                range.validate_with_context(context)
+            }
+        }
+    }
+}
+
+/// Whether a version of a target is affected by the vulnerability
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisAffectedStatusType)
+#[derive(Debug, PartialEq, Eq)]
+pub enum AffectedStatus {
+    Affected,
+    Unaffected,
+    Unknown,
+    #[doc(hidden)]
+    UnknownAffectedStatus(String),
+}
+
+impl fmt::Display for AffectedStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            AffectedStatus::Affected => "affected",
+            AffectedStatus::Unaffected => "unaffected",
+            AffectedStatus::Unknown => "unknown",
+            AffectedStatus::UnknownAffectedStatus(un) => un,
+        };
+        write!(f, "{}", status)
+    }
+}
+
+impl AffectedStatus {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "affected" => Self::Affected,
+            "unaffected" => Self::Unaffected,
+            "unknown" => Self::Unknown,
+            unknown => Self::UnknownAffectedStatus(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for AffectedStatus {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            AffectedStatus::UnknownAffectedStatus(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: ValidationSeverity::Error,
+                    message: "Unknown affected status".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::models::tool::Tool;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn valid_vulnerability() -> Vulnerability {
+        Vulnerability {
+            bom_ref: Some("vulnerability-1".to_string()),
+            id: Some(NormalizedString::new("CVE-2021-44228")),
+            source: Some(VulnerabilitySource::new(
+                "NVD",
+                Some("https://nvd.nist.gov/vuln/detail/CVE-2021-44228"),
+            )),
+            references: Some(vec![VulnerabilityReference {
+                id: NormalizedString::new("GHSA-jfh8-c2jp-5v3q"),
+                source: VulnerabilitySource::new("GitHub Advisories", None),
+            }]),
+            ratings: Some(vec![VulnerabilityRating {
+                source: None,
+                score: Some(Score::from(10.0)),
+                severity: Some(Severity::Critical),
+                method: Some(ScoreMethod::CVSSv31),
+                vector: Some(NormalizedString::new("AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H")),
+                justification: None,
+            }]),
+            cwes: Some(vec![20, 400, 502]),
+            description: Some("description".to_string()),
+            detail: None,
+            recommendation: Some("Upgrade to 2.17.1".to_string()),
+            workaround: None,
+            proof_of_concept: None,
+            advisories: Some(vec![Advisory {
+                title: Some(NormalizedString::new("Log4Shell")),
+                url: Uri("https://logging.apache.org/log4j/2.x/security.html".to_string()),
+            }]),
+            created: Some(DateTime("2021-12-10T00:00:00+00:00".to_string())),
+            published: None,
+            updated: None,
+            rejected: None,
+            credits: Some(VulnerabilityCredits {
+                organizations: None,
+                individuals: Some(vec![OrganizationalContact::new("Chen Zhaojun", None)]),
+            }),
+            tools: Some(Tools::List(vec![Tool::new("vendor", "scanner", "1.0.0")])),
+            analysis: Some(VulnerabilityAnalysis {
+                state: Some(ImpactAnalysisState::NotAffected),
+                justification: Some(ImpactAnalysisJustification::CodeNotReachable),
+                responses: Some(vec![ImpactAnalysisResponse::WillNotFix]),
+                detail: None,
+                first_issued: None,
+                last_updated: None,
+            }),
+            affects: Some(vec![VulnerabilityTarget {
+                bom_ref: "component-1".to_string(),
+                versions: Some(vec![
+                    AffectedVersion {
+                        version_range: VersionRange::Version(NormalizedString::new("2.14.1")),
+                        status: Some(AffectedStatus::Affected),
+                    },
+                    AffectedVersion {
+                        version_range: VersionRange::Range(NormalizedString::new(
+                            "vers:maven/>=2.17.1",
+                        )),
+                        status: Some(AffectedStatus::Unaffected),
+                    },
+                ]),
+            }]),
+            properties: None,
+        }
+    }
+
+    #[test]
+    fn valid_vulnerabilities_should_pass_validation() {
+        let validation_result = Vulnerabilities(vec![valid_vulnerability()])
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_vulnerabilities_should_fail_validation() {
+        let mut vulnerability = valid_vulnerability();
+        vulnerability.ratings = Some(vec![VulnerabilityRating {
+            source: None,
+            score: Some(Score::from(f32::NAN)),
+            severity: Some(Severity::UnknownSeverity("severe".to_string())),
+            method: Some(ScoreMethod::UnknownScoreMethod("CVSSv5".to_string())),
+            vector: None,
+            justification: None,
+        }]);
+        vulnerability.created = Some(DateTime("invalid date".to_string()));
+        vulnerability.analysis = Some(VulnerabilityAnalysis {
+            state: Some(ImpactAnalysisState::UnknownImpactAnalysisState(
+                "fixed".to_string(),
+            )),
+            justification: None,
+            responses: Some(vec![ImpactAnalysisResponse::UnknownImpactAnalysisResponse(
+                "ignore".to_string(),
+            )]),
+            detail: None,
+            first_issued: None,
+            last_updated: None,
+        });
+        vulnerability.affects = Some(vec![VulnerabilityTarget {
+            bom_ref: "component-1".to_string(),
+            versions: Some(vec![AffectedVersion {
+                version_range: VersionRange::Version(NormalizedString(
+                    "spaces and\ttabs".to_string(),
+                )),
+                status: Some(AffectedStatus::UnknownAffectedStatus("maybe".to_string())),
+            }]),
+        }]);
+
+        let validation_result = Vulnerabilities(vec![vulnerability])
+            .validate_with_context(ValidationContext::default())
+            .expect("Error while validating");
+
+        let rating_context = |field_name: &str| {
+            ValidationContext(vec![
+                ValidationPathComponent::Array { index: 0 },
+                ValidationPathComponent::Struct {
+                    struct_name: "Vulnerability".to_string(),
+                    field_name: "ratings".to_string(),
+                },
+                ValidationPathComponent::Array { index: 0 },
+                ValidationPathComponent::Struct {
+                    struct_name: "VulnerabilityRating".to_string(),
+                    field_name: field_name.to_string(),
+                },
+            ])
+        };
+        let affected_version_context = |field_name: &str| {
+            ValidationContext(vec![
+                ValidationPathComponent::Array { index: 0 },
+                ValidationPathComponent::Struct {
+                    struct_name: "Vulnerability".to_string(),
+                    field_name: "affects".to_string(),
+                },
+                ValidationPathComponent::Array { index: 0 },
+                ValidationPathComponent::Struct {
+                    struct_name: "VulnerabilityTarget".to_string(),
+                    field_name: "versions".to_string(),
+                },
+                ValidationPathComponent::Array { index: 0 },
+                ValidationPathComponent::Struct {
+                    struct_name: "AffectedVersion".to_string(),
+                    field_name: field_name.to_string(),
+                },
+            ])
+        };
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![
+                    FailureReason {
+                        severity: ValidationSeverity::Error,
+                        message: "Score must be a non-negative number".to_string(),
+                        context: rating_context("score"),
+                    },
+                    FailureReason {
+                        severity: ValidationSeverity::Error,
+                        message: "Unknown severity".to_string(),
+                        context: rating_context("severity"),
+                    },
+                    FailureReason {
+                        severity: ValidationSeverity::Error,
+                        message: "Unknown score method".to_string(),
+                        context: rating_context("method"),
+                    },
+                    FailureReason {
+                        severity: ValidationSeverity::Error,
+                        message: "DateTime does not conform to ISO 8601".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Vulnerability".to_string(),
+                                field_name: "created".to_string(),
+                            },
+                        ]),
+                    },
+                    FailureReason {
+                        severity: ValidationSeverity::Error,
+                        message: "Unknown impact analysis state".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Vulnerability".to_string(),
+                                field_name: "analysis".to_string(),
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "VulnerabilityAnalysis".to_string(),
+                                field_name: "state".to_string(),
+                            },
+                        ]),
+                    },
+                    FailureReason {
+                        severity: ValidationSeverity::Error,
+                        message: "Unknown impact analysis response".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::Struct {
+                                struct_name: "Vulnerability".to_string(),
+                                field_name: "analysis".to_string(),
+                            },
+                            ValidationPathComponent::Struct {
+                                struct_name: "VulnerabilityAnalysis".to_string(),
+                                field_name: "responses".to_string(),
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                        ]),
+                    },
+                    FailureReason {
+                        severity: ValidationSeverity::Error,
+                        message:
+                            "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                                .to_string(),
+                        context: {
+                            let mut context = affected_version_context("version_range");
+                            context.0.push(ValidationPathComponent::EnumVariant {
+                                variant_name: "Version".to_string(),
+                            });
+                            context
+                        },
+                    },
+                    FailureReason {
+                        severity: ValidationSeverity::Error,
+                        message: "Unknown affected status".to_string(),
+                        context: affected_version_context("status"),
+                    },
+                ]
+            }
+        );
+    }
+}
@@ -0,0 +1,1158 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Conversion between the [`Bom`](crate::models::bom::Bom) model and the messages generated from
+//! the CycloneDX Protocol Buffers schema in `schema/bom-1.3.proto`
+//!
+//! As with the JSON and XML documents for version 1.3 of the specification, fields that were only
+//! added in later versions are dropped when writing, and enum values that the schema cannot
+//! represent are written as the `NULL` value of the enum. Reading is lenient: unknown enum values
+//! become the `Unknown-X` variant of the model, so that they are reported by validation.
+
+use std::convert::{TryFrom, TryInto};
+
+use protobuf::well_known_types::timestamp::Timestamp;
+use protobuf::{Enum, EnumOrUnknown, Message, MessageField};
+use time::{
+    format_description::well_known::{Iso8601, Rfc3339},
+    OffsetDateTime,
+};
+
+use crate::errors::{ProtobufReadError, ProtobufWriteError};
+use crate::external_models::{
+    date_time::DateTime,
+    normalized_string::NormalizedString,
+    spdx::{SpdxExpression, SpdxIdentifier},
+    uri::{Purl, Uri},
+};
+use crate::models;
+
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/protobuf/mod.rs"));
+}
+
+use generated::bom_1_3 as proto;
+
+pub(crate) fn read_protobuf_document_v1_3<R: std::io::Read>(
+    mut reader: R,
+) -> Result<models::bom::Bom, ProtobufReadError> {
+    let bom = proto::Bom::parse_from_reader(&mut reader)?;
+    Ok(bom.into())
+}
+
+pub(crate) fn write_protobuf_document_v1_3<W: std::io::Write>(
+    bom: models::bom::Bom,
+    writer: &mut W,
+) -> Result<(), ProtobufWriteError> {
+    let bom: proto::Bom = bom.try_into()?;
+    bom.write_to_writer(writer)?;
+    Ok(())
+}
+
+impl TryFrom<models::bom::Bom> for proto::Bom {
+    type Error = ProtobufWriteError;
+
+    fn try_from(other: models::bom::Bom) -> Result<Self, Self::Error> {
+        Ok(Self {
+            spec_version: "1.3".to_string(),
+            version: Some(other.version as i32),
+            serial_number: other.serial_number.map(|s| s.0),
+            metadata: try_convert_message(other.metadata)?,
+            components: try_convert_repeated(other.components.map(|c| c.0))?,
+            services: convert_repeated(other.services.map(|s| s.0)),
+            external_references: convert_repeated(other.external_references.map(|e| e.0)),
+            dependencies: convert_repeated(other.dependencies.map(|d| d.0)),
+            compositions: convert_repeated(other.compositions.map(|c| c.0)),
+            ..Default::default()
+        })
+    }
+}
+
+impl From<proto::Bom> for models::bom::Bom {
+    fn from(other: proto::Bom) -> Self {
+        Self {
+            version: other.version.map(|v| v as u32).unwrap_or(1),
+            serial_number: other.serial_number.map(models::bom::UrnUuid),
+            metadata: convert_message(other.metadata),
+            components: convert_from_repeated(other.components).map(models::component::Components),
+            services: convert_from_repeated(other.services).map(models::service::Services),
+            external_references: convert_from_repeated(other.external_references)
+                .map(models::external_reference::ExternalReferences),
+            dependencies: convert_from_repeated(other.dependencies)
+                .map(models::dependency::Dependencies),
+            compositions: convert_from_repeated(other.compositions)
+                .map(models::composition::Compositions),
+            properties: None,
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
+            signature: None,
+        }
+    }
+}
+
+impl TryFrom<models::metadata::Metadata> for proto::Metadata {
+    type Error = ProtobufWriteError;
+
+    fn try_from(other: models::metadata::Metadata) -> Result<Self, Self::Error> {
+        Ok(Self {
+            timestamp: try_convert_timestamp(other.timestamp, "metadata")?,
+            tools: convert_repeated(other.tools.map(models::tool::Tools::into_list)),
+            authors: convert_repeated(other.authors),
+            component: try_convert_message(other.component)?,
+            manufacture: convert_to_message(other.manufacture),
+            supplier: convert_to_message(other.supplier),
+            // the schema only allows a single license choice for the BOM itself
+            licenses: convert_to_message(other.licenses.and_then(|l| l.0.into_iter().next())),
+            properties: convert_repeated(other.properties.map(|p| p.0)),
+            ..Default::default()
+        })
+    }
+}
+
+impl From<proto::Metadata> for models::metadata::Metadata {
+    fn from(other: proto::Metadata) -> Self {
+        Self {
+            timestamp: other.timestamp.into_option().map(convert_from_timestamp),
+            lifecycles: None,
+            tools: convert_from_repeated(other.tools).map(models::tool::Tools::List),
+            authors: convert_from_repeated(other.authors),
+            component: convert_message(other.component),
+            manufacture: convert_message(other.manufacture),
+            supplier: convert_message(other.supplier),
+            licenses: other
+                .licenses
+                .into_option()
+                .map(|l| models::license::Licenses(vec![l.into()])),
+            properties: convert_from_repeated(other.properties).map(models::property::Properties),
+        }
+    }
+}
+
+impl From<models::tool::Tool> for proto::Tool {
+    fn from(other: models::tool::Tool) -> Self {
+        Self {
+            vendor: other.vendor.map(|v| v.0),
+            name: other.name.map(|n| n.0),
+            version: other.version.map(|v| v.0),
+            hashes: convert_repeated(other.hashes.map(|h| h.0)),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Tool> for models::tool::Tool {
+    fn from(other: proto::Tool) -> Self {
+        Self {
+            vendor: other.vendor.map(NormalizedString::new_unchecked),
+            name: other.name.map(NormalizedString::new_unchecked),
+            version: other.version.map(NormalizedString::new_unchecked),
+            hashes: convert_from_repeated(other.hashes).map(models::hash::Hashes),
+            external_references: None,
+        }
+    }
+}
+
+impl From<models::organization::OrganizationalContact> for proto::OrganizationalContact {
+    fn from(other: models::organization::OrganizationalContact) -> Self {
+        Self {
+            name: other.name.map(|n| n.0),
+            email: other.email.map(|e| e.0),
+            phone: other.phone.map(|p| p.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::OrganizationalContact> for models::organization::OrganizationalContact {
+    fn from(other: proto::OrganizationalContact) -> Self {
+        Self {
+            name: other.name.map(NormalizedString::new_unchecked),
+            email: other.email.map(NormalizedString::new_unchecked),
+            phone: other.phone.map(NormalizedString::new_unchecked),
+        }
+    }
+}
+
+impl From<models::organization::OrganizationalEntity> for proto::OrganizationalEntity {
+    fn from(other: models::organization::OrganizationalEntity) -> Self {
+        Self {
+            name: other.name.map(|n| n.0),
+            url: other
+                .url
+                .unwrap_or_default()
+                .into_iter()
+                .map(|u| u.0)
+                .collect(),
+            contact: convert_repeated(other.contact),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::OrganizationalEntity> for models::organization::OrganizationalEntity {
+    fn from(other: proto::OrganizationalEntity) -> Self {
+        Self {
+            name: other.name.map(NormalizedString::new_unchecked),
+            url: convert_strings(other.url, Uri),
+            contact: convert_from_repeated(other.contact),
+        }
+    }
+}
+
+impl TryFrom<models::component::Component> for proto::Component {
+    type Error = ProtobufWriteError;
+
+    fn try_from(other: models::component::Component) -> Result<Self, Self::Error> {
+        Ok(Self {
+            type_: enum_to_proto(CLASSIFICATIONS, &other.component_type.to_string()),
+            mime_type: other.mime_type.map(|m| m.0),
+            bom_ref: other.bom_ref,
+            supplier: convert_to_message(other.supplier),
+            author: other.author.map(|a| a.0),
+            publisher: other.publisher.map(|p| p.0),
+            group: other.group.map(|g| g.0),
+            name: other.name.0,
+            version: other.version.0,
+            description: other.description.map(|d| d.0),
+            scope: other.scope.map(|s| enum_to_proto(SCOPES, &s.to_string())),
+            hashes: convert_repeated(other.hashes.map(|h| h.0)),
+            licenses: convert_repeated(other.licenses.map(|l| l.0)),
+            copyright: other.copyright.map(|c| c.0),
+            cpe: other.cpe.map(|c| c.0),
+            purl: other.purl.map(|p| p.0),
+            swid: convert_to_message(other.swid),
+            modified: other.modified,
+            pedigree: try_convert_message(other.pedigree)?,
+            external_references: convert_repeated(other.external_references.map(|e| e.0)),
+            components: try_convert_repeated(other.components.map(|c| c.0))?,
+            properties: convert_repeated(other.properties.map(|p| p.0)),
+            evidence: other.evidence.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        })
+    }
+}
+
+impl From<proto::Component> for models::component::Component {
+    fn from(other: proto::Component) -> Self {
+        Self {
+            component_type: models::component::Classification::new_unchecked(enum_from_proto(
+                CLASSIFICATIONS,
+                other.type_,
+            )),
+            mime_type: other.mime_type.map(models::component::MimeType),
+            bom_ref: other.bom_ref,
+            supplier: convert_message(other.supplier),
+            author: other.author.map(NormalizedString::new_unchecked),
+            publisher: other.publisher.map(NormalizedString::new_unchecked),
+            group: other.group.map(NormalizedString::new_unchecked),
+            name: NormalizedString::new_unchecked(other.name),
+            version: NormalizedString::new_unchecked(other.version),
+            description: other.description.map(NormalizedString::new_unchecked),
+            scope: other.scope.and_then(|s| match enum_from_proto(SCOPES, s) {
+                "" => None,
+                scope => Some(models::component::Scope::new_unchecked(scope)),
+            }),
+            hashes: convert_from_repeated(other.hashes).map(models::hash::Hashes),
+            licenses: convert_from_repeated(other.licenses).map(models::license::Licenses),
+            copyright: other.copyright.map(NormalizedString::new_unchecked),
+            cpe: other.cpe.map(models::component::Cpe),
+            purl: other.purl.map(Purl),
+            swid: convert_message(other.swid),
+            modified: other.modified,
+            pedigree: convert_message(other.pedigree),
+            external_references: convert_from_repeated(other.external_references)
+                .map(models::external_reference::ExternalReferences),
+            properties: convert_from_repeated(other.properties).map(models::property::Properties),
+            components: convert_from_repeated(other.components).map(models::component::Components),
+            // the schema allows repeated evidence, but the model only has room for one
+            evidence: other.evidence.into_iter().next().map(Into::into),
+            release_notes: None,
+            model_card: None,
+            data: None,
+            signature: None,
+        }
+    }
+}
+
+impl From<models::component::Swid> for proto::Swid {
+    fn from(other: models::component::Swid) -> Self {
+        Self {
+            tag_id: other.tag_id,
+            name: other.name,
+            version: other.version,
+            tag_version: other.tag_version.map(|t| t as i32),
+            patch: other.patch,
+            text: convert_to_message(other.text),
+            url: other.url.map(|u| u.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Swid> for models::component::Swid {
+    fn from(other: proto::Swid) -> Self {
+        Self {
+            tag_id: other.tag_id,
+            name: other.name,
+            version: other.version,
+            tag_version: other.tag_version.map(|t| t as u32),
+            patch: other.patch,
+            text: convert_message(other.text),
+            url: other.url.map(Uri),
+        }
+    }
+}
+
+impl From<models::component::ComponentEvidence> for proto::Evidence {
+    fn from(other: models::component::ComponentEvidence) -> Self {
+        Self {
+            licenses: convert_repeated(other.licenses.map(|l| l.0)),
+            copyright: other
+                .copyright
+                .map(|c| c.0)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|c| proto::EvidenceCopyright {
+                    text: c.0,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Evidence> for models::component::ComponentEvidence {
+    fn from(other: proto::Evidence) -> Self {
+        Self {
+            licenses: convert_from_repeated(other.licenses).map(models::license::Licenses),
+            copyright: convert_strings(
+                other.copyright.into_iter().map(|c| c.text).collect(),
+                models::component::Copyright,
+            )
+            .map(models::component::CopyrightTexts),
+            identity: None,
+            occurrences: None,
+            callstack: None,
+        }
+    }
+}
+
+impl TryFrom<models::component::Pedigree> for proto::Pedigree {
+    type Error = ProtobufWriteError;
+
+    fn try_from(other: models::component::Pedigree) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ancestors: try_convert_repeated(other.ancestors.map(|a| a.0))?,
+            descendants: try_convert_repeated(other.descendants.map(|d| d.0))?,
+            variants: try_convert_repeated(other.variants.map(|v| v.0))?,
+            commits: try_convert_repeated(other.commits.map(|c| c.0))?,
+            patches: convert_repeated(other.patches.map(|p| p.0)),
+            notes: other.notes,
+            ..Default::default()
+        })
+    }
+}
+
+impl From<proto::Pedigree> for models::component::Pedigree {
+    fn from(other: proto::Pedigree) -> Self {
+        Self {
+            ancestors: convert_from_repeated(other.ancestors).map(models::component::Components),
+            descendants: convert_from_repeated(other.descendants)
+                .map(models::component::Components),
+            variants: convert_from_repeated(other.variants).map(models::component::Components),
+            commits: convert_from_repeated(other.commits).map(models::code::Commits),
+            patches: convert_from_repeated(other.patches).map(models::code::Patches),
+            notes: other.notes,
+        }
+    }
+}
+
+impl TryFrom<models::code::Commit> for proto::Commit {
+    type Error = ProtobufWriteError;
+
+    fn try_from(other: models::code::Commit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            uid: other.uid.map(|u| u.0),
+            url: other.url.map(|u| u.0),
+            author: try_convert_message(other.author)?,
+            committer: try_convert_message(other.committer)?,
+            message: other.message.map(|m| m.0),
+            ..Default::default()
+        })
+    }
+}
+
+impl From<proto::Commit> for models::code::Commit {
+    fn from(other: proto::Commit) -> Self {
+        Self {
+            uid: other.uid.map(NormalizedString::new_unchecked),
+            url: other.url.map(Uri),
+            author: convert_message(other.author),
+            committer: convert_message(other.committer),
+            message: other.message.map(NormalizedString::new_unchecked),
+        }
+    }
+}
+
+impl TryFrom<models::code::IdentifiableAction> for proto::IdentifiableAction {
+    type Error = ProtobufWriteError;
+
+    fn try_from(other: models::code::IdentifiableAction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            timestamp: try_convert_timestamp(other.timestamp, "commit")?,
+            name: other.name.map(|n| n.0),
+            email: other.email.map(|e| e.0),
+            ..Default::default()
+        })
+    }
+}
+
+impl From<proto::IdentifiableAction> for models::code::IdentifiableAction {
+    fn from(other: proto::IdentifiableAction) -> Self {
+        Self {
+            timestamp: other.timestamp.into_option().map(convert_from_timestamp),
+            name: other.name.map(NormalizedString::new_unchecked),
+            email: other.email.map(NormalizedString::new_unchecked),
+        }
+    }
+}
+
+impl From<models::code::Patch> for proto::Patch {
+    fn from(other: models::code::Patch) -> Self {
+        Self {
+            type_: enum_to_proto(PATCH_CLASSIFICATIONS, &other.patch_type.to_string()),
+            diff: convert_to_message(other.diff),
+            resolves: convert_repeated(other.resolves),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Patch> for models::code::Patch {
+    fn from(other: proto::Patch) -> Self {
+        Self {
+            patch_type: models::code::PatchClassification::new_unchecked(enum_from_proto(
+                PATCH_CLASSIFICATIONS,
+                other.type_,
+            )),
+            diff: convert_message(other.diff),
+            resolves: convert_from_repeated(other.resolves),
+        }
+    }
+}
+
+impl From<models::code::Diff> for proto::Diff {
+    fn from(other: models::code::Diff) -> Self {
+        Self {
+            text: convert_to_message(other.text),
+            url: other.url.map(|u| u.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Diff> for models::code::Diff {
+    fn from(other: proto::Diff) -> Self {
+        Self {
+            text: convert_message(other.text),
+            url: other.url.map(Uri),
+        }
+    }
+}
+
+impl From<models::code::Issue> for proto::Issue {
+    fn from(other: models::code::Issue) -> Self {
+        Self {
+            type_: enum_to_proto(ISSUE_CLASSIFICATIONS, &other.issue_type.to_string()),
+            id: other.id.map(|i| i.0),
+            name: other.name.map(|n| n.0),
+            description: other.description.map(|d| d.0),
+            source: convert_to_message(other.source),
+            references: other
+                .references
+                .unwrap_or_default()
+                .into_iter()
+                .map(|r| r.0)
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Issue> for models::code::Issue {
+    fn from(other: proto::Issue) -> Self {
+        Self {
+            issue_type: models::code::IssueClassification::new_unchecked(enum_from_proto(
+                ISSUE_CLASSIFICATIONS,
+                other.type_,
+            )),
+            id: other.id.map(NormalizedString::new_unchecked),
+            name: other.name.map(NormalizedString::new_unchecked),
+            description: other.description.map(NormalizedString::new_unchecked),
+            source: convert_message(other.source),
+            references: convert_strings(other.references, Uri),
+        }
+    }
+}
+
+impl From<models::code::Source> for proto::Source {
+    fn from(other: models::code::Source) -> Self {
+        Self {
+            name: other.name.map(|n| n.0),
+            url: other.url.map(|u| u.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Source> for models::code::Source {
+    fn from(other: proto::Source) -> Self {
+        Self {
+            name: other.name.map(NormalizedString::new_unchecked),
+            url: other.url.map(Uri),
+        }
+    }
+}
+
+impl From<models::service::Service> for proto::Service {
+    fn from(other: models::service::Service) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            provider: convert_to_message(other.provider),
+            group: other.group.map(|g| g.0),
+            name: other.name.0,
+            version: other.version.map(|v| v.0),
+            description: other.description.map(|d| d.0),
+            endpoints: other
+                .endpoints
+                .unwrap_or_default()
+                .into_iter()
+                .map(|e| e.0)
+                .collect(),
+            authenticated: other.authenticated,
+            x_trust_boundary: other.x_trust_boundary,
+            data: convert_repeated(other.data),
+            licenses: convert_repeated(other.licenses.map(|l| l.0)),
+            external_references: convert_repeated(other.external_references.map(|e| e.0)),
+            services: convert_repeated(other.services.map(|s| s.0)),
+            properties: convert_repeated(other.properties.map(|p| p.0)),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Service> for models::service::Service {
+    fn from(other: proto::Service) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            provider: convert_message(other.provider),
+            group: other.group.map(NormalizedString::new_unchecked),
+            name: NormalizedString::new_unchecked(other.name),
+            version: other.version.map(NormalizedString::new_unchecked),
+            description: other.description.map(NormalizedString::new_unchecked),
+            endpoints: convert_strings(other.endpoints, Uri),
+            authenticated: other.authenticated,
+            x_trust_boundary: other.x_trust_boundary,
+            trust_zone: None,
+            data: convert_from_repeated(other.data),
+            licenses: convert_from_repeated(other.licenses).map(models::license::Licenses),
+            external_references: convert_from_repeated(other.external_references)
+                .map(models::external_reference::ExternalReferences),
+            properties: convert_from_repeated(other.properties).map(models::property::Properties),
+            services: convert_from_repeated(other.services).map(models::service::Services),
+            release_notes: None,
+            signature: None,
+        }
+    }
+}
+
+impl From<models::service::DataClassification> for proto::DataClassification {
+    fn from(other: models::service::DataClassification) -> Self {
+        Self {
+            flow: enum_to_proto(DATA_FLOWS, &other.flow.to_string()),
+            value: other.classification.0,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::DataClassification> for models::service::DataClassification {
+    fn from(other: proto::DataClassification) -> Self {
+        Self {
+            flow: models::service::DataFlowType::new_unchecked(enum_from_proto(
+                DATA_FLOWS, other.flow,
+            )),
+            classification: NormalizedString::new_unchecked(other.value),
+        }
+    }
+}
+
+impl From<models::external_reference::ExternalReference> for proto::ExternalReference {
+    fn from(other: models::external_reference::ExternalReference) -> Self {
+        Self {
+            type_: enum_to_proto(
+                EXTERNAL_REFERENCE_TYPES,
+                &other.external_reference_type.to_string(),
+            ),
+            url: other.url.0,
+            comment: other.comment,
+            hashes: convert_repeated(other.hashes.map(|h| h.0)),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::ExternalReference> for models::external_reference::ExternalReference {
+    fn from(other: proto::ExternalReference) -> Self {
+        Self {
+            external_reference_type:
+                models::external_reference::ExternalReferenceType::new_unchecked(enum_from_proto(
+                    EXTERNAL_REFERENCE_TYPES,
+                    other.type_,
+                )),
+            url: Uri(other.url),
+            comment: other.comment,
+            hashes: convert_from_repeated(other.hashes).map(models::hash::Hashes),
+        }
+    }
+}
+
+impl From<models::hash::Hash> for proto::Hash {
+    fn from(other: models::hash::Hash) -> Self {
+        Self {
+            alg: enum_to_proto(HASH_ALGORITHMS, &other.alg.to_string()),
+            value: other.content.0,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Hash> for models::hash::Hash {
+    fn from(other: proto::Hash) -> Self {
+        Self {
+            alg: models::hash::HashAlgorithm::new_unchecked(enum_from_proto(
+                HASH_ALGORITHMS,
+                other.alg,
+            )),
+            content: models::hash::HashValue(other.value),
+        }
+    }
+}
+
+impl From<models::license::LicenseChoice> for proto::LicenseChoice {
+    fn from(other: models::license::LicenseChoice) -> Self {
+        let choice = match other {
+            models::license::LicenseChoice::License(license) => {
+                proto::license_choice::Choice::License(license.into())
+            }
+            models::license::LicenseChoice::Expression(expression) => {
+                proto::license_choice::Choice::Expression(expression.0)
+            }
+        };
+
+        Self {
+            choice: Some(choice),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::LicenseChoice> for models::license::LicenseChoice {
+    fn from(other: proto::LicenseChoice) -> Self {
+        match other.choice {
+            Some(proto::license_choice::Choice::License(license)) => Self::License(license.into()),
+            Some(proto::license_choice::Choice::Expression(expression)) => {
+                Self::Expression(SpdxExpression(expression))
+            }
+            // an empty choice is kept as an invalid expression, so that validation reports it
+            None => Self::Expression(SpdxExpression(String::new())),
+        }
+    }
+}
+
+impl From<models::license::License> for proto::License {
+    fn from(other: models::license::License) -> Self {
+        let license = match other.license_identifier {
+            models::license::LicenseIdentifier::SpdxId(id) => proto::license::License::Id(id.0),
+            models::license::LicenseIdentifier::Name(name) => proto::license::License::Name(name.0),
+        };
+
+        Self {
+            license: Some(license),
+            text: convert_to_message(other.text),
+            url: other.url.map(|u| u.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::License> for models::license::License {
+    fn from(other: proto::License) -> Self {
+        let license_identifier = match other.license {
+            Some(proto::license::License::Id(id)) => {
+                models::license::LicenseIdentifier::SpdxId(SpdxIdentifier(id))
+            }
+            Some(proto::license::License::Name(name)) => {
+                models::license::LicenseIdentifier::Name(NormalizedString::new_unchecked(name))
+            }
+            None => models::license::LicenseIdentifier::SpdxId(SpdxIdentifier(String::new())),
+        };
+
+        Self {
+            license_identifier,
+            text: convert_message(other.text),
+            url: other.url.map(Uri),
+            licensing: None,
+        }
+    }
+}
+
+impl From<models::attached_text::AttachedText> for proto::AttachedText {
+    fn from(other: models::attached_text::AttachedText) -> Self {
+        Self {
+            content_type: other.content_type.map(|c| c.0),
+            encoding: other.encoding.map(|e| e.to_string()),
+            value: other.content,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::AttachedText> for models::attached_text::AttachedText {
+    fn from(other: proto::AttachedText) -> Self {
+        Self {
+            content_type: other.content_type.map(NormalizedString::new_unchecked),
+            encoding: other
+                .encoding
+                .map(models::attached_text::Encoding::new_unchecked),
+            content: other.value,
+        }
+    }
+}
+
+impl From<models::property::Property> for proto::Property {
+    fn from(other: models::property::Property) -> Self {
+        Self {
+            name: other.name,
+            value: Some(other.value.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Property> for models::property::Property {
+    fn from(other: proto::Property) -> Self {
+        Self {
+            name: other.name,
+            value: NormalizedString::new_unchecked(other.value.unwrap_or_default()),
+        }
+    }
+}
+
+impl From<models::dependency::Dependency> for proto::Dependency {
+    fn from(other: models::dependency::Dependency) -> Self {
+        Self {
+            ref_: other.dependency_ref,
+            dependencies: other
+                .dependencies
+                .into_iter()
+                .map(|dependency_ref| proto::Dependency {
+                    ref_: dependency_ref,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Dependency> for models::dependency::Dependency {
+    fn from(other: proto::Dependency) -> Self {
+        Self {
+            dependency_ref: other.ref_,
+            dependencies: other.dependencies.into_iter().map(|d| d.ref_).collect(),
+        }
+    }
+}
+
+impl From<models::composition::Composition> for proto::Composition {
+    fn from(other: models::composition::Composition) -> Self {
+        Self {
+            aggregate: enum_to_proto(AGGREGATE_TYPES, &other.aggregate.to_string()),
+            assemblies: other
+                .assemblies
+                .unwrap_or_default()
+                .into_iter()
+                .map(|a| a.0)
+                .collect(),
+            dependencies: other
+                .dependencies
+                .unwrap_or_default()
+                .into_iter()
+                .map(|d| d.0)
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::Composition> for models::composition::Composition {
+    fn from(other: proto::Composition) -> Self {
+        Self {
+            aggregate: models::composition::AggregateType::new_unchecked(enum_from_proto(
+                AGGREGATE_TYPES,
+                other.aggregate,
+            )),
+            assemblies: convert_strings(other.assemblies, models::composition::BomReference),
+            dependencies: convert_strings(other.dependencies, models::composition::BomReference),
+            signature: None,
+        }
+    }
+}
+
+const CLASSIFICATIONS: &[(proto::Classification, &str)] = &[
+    (
+        proto::Classification::CLASSIFICATION_APPLICATION,
+        "application",
+    ),
+    (proto::Classification::CLASSIFICATION_FRAMEWORK, "framework"),
+    (proto::Classification::CLASSIFICATION_LIBRARY, "library"),
+    (
+        proto::Classification::CLASSIFICATION_OPERATING_SYSTEM,
+        "operating-system",
+    ),
+    (proto::Classification::CLASSIFICATION_DEVICE, "device"),
+    (proto::Classification::CLASSIFICATION_FILE, "file"),
+    (proto::Classification::CLASSIFICATION_CONTAINER, "container"),
+    (proto::Classification::CLASSIFICATION_FIRMWARE, "firmware"),
+];
+
+const SCOPES: &[(proto::Scope, &str)] = &[
+    (proto::Scope::SCOPE_REQUIRED, "required"),
+    (proto::Scope::SCOPE_OPTIONAL, "optional"),
+    (proto::Scope::SCOPE_EXCLUDED, "excluded"),
+];
+
+const HASH_ALGORITHMS: &[(proto::HashAlg, &str)] = &[
+    (proto::HashAlg::HASH_ALG_MD_5, "MD5"),
+    (proto::HashAlg::HASH_ALG_SHA_1, "SHA-1"),
+    (proto::HashAlg::HASH_ALG_SHA_256, "SHA-256"),
+    (proto::HashAlg::HASH_ALG_SHA_384, "SHA-384"),
+    (proto::HashAlg::HASH_ALG_SHA_512, "SHA-512"),
+    (proto::HashAlg::HASH_ALG_SHA_3_256, "SHA3-256"),
+    (proto::HashAlg::HASH_ALG_SHA_3_384, "SHA3-384"),
+    (proto::HashAlg::HASH_ALG_SHA_3_512, "SHA3-512"),
+    (proto::HashAlg::HASH_ALG_BLAKE_2_B_256, "BLAKE2b-256"),
+    (proto::HashAlg::HASH_ALG_BLAKE_2_B_384, "BLAKE2b-384"),
+    (proto::HashAlg::HASH_ALG_BLAKE_2_B_512, "BLAKE2b-512"),
+    (proto::HashAlg::HASH_ALG_BLAKE_3, "BLAKE3"),
+];
+
+const EXTERNAL_REFERENCE_TYPES: &[(proto::ExternalReferenceType, &str)] = &[
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_OTHER,
+        "other",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_VCS,
+        "vcs",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_ISSUE_TRACKER,
+        "issue-tracker",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_WEBSITE,
+        "website",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_ADVISORIES,
+        "advisories",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_BOM,
+        "bom",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_MAILING_LIST,
+        "mailing-list",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_SOCIAL,
+        "social",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_CHAT,
+        "chat",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_DOCUMENTATION,
+        "documentation",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_SUPPORT,
+        "support",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_DISTRIBUTION,
+        "distribution",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_LICENSE,
+        "license",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_BUILD_META,
+        "build-meta",
+    ),
+    (
+        proto::ExternalReferenceType::EXTERNAL_REFERENCE_TYPE_BUILD_SYSTEM,
+        "build-system",
+    ),
+];
+
+const DATA_FLOWS: &[(proto::DataFlow, &str)] = &[
+    (proto::DataFlow::DATA_FLOW_INBOUND, "inbound"),
+    (proto::DataFlow::DATA_FLOW_OUTBOUND, "outbound"),
+    (proto::DataFlow::DATA_FLOW_BI_DIRECTIONAL, "bi-directional"),
+    (proto::DataFlow::DATA_FLOW_UNKNOWN, "unknown"),
+];
+
+const ISSUE_CLASSIFICATIONS: &[(proto::IssueClassification, &str)] = &[
+    (
+        proto::IssueClassification::ISSUE_CLASSIFICATION_DEFECT,
+        "defect",
+    ),
+    (
+        proto::IssueClassification::ISSUE_CLASSIFICATION_ENHANCEMENT,
+        "enhancement",
+    ),
+    (
+        proto::IssueClassification::ISSUE_CLASSIFICATION_SECURITY,
+        "security",
+    ),
+];
+
+const PATCH_CLASSIFICATIONS: &[(proto::PatchClassification, &str)] = &[
+    (
+        proto::PatchClassification::PATCH_CLASSIFICATION_UNOFFICIAL,
+        "unofficial",
+    ),
+    (
+        proto::PatchClassification::PATCH_CLASSIFICATION_MONKEY,
+        "monkey",
+    ),
+    (
+        proto::PatchClassification::PATCH_CLASSIFICATION_BACKPORT,
+        "backport",
+    ),
+    (
+        proto::PatchClassification::PATCH_CLASSIFICATION_CHERRY_PICK,
+        "cherry-pick",
+    ),
+];
+
+const AGGREGATE_TYPES: &[(proto::Aggregate, &str)] = &[
+    (proto::Aggregate::AGGREGATE_NOT_SPECIFIED, "not_specified"),
+    (proto::Aggregate::AGGREGATE_COMPLETE, "complete"),
+    (proto::Aggregate::AGGREGATE_INCOMPLETE, "incomplete"),
+    (
+        proto::Aggregate::AGGREGATE_INCOMPLETE_FIRST_PARTY_ONLY,
+        "incomplete_first_party_only",
+    ),
+    (
+        proto::Aggregate::AGGREGATE_INCOMPLETE_THIRD_PARTY_ONLY,
+        "incomplete_third_party_only",
+    ),
+    (proto::Aggregate::AGGREGATE_UNKNOWN, "unknown"),
+];
+
+/// Look up the enum value for a CycloneDX string, falling back to the default (`NULL`) value when
+/// the schema has no equivalent
+fn enum_to_proto<E: Enum + Default>(table: &[(E, &str)], value: &str) -> EnumOrUnknown<E> {
+    let value = table
+        .iter()
+        .find(|(_, name)| *name == value)
+        .map(|(proto_value, _)| *proto_value)
+        .unwrap_or_default();
+    EnumOrUnknown::new(value)
+}
+
+/// Look up the CycloneDX string for an enum value, returning an empty string for the `NULL` value
+/// and any value that is unknown to the schema
+fn enum_from_proto<E: Enum>(table: &[(E, &'static str)], value: EnumOrUnknown<E>) -> &'static str {
+    value
+        .enum_value()
+        .ok()
+        .and_then(|value| table.iter().find(|(proto_value, _)| *proto_value == value))
+        .map(|(_, name)| *name)
+        .unwrap_or_default()
+}
+
+fn try_convert_timestamp(
+    value: Option<DateTime>,
+    element: &str,
+) -> Result<MessageField<Timestamp>, ProtobufWriteError> {
+    let value = match value {
+        Some(value) => value,
+        None => return Ok(MessageField::none()),
+    };
+
+    let date_time = OffsetDateTime::parse(&value.0, &Iso8601::DEFAULT).map_err(|_| {
+        ProtobufWriteError::InvalidTimestamp {
+            value: value.0.clone(),
+            element: element.to_string(),
+        }
+    })?;
+
+    Ok(MessageField::some(Timestamp {
+        seconds: date_time.unix_timestamp(),
+        nanos: date_time.nanosecond() as i32,
+        ..Default::default()
+    }))
+}
+
+/// A timestamp that cannot be represented is kept as its number of seconds, which does not
+/// conform to ISO 8601 and is therefore reported by validation
+fn convert_from_timestamp(value: Timestamp) -> DateTime {
+    OffsetDateTime::from_unix_timestamp(value.seconds)
+        .ok()
+        .and_then(|date_time| date_time.replace_nanosecond(value.nanos as u32).ok())
+        .and_then(|date_time| date_time.format(&Rfc3339).ok())
+        .map(DateTime)
+        .unwrap_or_else(|| DateTime(value.seconds.to_string()))
+}
+
+fn convert_to_message<A, B: From<A>>(value: Option<A>) -> MessageField<B> {
+    MessageField::from_option(value.map(From::from))
+}
+
+fn try_convert_message<A, B: TryFrom<A>>(value: Option<A>) -> Result<MessageField<B>, B::Error> {
+    Ok(MessageField::from_option(
+        value.map(TryFrom::try_from).transpose()?,
+    ))
+}
+
+fn convert_message<A, B: From<A>>(value: MessageField<A>) -> Option<B> {
+    value.into_option().map(From::from)
+}
+
+fn convert_repeated<A, B: From<A>>(value: Option<Vec<A>>) -> Vec<B> {
+    value
+        .unwrap_or_default()
+        .into_iter()
+        .map(From::from)
+        .collect()
+}
+
+fn try_convert_repeated<A, B: TryFrom<A>>(value: Option<Vec<A>>) -> Result<Vec<B>, B::Error> {
+    value
+        .unwrap_or_default()
+        .into_iter()
+        .map(TryFrom::try_from)
+        .collect()
+}
+
+/// Repeated fields cannot distinguish an empty list from an absent one, so both are read as absent
+fn convert_from_repeated<A, B: From<A>>(value: Vec<A>) -> Option<Vec<B>> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.into_iter().map(From::from).collect())
+    }
+}
+
+fn convert_strings<T>(value: Vec<String>, wrap: fn(String) -> T) -> Option<Vec<T>> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.into_iter().map(wrap).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::bom::Bom;
+    use crate::models::component::{Classification, Component, Components};
+    use crate::models::metadata::Metadata;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_convert_timestamps() {
+        let timestamp = try_convert_timestamp(
+            Some(DateTime("2023-11-14T22:13:20.5+01:00".to_string())),
+            "metadata",
+        )
+        .expect("Failed to convert timestamp")
+        .into_option()
+        .expect("Missing timestamp");
+
+        assert_eq!(timestamp.seconds, 1699996400);
+        assert_eq!(timestamp.nanos, 500_000_000);
+        assert_eq!(
+            convert_from_timestamp(timestamp),
+            DateTime("2023-11-14T21:13:20.5Z".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_fail_to_write_an_invalid_timestamp() {
+        let bom = Bom {
+            metadata: Some(Metadata {
+                timestamp: Some(DateTime("not a timestamp".to_string())),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+
+        let error = bom
+            .output_as_protobuf_v1_3(&mut Vec::new())
+            .expect_err("Expected the timestamp to be rejected");
+
+        assert_eq!(
+            error.to_string(),
+            "Could not convert not a timestamp on metadata to a Protocol Buffers timestamp"
+        );
+    }
+
+    #[test]
+    fn it_should_read_enum_values_that_the_schema_cannot_represent_as_unknown() {
+        let bom = Bom {
+            components: Some(Components(vec![Component::new(
+                Classification::Platform,
+                "platform",
+                "1.0.0",
+                None,
+            )])),
+            ..Bom::default()
+        };
+
+        let mut output = Vec::new();
+        bom.output_as_protobuf_v1_3(&mut output)
+            .expect("Failed to write BOM");
+        let bom = Bom::parse_from_protobuf_v1_3(output.as_slice()).expect("Failed to read BOM");
+
+        assert_eq!(
+            bom.components.expect("Missing components").0[0].component_type,
+            Classification::UnknownClassification("".to_string())
+        );
+    }
+}
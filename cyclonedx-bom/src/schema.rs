@@ -0,0 +1,224 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Validation of raw JSON documents against the CycloneDX JSON schemas
+//!
+//! This module is only available with the `json-schema` feature. The schemas for each supported
+//! specification version, the SPDX license list and the JSON Signature Format (JSF) are bundled
+//! with the library, so validation happens offline. They are copied unchanged from the CycloneDX
+//! specification by `schema/vendor.sh`, which records their sources in `schema/SOURCES`.
+//!
+//! Use this module to check the conformance of a document before converting it to a
+//! [`Bom`](crate::models::bom::Bom), which discards anything that does not fit the model.
+//!
+//! XML documents are not validated against the XSDs, as no XSD validator is available without
+//! depending on native libraries.
+//!
+//! ```rust
+//! use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+//! use cyclonedx_bom::schema::validate_json;
+//!
+//! let bom_json = br#"{
+//!   "bomFormat": "CycloneDX",
+//!   "specVersion": "1.5",
+//!   "version": 1,
+//!   "components": [{ "type": "library", "name": "first", "hashes": "none" }]
+//! }"#;
+//!
+//! let document = serde_json::from_slice(bom_json).expect("Failed to read JSON");
+//! let validation_result =
+//!     validate_json(&document, SpecVersion::V1_5).expect("Failed to validate JSON");
+//! assert!(!validation_result.passed());
+//!
+//! let reason = &validation_result.reasons()[0];
+//! assert_eq!(reason.context.to_string(), "Bom.components[0].hashes");
+//! ```
+
+use jsonschema::{paths::PathChunk, JSONSchema};
+use once_cell::sync::Lazy;
+use serde_json::Value;
+
+use crate::models::bom::SpecVersion;
+use crate::validation::{
+    FailureReason, Severity, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+static SCHEMA_V1_3: Lazy<Result<JSONSchema, String>> =
+    Lazy::new(|| compile(include_str!("../schema/bom-1.3.schema.json")));
+static SCHEMA_V1_4: Lazy<Result<JSONSchema, String>> =
+    Lazy::new(|| compile(include_str!("../schema/bom-1.4.schema.json")));
+static SCHEMA_V1_5: Lazy<Result<JSONSchema, String>> =
+    Lazy::new(|| compile(include_str!("../schema/bom-1.5.schema.json")));
+
+/// Schemas referenced by the CycloneDX schemas, keyed by their `$id`
+const REFERENCED_SCHEMAS: [(&str, &str); 2] = [
+    (
+        "http://cyclonedx.org/schema/spdx.schema.json",
+        include_str!("../schema/spdx.schema.json"),
+    ),
+    (
+        "http://cyclonedx.org/schema/jsf-0.82.schema.json",
+        include_str!("../schema/jsf-0.82.schema.json"),
+    ),
+];
+
+/// Validate a JSON document against the bundled schema of the given specification version
+///
+/// Every schema violation is reported as a [`FailureReason`] with [`Severity::Error`], and its
+/// context is the location of the offending value in the document.
+pub fn validate_json(
+    document: &Value,
+    spec_version: SpecVersion,
+) -> Result<ValidationResult, ValidationError> {
+    let schema = match spec_version {
+        SpecVersion::V1_3 => &*SCHEMA_V1_3,
+        SpecVersion::V1_4 => &*SCHEMA_V1_4,
+        SpecVersion::V1_5 => &*SCHEMA_V1_5,
+    }
+    .as_ref()
+    .map_err(|error| ValidationError::InvalidSchemaError(error.clone()))?;
+
+    let reasons: Vec<FailureReason> = match schema.validate(document) {
+        Ok(()) => return Ok(ValidationResult::Passed),
+        Err(errors) => errors
+            .map(|error| FailureReason {
+                severity: Severity::Error,
+                message: error.to_string(),
+                context: context_of(error.instance_path.iter()),
+            })
+            .collect(),
+    };
+
+    Ok(ValidationResult::Failed { reasons })
+}
+
+fn compile(schema: &str) -> Result<JSONSchema, String> {
+    let schema: Value = serde_json::from_str(schema).map_err(|error| error.to_string())?;
+
+    let mut options = JSONSchema::options();
+    for (id, document) in REFERENCED_SCHEMAS {
+        let document = serde_json::from_str(document).map_err(|error| error.to_string())?;
+        options.with_document(id.to_string(), document);
+    }
+
+    options
+        .should_validate_formats(true)
+        .compile(&schema)
+        .map_err(|error| error.to_string())
+}
+
+/// Converts the location of a value within the document to a context
+///
+/// JSON objects have no type names, so each object is named after the property that contains it,
+/// or `Bom` for the document itself.
+fn context_of<'a>(path: impl Iterator<Item = &'a PathChunk>) -> ValidationContext {
+    let mut components = Vec::new();
+    let mut struct_name = "Bom".to_string();
+
+    for chunk in path {
+        match chunk {
+            PathChunk::Property(property) => {
+                components.push(ValidationPathComponent::Struct {
+                    struct_name: struct_name.clone(),
+                    field_name: property.to_string(),
+                });
+                struct_name = property.to_string();
+            }
+            PathChunk::Index(index) => {
+                components.push(ValidationPathComponent::Array { index: *index })
+            }
+            PathChunk::Keyword(_) => {}
+        }
+    }
+
+    ValidationContext(components)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_a_conforming_document() {
+        let document = json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.4",
+            "version": 1,
+            "components": [{
+                "type": "library",
+                "name": "component",
+                "licenses": [{ "license": { "id": "Apache-2.0" } }]
+            }]
+        });
+
+        let validation_result =
+            validate_json(&document, SpecVersion::V1_4).expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_fail_a_document_with_an_unknown_license_id() {
+        let document = json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.3",
+            "version": 1,
+            "components": [{
+                "type": "library",
+                "name": "component",
+                "version": "1.0.0",
+                "licenses": [{ "license": { "id": "Not-A-License" } }]
+            }]
+        });
+
+        let validation_result =
+            validate_json(&document, SpecVersion::V1_3).expect("Error while validating");
+
+        let contexts: Vec<String> = validation_result
+            .reasons()
+            .iter()
+            .map(|reason| reason.context.to_string())
+            .collect();
+        assert!(!validation_result.passed());
+        assert!(contexts.contains(&"Bom.components[0].licenses[0].license.id".to_string()));
+    }
+
+    #[test]
+    fn it_should_fail_a_document_with_an_invalid_signature() {
+        let document = json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "version": 1,
+            "signature": { "algorithm": "RS256" }
+        });
+
+        let validation_result =
+            validate_json(&document, SpecVersion::V1_5).expect("Error while validating");
+
+        assert!(!validation_result.passed());
+        assert_eq!(
+            validation_result.reasons()[0].context,
+            ValidationContext(vec![ValidationPathComponent::Struct {
+                struct_name: "Bom".to_string(),
+                field_name: "signature".to_string(),
+            }])
+        );
+    }
+}
@@ -0,0 +1,341 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use serde_json::{Map, Value};
+
+use crate::errors::SignatureError;
+use crate::signature::{SigningKey, VerifyingKey};
+
+const SIGNATURE: &str = "signature";
+const ALGORITHM: &str = "algorithm";
+const VALUE: &str = "value";
+
+/// Sign a JSON document, adding a JSF signature to its `signature` property
+///
+/// The rest of the document is returned exactly as it was given.
+pub fn sign_json(document: &str, key: &SigningKey) -> Result<String, SignatureError> {
+    let mut value: Value = serde_json::from_str(document)?;
+    let object = value.as_object_mut().ok_or_else(|| {
+        SignatureError::InvalidDocument("the document is not a JSON object".to_string())
+    })?;
+    if object.contains_key(SIGNATURE) {
+        return Err(SignatureError::InvalidDocument(
+            "the document is already signed".to_string(),
+        ));
+    }
+
+    let mut signature = Map::new();
+    signature.insert(
+        ALGORITHM.to_string(),
+        Value::String(key.algorithm().to_string()),
+    );
+    object.insert(SIGNATURE.to_string(), Value::Object(signature.clone()));
+
+    let signature_value = key.sign(canonicalize(&value).as_bytes());
+    signature.insert(
+        VALUE.to_string(),
+        Value::String(base64::encode_config(
+            signature_value,
+            base64::URL_SAFE_NO_PAD,
+        )),
+    );
+
+    insert_signature(document, &Value::Object(signature))
+}
+
+/// Verify the JSF signature in the `signature` property of a JSON document
+pub fn verify_json(document: &str, key: &VerifyingKey) -> Result<(), SignatureError> {
+    let mut value: Value = serde_json::from_str(document)?;
+    let signature = value
+        .get_mut(SIGNATURE)
+        .and_then(Value::as_object_mut)
+        .ok_or(SignatureError::MissingSignature)?;
+
+    let algorithm = signature
+        .get(ALGORITHM)
+        .and_then(Value::as_str)
+        .ok_or(SignatureError::MissingSignature)?;
+    let expected = key.algorithm().to_string();
+    if algorithm != expected {
+        return Err(SignatureError::AlgorithmMismatch {
+            expected,
+            actual: algorithm.to_string(),
+        });
+    }
+
+    let signature_value = match signature.remove(VALUE) {
+        Some(Value::String(signature_value)) => {
+            base64::decode_config(signature_value, base64::URL_SAFE_NO_PAD)
+                .map_err(|_| SignatureError::InvalidSignature)?
+        }
+        _ => return Err(SignatureError::MissingSignature),
+    };
+
+    key.verify(canonicalize(&value).as_bytes(), &signature_value)
+}
+
+/// Adds the signature as the last property of the document's root object, leaving the rest of the
+/// document untouched
+fn insert_signature(document: &str, signature: &Value) -> Result<String, SignatureError> {
+    let trimmed = document.trim_end();
+    let body = trimmed
+        .strip_suffix('}')
+        .ok_or_else(|| {
+            SignatureError::InvalidDocument("the document is not a JSON object".to_string())
+        })?
+        .trim_end();
+    let separator = if body.ends_with('{') { "" } else { "," };
+    let signature = serde_json::to_string_pretty(signature)?.replace('\n', "\n  ");
+
+    Ok(format!(
+        "{}{}\n  \"{}\": {}\n}}{}",
+        body,
+        separator,
+        SIGNATURE,
+        signature,
+        &document[trimmed.len()..]
+    ))
+}
+
+/// Serialize the value with the JSON Canonicalization Scheme (RFC 8785)
+pub(crate) fn canonicalize(value: &Value) -> String {
+    let mut output = String::new();
+    write_canonical(value, &mut output);
+    output
+}
+
+fn write_canonical(value: &Value, output: &mut String) {
+    match value {
+        Value::Null => output.push_str("null"),
+        Value::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+        Value::Number(number) => {
+            output.push_str(&canonical_number(number.as_f64().unwrap_or_default()))
+        }
+        Value::String(string) => write_canonical_string(string, output),
+        Value::Array(values) => {
+            output.push('[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_canonical(value, output);
+            }
+            output.push(']');
+        }
+        Value::Object(object) => {
+            let mut entries: Vec<(&String, &Value)> = object.iter().collect();
+            entries.sort_by(|(left, _), (right, _)| left.encode_utf16().cmp(right.encode_utf16()));
+
+            output.push('{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_canonical_string(key, output);
+                output.push(':');
+                write_canonical(value, output);
+            }
+            output.push('}');
+        }
+    }
+}
+
+fn write_canonical_string(string: &str, output: &mut String) {
+    output.push('"');
+    for character in string.chars() {
+        match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\u{8}' => output.push_str("\\b"),
+            '\t' => output.push_str("\\t"),
+            '\n' => output.push_str("\\n"),
+            '\u{c}' => output.push_str("\\f"),
+            '\r' => output.push_str("\\r"),
+            control if control < ' ' => output.push_str(&format!("\\u{:04x}", control as u32)),
+            other => output.push(other),
+        }
+    }
+    output.push('"');
+}
+
+/// Formats the number as ECMAScript's `Number.prototype.toString` does
+fn canonical_number(value: f64) -> String {
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    // the shortest representation that round trips, e.g. `1.2345e3`
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let exponent: i32 = exponent.parse().unwrap_or_default();
+
+    let length = digits.len() as i32;
+    let point = exponent + 1;
+    let formatted = if length <= point && point <= 21 {
+        format!("{}{}", digits, "0".repeat((point - length) as usize))
+    } else if 0 < point && point <= 21 {
+        format!(
+            "{}.{}",
+            &digits[..point as usize],
+            &digits[point as usize..]
+        )
+    } else if -6 < point && point <= 0 {
+        format!("0.{}{}", "0".repeat(-point as usize), digits)
+    } else {
+        let sign = if exponent < 0 { '-' } else { '+' };
+        let fraction = if length > 1 {
+            format!(".{}", &digits[1..])
+        } else {
+            String::new()
+        };
+        format!("{}{}e{}{}", &digits[..1], fraction, sign, exponent.abs())
+    };
+
+    if value < 0.0 {
+        format!("-{}", formatted)
+    } else {
+        formatted
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::bom::Bom;
+    use crate::signature::test::signing_keys;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    // `333333333.33333329` is the literal of the RFC 8785 test vector, which has to be kept as
+    // written to show that it serializes as `333333333.3333333`
+    #[allow(clippy::excessive_precision)]
+    fn it_should_canonicalize_json() {
+        let value = json!({
+            "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001, -0.0, 100],
+            "string": "\u{20ac}$\u{000F}\u{000a}A'\u{0042}\u{0022}\u{005c}\\\"/",
+            "literals": [null, true, false],
+            "\u{20ac}": "Euro Sign",
+            "\r": "Carriage Return",
+            "1": "One"
+        });
+
+        assert_eq!(
+            canonicalize(&value),
+            r#"{"\r":"Carriage Return","1":"One","literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27,0,100],"string":"€$\u000f\nA'B\"\\\\\"/","€":"Euro Sign"}"#
+        );
+    }
+
+    #[test]
+    fn it_should_sign_and_verify_json() {
+        let document = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.5",
+  "version": 1
+}
+"#;
+
+        for key in signing_keys() {
+            let signed = sign_json(document, &key).expect("Failed to sign");
+            assert!(signed.starts_with(document.trim_end().trim_end_matches('}').trim_end()));
+            assert!(signed.ends_with("}\n"));
+
+            verify_json(&signed, &key.verifying_key()).expect("Failed to verify");
+
+            let tampered = signed.replace("\"version\": 1", "\"version\": 2");
+            assert!(matches!(
+                verify_json(&tampered, &key.verifying_key()),
+                Err(SignatureError::InvalidSignature)
+            ));
+        }
+    }
+
+    fn document(spec_version: &str) -> String {
+        format!(
+            r#"{{"bomFormat":"CycloneDX","specVersion":"{}","serialNumber":"urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79","version":1,"components":[{{"type":"library","name":"component","version":"1.0.0"}}]}}"#,
+            spec_version
+        )
+    }
+
+    #[test]
+    fn it_should_verify_after_a_round_trip_through_the_model() {
+        for key in signing_keys() {
+            let signed = sign_json(&document("1.4"), &key).expect("Failed to sign");
+            let bom = Bom::parse_from_json_v1_4(signed.as_bytes()).expect("Failed to parse");
+            let mut output = Vec::new();
+            bom.output_as_json_v1_4(&mut output)
+                .expect("Failed to output");
+            let output = String::from_utf8(output).expect("Failed to read output as a string");
+
+            assert_ne!(output, signed);
+            verify_json(&output, &key.verifying_key()).expect("Failed to verify");
+
+            let signed = sign_json(&document("1.5"), &key).expect("Failed to sign");
+            let bom = Bom::parse_from_json_v1_5(signed.as_bytes()).expect("Failed to parse");
+            let mut output = Vec::new();
+            bom.output_as_json_v1_5(&mut output)
+                .expect("Failed to output");
+            let output = String::from_utf8(output).expect("Failed to read output as a string");
+
+            assert_ne!(output, signed);
+            verify_json(&output, &key.verifying_key()).expect("Failed to verify");
+        }
+    }
+
+    #[test]
+    fn it_should_verify_after_a_round_trip_through_the_v1_3_model() {
+        for key in signing_keys() {
+            let signed = sign_json(&document("1.3"), &key).expect("Failed to sign");
+            let bom = Bom::parse_from_json_v1_3(signed.as_bytes()).expect("Failed to parse");
+            let mut output = Vec::new();
+            bom.output_as_json_v1_3(&mut output)
+                .expect("Failed to output");
+            let output = String::from_utf8(output).expect("Failed to read output as a string");
+
+            assert_ne!(output, signed);
+            verify_json(&output, &key.verifying_key()).expect("Failed to verify");
+
+            let tampered = output.replace("1.0.0", "1.0.1");
+            assert!(matches!(
+                verify_json(&tampered, &key.verifying_key()),
+                Err(SignatureError::InvalidSignature)
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_fail_to_verify_with_a_key_for_a_different_algorithm() {
+        let keys = signing_keys();
+        let signed = sign_json("{}", &keys[0]).expect("Failed to sign");
+
+        assert!(matches!(
+            verify_json(&signed, &keys[2].verifying_key()),
+            Err(SignatureError::AlgorithmMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_fail_to_verify_an_unsigned_document() {
+        let keys = signing_keys();
+
+        assert!(matches!(
+            verify_json("{}", &keys[0].verifying_key()),
+            Err(SignatureError::MissingSignature)
+        ));
+    }
+}
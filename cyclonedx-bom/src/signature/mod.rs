@@ -0,0 +1,259 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Enveloped signatures for JSON and XML documents
+//!
+//! This module is only available with the `signature` feature. JSON documents are signed with
+//! the [JSON Signature Format (JSF)](https://cyberphone.github.io/doc/security/jsf.html), placing
+//! the signature in the `signature` property of the BOM, and canonicalized with the
+//! [JSON Canonicalization Scheme](https://www.rfc-editor.org/rfc/rfc8785). XML documents are
+//! signed with an enveloped [XML Signature](https://www.w3.org/TR/xmldsig-core1/) using
+//! [Exclusive XML Canonicalization](https://www.w3.org/TR/xml-exc-c14n/).
+//!
+//! The supported algorithms are ES256, RS256 and Ed25519, and keys are read from PEM files.
+//!
+//! The signature of a JSON document covers its content rather than its formatting, so it still
+//! verifies after the document has been parsed into a [`Bom`](crate::models::bom::Bom) and written
+//! again. CycloneDX 1.3 has no `signature` property, but the signature of the whole document is
+//! carried along in 1.3 JSON documents as well. The model does not keep XML signatures, so a
+//! signed XML document has to be verified as it was received.
+//!
+//! ```rust
+//! use cyclonedx_bom::prelude::*;
+//! use cyclonedx_bom::signature::{sign_json, verify_json, SigningKey};
+//!
+//! # let key_path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/keys/ec-private.pem");
+//! let key = SigningKey::from_pem_file(key_path).expect("Failed to read key");
+//!
+//! let mut output = Vec::new();
+//! Bom::default()
+//!     .output_as_json_v1_5(&mut output)
+//!     .expect("Failed to write BOM");
+//! let document = String::from_utf8(output).expect("Failed to read output as a string");
+//!
+//! let signed = sign_json(&document, &key).expect("Failed to sign BOM");
+//! verify_json(&signed, &key.verifying_key()).expect("Failed to verify BOM");
+//! ```
+
+mod jsf;
+mod xmldsig;
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::path::Path;
+
+use ed25519_dalek::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+use rsa::signature::{Keypair, SignatureEncoding, Signer, Verifier};
+use sha2::Sha256;
+
+use crate::errors::SignatureError;
+use crate::models::signature::Algorithm;
+
+pub use self::jsf::{sign_json, verify_json};
+pub use self::xmldsig::{sign_xml, verify_xml};
+
+/// A private key used to sign documents
+pub struct SigningKey(SigningKeyInner);
+
+enum SigningKeyInner {
+    Es256(p256::ecdsa::SigningKey),
+    Rs256(rsa::pkcs1v15::SigningKey<Sha256>),
+    Ed25519(ed25519_dalek::SigningKey),
+}
+
+impl SigningKey {
+    /// Read a P-256, RSA or Ed25519 private key from a PEM file
+    pub fn from_pem_file(path: impl AsRef<Path>) -> Result<Self, SignatureError> {
+        Self::from_pem(&std::fs::read_to_string(path)?)
+    }
+
+    /// Read a P-256, RSA or Ed25519 private key in PKCS#8, SEC1 (P-256) or PKCS#1 (RSA) PEM
+    /// encoding
+    pub fn from_pem(pem: &str) -> Result<Self, SignatureError> {
+        let inner = if let Ok(key) = p256::ecdsa::SigningKey::from_pkcs8_pem(pem) {
+            SigningKeyInner::Es256(key)
+        } else if let Ok(key) = p256::SecretKey::from_sec1_pem(pem) {
+            SigningKeyInner::Es256(key.into())
+        } else if let Ok(key) = ed25519_dalek::SigningKey::from_pkcs8_pem(pem) {
+            SigningKeyInner::Ed25519(key)
+        } else if let Ok(key) = rsa::RsaPrivateKey::from_pkcs8_pem(pem)
+            .or_else(|_| rsa::RsaPrivateKey::from_pkcs1_pem(pem))
+        {
+            SigningKeyInner::Rs256(rsa::pkcs1v15::SigningKey::new(key))
+        } else {
+            return Err(SignatureError::UnsupportedKey);
+        };
+
+        Ok(Self(inner))
+    }
+
+    /// The algorithm that signatures created with this key use
+    pub fn algorithm(&self) -> Algorithm {
+        match &self.0 {
+            SigningKeyInner::Es256(_) => Algorithm::ES256,
+            SigningKeyInner::Rs256(_) => Algorithm::RS256,
+            SigningKeyInner::Ed25519(_) => Algorithm::Ed25519,
+        }
+    }
+
+    /// The public key that verifies signatures created with this key
+    pub fn verifying_key(&self) -> VerifyingKey {
+        let inner = match &self.0 {
+            SigningKeyInner::Es256(key) => VerifyingKeyInner::Es256(*key.verifying_key()),
+            SigningKeyInner::Rs256(key) => VerifyingKeyInner::Rs256(key.verifying_key()),
+            SigningKeyInner::Ed25519(key) => VerifyingKeyInner::Ed25519(key.verifying_key()),
+        };
+        VerifyingKey(inner)
+    }
+
+    pub(crate) fn sign(&self, message: &[u8]) -> Vec<u8> {
+        match &self.0 {
+            SigningKeyInner::Es256(key) => {
+                let signature: p256::ecdsa::Signature = key.sign(message);
+                signature.to_vec()
+            }
+            SigningKeyInner::Rs256(key) => key.sign(message).to_vec(),
+            SigningKeyInner::Ed25519(key) => key.sign(message).to_vec(),
+        }
+    }
+}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SigningKey")
+            .field(&self.algorithm().to_string())
+            .finish()
+    }
+}
+
+/// A public key used to verify signatures
+#[derive(Debug)]
+pub struct VerifyingKey(VerifyingKeyInner);
+
+#[derive(Debug)]
+enum VerifyingKeyInner {
+    Es256(p256::ecdsa::VerifyingKey),
+    Rs256(rsa::pkcs1v15::VerifyingKey<Sha256>),
+    Ed25519(ed25519_dalek::VerifyingKey),
+}
+
+impl VerifyingKey {
+    /// Read a P-256, RSA or Ed25519 public key from a PEM file
+    pub fn from_pem_file(path: impl AsRef<Path>) -> Result<Self, SignatureError> {
+        Self::from_pem(&std::fs::read_to_string(path)?)
+    }
+
+    /// Read a P-256, RSA or Ed25519 public key in SubjectPublicKeyInfo or PKCS#1 (RSA) PEM
+    /// encoding
+    pub fn from_pem(pem: &str) -> Result<Self, SignatureError> {
+        let inner = if let Ok(key) = p256::ecdsa::VerifyingKey::from_public_key_pem(pem) {
+            VerifyingKeyInner::Es256(key)
+        } else if let Ok(key) = ed25519_dalek::VerifyingKey::from_public_key_pem(pem) {
+            VerifyingKeyInner::Ed25519(key)
+        } else if let Ok(key) = rsa::RsaPublicKey::from_public_key_pem(pem)
+            .or_else(|_| rsa::RsaPublicKey::from_pkcs1_pem(pem))
+        {
+            VerifyingKeyInner::Rs256(rsa::pkcs1v15::VerifyingKey::new(key))
+        } else {
+            return Err(SignatureError::UnsupportedKey);
+        };
+
+        Ok(Self(inner))
+    }
+
+    /// The algorithm of the signatures that this key verifies
+    pub fn algorithm(&self) -> Algorithm {
+        match &self.0 {
+            VerifyingKeyInner::Es256(_) => Algorithm::ES256,
+            VerifyingKeyInner::Rs256(_) => Algorithm::RS256,
+            VerifyingKeyInner::Ed25519(_) => Algorithm::Ed25519,
+        }
+    }
+
+    pub(crate) fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), SignatureError> {
+        let verified = match &self.0 {
+            VerifyingKeyInner::Es256(key) => p256::ecdsa::Signature::from_slice(signature)
+                .and_then(|signature| key.verify(message, &signature)),
+            VerifyingKeyInner::Rs256(key) => rsa::pkcs1v15::Signature::try_from(signature)
+                .and_then(|signature| key.verify(message, &signature)),
+            VerifyingKeyInner::Ed25519(key) => ed25519_dalek::Signature::from_slice(signature)
+                .and_then(|signature| key.verify(message, &signature)),
+        };
+
+        verified.map_err(|_| SignatureError::InvalidSignature)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    pub(crate) fn key_path(name: &str) -> String {
+        format!(
+            "{}/tests/data/keys/{}.pem",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        )
+    }
+
+    pub(crate) fn signing_keys() -> Vec<SigningKey> {
+        ["ec-private", "rsa-private", "ed25519-private"]
+            .iter()
+            .map(|name| SigningKey::from_pem_file(key_path(name)).expect("Failed to read key"))
+            .collect()
+    }
+
+    #[test]
+    fn it_should_read_keys_of_each_algorithm() {
+        for (name, algorithm) in [
+            ("ec", Algorithm::ES256),
+            ("rsa", Algorithm::RS256),
+            ("ed25519", Algorithm::Ed25519),
+        ] {
+            let signing_key = SigningKey::from_pem_file(key_path(&format!("{}-private", name)))
+                .expect("Failed to read private key");
+            let verifying_key = VerifyingKey::from_pem_file(key_path(&format!("{}-public", name)))
+                .expect("Failed to read public key");
+
+            assert_eq!(signing_key.algorithm(), algorithm);
+            assert_eq!(verifying_key.algorithm(), algorithm);
+
+            let signature = signing_key.sign(b"message");
+            verifying_key
+                .verify(b"message", &signature)
+                .expect("Failed to verify the signature");
+            assert!(matches!(
+                verifying_key.verify(b"other message", &signature),
+                Err(SignatureError::InvalidSignature)
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_reject_a_key_that_is_not_pem() {
+        assert!(matches!(
+            SigningKey::from_pem("not a key"),
+            Err(SignatureError::UnsupportedKey)
+        ));
+        assert!(matches!(
+            VerifyingKey::from_pem("not a key"),
+            Err(SignatureError::UnsupportedKey)
+        ));
+    }
+}
@@ -0,0 +1,578 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use std::collections::BTreeMap;
+
+use sha2::{Digest, Sha256};
+use xml::attribute::OwnedAttribute;
+use xml::name::OwnedName;
+use xml::reader::XmlEvent;
+use xml::{EventReader, ParserConfig};
+
+use crate::errors::SignatureError;
+use crate::models::signature::Algorithm;
+use crate::signature::{SigningKey, VerifyingKey};
+
+const DSIG_NAMESPACE: &str = "http://www.w3.org/2000/09/xmldsig#";
+const EXCLUSIVE_C14N: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+const ENVELOPED_SIGNATURE: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+const SHA256: &str = "http://www.w3.org/2001/04/xmlenc#sha256";
+const RSA_SHA256: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256";
+const ECDSA_SHA256: &str = "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256";
+const EDDSA_ED25519: &str = "http://www.w3.org/2021/04/xmldsig-more#eddsa-ed25519";
+
+/// Sign an XML document, adding an enveloped XML Signature as the last child of its root element
+///
+/// The rest of the document is returned exactly as it was given. The signature covers the root
+/// element, canonicalized with Exclusive XML Canonicalization without comments.
+pub fn sign_xml(document: &str, key: &SigningKey) -> Result<String, SignatureError> {
+    let root = read_document(document)?;
+    if signature_element(&root).is_some() {
+        return Err(SignatureError::InvalidDocument(
+            "the document is already signed".to_string(),
+        ));
+    }
+
+    let algorithm = key.algorithm();
+    let signature_method = signature_method(&algorithm)
+        .ok_or_else(|| SignatureError::UnsupportedAlgorithm(algorithm.to_string()))?;
+    let digest = base64::encode(Sha256::digest(canonicalize(&root).as_bytes()));
+    let signed_info = format!(
+        concat!(
+            r#"<SignedInfo><CanonicalizationMethod Algorithm="{}"/><SignatureMethod Algorithm="{}"/>"#,
+            r#"<Reference URI=""><Transforms><Transform Algorithm="{}"/><Transform Algorithm="{}"/></Transforms>"#,
+            r#"<DigestMethod Algorithm="{}"/><DigestValue>{}</DigestValue></Reference></SignedInfo>"#
+        ),
+        EXCLUSIVE_C14N, signature_method, ENVELOPED_SIGNATURE, EXCLUSIVE_C14N, SHA256, digest
+    );
+
+    // the signed bytes are the canonical form of `SignedInfo`, exactly as a verifier will see it
+    let unsigned = read_document(&format!(
+        r#"<Signature xmlns="{}">{}</Signature>"#,
+        DSIG_NAMESPACE, signed_info
+    ))?;
+    let canonical_signed_info = child(&unsigned, "SignedInfo")
+        .map(canonicalize)
+        .ok_or_else(|| {
+            SignatureError::InvalidDocument("failed to create SignedInfo".to_string())
+        })?;
+    let signature_value = base64::encode(key.sign(canonical_signed_info.as_bytes()));
+
+    let signature = format!(
+        r#"<Signature xmlns="{}">{}<SignatureValue>{}</SignatureValue></Signature>"#,
+        DSIG_NAMESPACE, signed_info, signature_value
+    );
+
+    insert_signature(document, &root, &signature)
+}
+
+/// Verify the enveloped XML Signature on the root element of an XML document
+pub fn verify_xml(document: &str, key: &VerifyingKey) -> Result<(), SignatureError> {
+    let root = read_document(document)?;
+    let signature = signature_element(&root).ok_or(SignatureError::MissingSignature)?;
+
+    let signed_info = required_child(signature, "SignedInfo")?;
+    let signature_value = required_child(signature, "SignatureValue")?;
+
+    expect_algorithm(
+        required_child(signed_info, "CanonicalizationMethod")?,
+        EXCLUSIVE_C14N,
+    )?;
+
+    let signature_method = required_child(signed_info, "SignatureMethod")?;
+    let actual = attribute(signature_method, "Algorithm").unwrap_or_default();
+    let actual = algorithm(actual)
+        .ok_or_else(|| SignatureError::UnsupportedAlgorithm(actual.to_string()))?;
+    let expected = key.algorithm();
+    if actual != expected {
+        return Err(SignatureError::AlgorithmMismatch {
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        });
+    }
+
+    let reference = required_child(signed_info, "Reference")?;
+    if attribute(reference, "URI") != Some("") {
+        return Err(SignatureError::InvalidDocument(
+            "the signature does not reference the whole document".to_string(),
+        ));
+    }
+    if let Some(transforms) = child(reference, "Transforms") {
+        for transform in children(transforms, "Transform") {
+            let transform = attribute(transform, "Algorithm").unwrap_or_default();
+            if transform != ENVELOPED_SIGNATURE && transform != EXCLUSIVE_C14N {
+                return Err(SignatureError::UnsupportedAlgorithm(transform.to_string()));
+            }
+        }
+    }
+    expect_algorithm(required_child(reference, "DigestMethod")?, SHA256)?;
+
+    // the enveloped signature transform only removes the signature being verified, so any other
+    // signature stays part of the signed content
+    let digest = decode(&text(required_child(reference, "DigestValue")?))?;
+    if digest[..] != Sha256::digest(canonicalize_enveloping(&root, signature).as_bytes())[..] {
+        return Err(SignatureError::InvalidSignature);
+    }
+
+    key.verify(
+        canonicalize(signed_info).as_bytes(),
+        &decode(&text(signature_value))?,
+    )
+}
+
+/// The XML Signature algorithm identifier for a signature algorithm
+fn signature_method(algorithm: &Algorithm) -> Option<&'static str> {
+    match algorithm {
+        Algorithm::ES256 => Some(ECDSA_SHA256),
+        Algorithm::RS256 => Some(RSA_SHA256),
+        Algorithm::Ed25519 => Some(EDDSA_ED25519),
+        _ => None,
+    }
+}
+
+/// The signature algorithm for an XML Signature algorithm identifier
+fn algorithm(signature_method: &str) -> Option<Algorithm> {
+    match signature_method {
+        ECDSA_SHA256 => Some(Algorithm::ES256),
+        RSA_SHA256 => Some(Algorithm::RS256),
+        EDDSA_ED25519 => Some(Algorithm::Ed25519),
+        _ => None,
+    }
+}
+
+fn expect_algorithm(element: &Element, expected: &str) -> Result<(), SignatureError> {
+    match attribute(element, "Algorithm") {
+        Some(actual) if actual == expected => Ok(()),
+        actual => Err(SignatureError::UnsupportedAlgorithm(
+            actual.unwrap_or_default().to_string(),
+        )),
+    }
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, SignatureError> {
+    let value: String = value.split_whitespace().collect();
+    base64::decode(value).map_err(|_| SignatureError::InvalidSignature)
+}
+
+/// Adds the signature as the last child of the root element, leaving the rest of the document
+/// untouched
+fn insert_signature(
+    document: &str,
+    root: &Element,
+    signature: &str,
+) -> Result<String, SignatureError> {
+    let trimmed = document.trim_end();
+    let trailing = &document[trimmed.len()..];
+
+    if let Some(start_tag) = trimmed.strip_suffix("/>") {
+        return Ok(format!(
+            "{}>{}</{}>{}",
+            start_tag.trim_end(),
+            signature,
+            qualified_name(&root.name),
+            trailing
+        ));
+    }
+
+    match trimmed.rfind("</") {
+        Some(index) if trimmed[index + 2..].starts_with(&qualified_name(&root.name)) => {
+            Ok(format!(
+                "{}{}{}{}",
+                &trimmed[..index],
+                signature,
+                &trimmed[index..],
+                trailing
+            ))
+        }
+        _ => Err(SignatureError::InvalidDocument(
+            "the document must end with its root element".to_string(),
+        )),
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Element {
+    name: OwnedName,
+    attributes: Vec<OwnedAttribute>,
+    children: Vec<Node>,
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Element(Element),
+    Text(String),
+    ProcessingInstruction { name: String, data: Option<String> },
+}
+
+/// Read the root element of a document, keeping whitespace and dropping comments
+///
+/// Processing instructions outside the root element are not covered by the signature.
+fn read_document(document: &str) -> Result<Element, SignatureError> {
+    let config = ParserConfig::default()
+        .trim_whitespace(false)
+        .whitespace_to_characters(true)
+        .cdata_to_characters(true)
+        .ignore_comments(true)
+        .coalesce_characters(true);
+    let mut event_reader = EventReader::new_with_config(document.as_bytes(), config);
+    let mut open_elements: Vec<Element> = Vec::new();
+
+    loop {
+        match event_reader.next()? {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => open_elements.push(Element {
+                name,
+                attributes,
+                children: Vec::new(),
+            }),
+            XmlEvent::EndElement { .. } => {
+                let element = open_elements.pop().ok_or_else(|| {
+                    SignatureError::InvalidDocument("unbalanced elements".to_string())
+                })?;
+                match open_elements.last_mut() {
+                    Some(parent) => parent.children.push(Node::Element(element)),
+                    None => return Ok(element),
+                }
+            }
+            XmlEvent::Characters(text) => {
+                if let Some(parent) = open_elements.last_mut() {
+                    parent.children.push(Node::Text(text));
+                }
+            }
+            XmlEvent::ProcessingInstruction { name, data } => {
+                if let Some(parent) = open_elements.last_mut() {
+                    parent
+                        .children
+                        .push(Node::ProcessingInstruction { name, data });
+                }
+            }
+            XmlEvent::EndDocument => {
+                return Err(SignatureError::InvalidDocument(
+                    "the document has no root element".to_string(),
+                ))
+            }
+            _ => {}
+        }
+    }
+}
+
+fn qualified_name(name: &OwnedName) -> String {
+    name.borrow().to_repr()
+}
+
+fn is_signature(element: &Element) -> bool {
+    element.name.namespace_ref() == Some(DSIG_NAMESPACE) && element.name.local_name == "Signature"
+}
+
+fn signature_element(root: &Element) -> Option<&Element> {
+    root.children.iter().find_map(|node| match node {
+        Node::Element(element) if is_signature(element) => Some(element),
+        _ => None,
+    })
+}
+
+fn children<'a>(element: &'a Element, local_name: &'a str) -> impl Iterator<Item = &'a Element> {
+    element.children.iter().filter_map(move |node| match node {
+        Node::Element(child)
+            if child.name.namespace_ref() == Some(DSIG_NAMESPACE)
+                && child.name.local_name == local_name =>
+        {
+            Some(child)
+        }
+        _ => None,
+    })
+}
+
+fn child<'a>(element: &'a Element, local_name: &'a str) -> Option<&'a Element> {
+    children(element, local_name).next()
+}
+
+fn required_child<'a>(
+    element: &'a Element,
+    local_name: &'a str,
+) -> Result<&'a Element, SignatureError> {
+    child(element, local_name).ok_or_else(|| {
+        SignatureError::InvalidDocument(format!(
+            "{} has no {} element",
+            element.name.local_name, local_name
+        ))
+    })
+}
+
+fn attribute<'a>(element: &'a Element, local_name: &str) -> Option<&'a str> {
+    element
+        .attributes
+        .iter()
+        .find(|attribute| {
+            attribute.name.namespace.is_none() && attribute.name.local_name == local_name
+        })
+        .map(|attribute| attribute.value.as_str())
+}
+
+fn text(element: &Element) -> String {
+    element
+        .children
+        .iter()
+        .filter_map(|node| match node {
+            Node::Text(text) => Some(text.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Serialize an element with Exclusive XML Canonicalization
+fn canonicalize(element: &Element) -> String {
+    let mut output = String::new();
+    write_canonical(element, &BTreeMap::new(), None, &mut output);
+    output
+}
+
+/// Serialize an element with Exclusive XML Canonicalization, leaving out the given enveloped
+/// signature
+fn canonicalize_enveloping(element: &Element, signature: &Element) -> String {
+    let mut output = String::new();
+    write_canonical(element, &BTreeMap::new(), Some(signature), &mut output);
+    output
+}
+
+/// Writes the element, declaring only the namespaces that it visibly uses and that no output
+/// ancestor has already declared with the same value
+fn write_canonical(
+    element: &Element,
+    rendered: &BTreeMap<Option<String>, String>,
+    enveloped: Option<&Element>,
+    output: &mut String,
+) {
+    let mut utilized = BTreeMap::new();
+    utilized.insert(
+        element.name.prefix.clone(),
+        element.name.namespace.clone().unwrap_or_default(),
+    );
+    for attribute in &element.attributes {
+        if let Some(prefix) = &attribute.name.prefix {
+            if prefix != "xml" {
+                utilized.insert(
+                    Some(prefix.clone()),
+                    attribute.name.namespace.clone().unwrap_or_default(),
+                );
+            }
+        }
+    }
+
+    let mut rendered = rendered.clone();
+    output.push('<');
+    output.push_str(&qualified_name(&element.name));
+    for (prefix, namespace) in utilized {
+        if rendered
+            .get(&prefix)
+            .map(String::as_str)
+            .unwrap_or_default()
+            == namespace
+        {
+            continue;
+        }
+        match &prefix {
+            Some(prefix) => output.push_str(&format!(" xmlns:{}=\"", prefix)),
+            None => output.push_str(" xmlns=\""),
+        }
+        output.push_str(&escape_attribute(&namespace));
+        output.push('"');
+        rendered.insert(prefix, namespace);
+    }
+
+    let mut attributes: Vec<&OwnedAttribute> = element.attributes.iter().collect();
+    attributes.sort_by(|left, right| {
+        (left.name.namespace_ref(), &left.name.local_name)
+            .cmp(&(right.name.namespace_ref(), &right.name.local_name))
+    });
+    for attribute in attributes {
+        output.push_str(&format!(
+            " {}=\"{}\"",
+            qualified_name(&attribute.name),
+            escape_attribute(&attribute.value)
+        ));
+    }
+    output.push('>');
+
+    for node in &element.children {
+        match node {
+            Node::Element(child)
+                if enveloped.map_or(false, |enveloped| std::ptr::eq(enveloped, child)) => {}
+            Node::Element(child) => write_canonical(child, &rendered, enveloped, output),
+            Node::Text(text) => output.push_str(&escape_text(text)),
+            Node::ProcessingInstruction { name, data } => match data {
+                Some(data) => output.push_str(&format!("<?{} {}?>", name, data)),
+                None => output.push_str(&format!("<?{}?>", name)),
+            },
+        }
+    }
+
+    output.push_str(&format!("</{}>", qualified_name(&element.name)));
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\r', "&#xD;")
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+        .replace('\t', "&#x9;")
+        .replace('\n', "&#xA;")
+        .replace('\r', "&#xD;")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::bom::Bom;
+    use crate::signature::test::signing_keys;
+    use pretty_assertions::assert_eq;
+
+    const DOCUMENT: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" serialNumber="urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79" version="1">
+  <components>
+    <component type="library">
+      <name>component</name>
+      <version>1.0.0</version>
+    </component>
+  </components>
+</bom>
+"#;
+
+    #[test]
+    fn it_should_canonicalize_xml() {
+        let root = read_document(
+            r#"<?xml version="1.0"?>
+<!-- comment -->
+<a:root xmlns:a="urn:a" xmlns:b="urn:b" xmlns:unused="urn:unused" z="1" b:y="2" a:x="3">
+  <child xmlns="urn:c" attribute='"&amp;&#9;'/><!-- comment -->
+  <a:child><![CDATA[<text> & more]]>&#13;</a:child>
+  <b:child xmlns:a="urn:a"><?target data?></b:child>
+</a:root>"#,
+        )
+        .expect("Failed to read document");
+
+        assert_eq!(
+            canonicalize(&root),
+            r#"<a:root xmlns:a="urn:a" xmlns:b="urn:b" z="1" a:x="3" b:y="2">
+  <child xmlns="urn:c" attribute="&quot;&amp;&#x9;"></child>
+  <a:child>&lt;text&gt; &amp; more&#xD;</a:child>
+  <b:child><?target data?></b:child>
+</a:root>"#
+        );
+    }
+
+    #[test]
+    fn it_should_sign_and_verify_xml() {
+        for key in signing_keys() {
+            let signed = sign_xml(DOCUMENT, &key).expect("Failed to sign");
+            assert!(signed.starts_with(DOCUMENT.trim_end().trim_end_matches("</bom>")));
+            assert!(signed.ends_with("</Signature></bom>\n"));
+
+            verify_xml(&signed, &key.verifying_key()).expect("Failed to verify");
+
+            let tampered = signed.replace("1.0.0", "1.0.1");
+            assert!(matches!(
+                verify_xml(&tampered, &key.verifying_key()),
+                Err(SignatureError::InvalidSignature)
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_fail_to_verify_a_document_with_an_injected_signature() {
+        for key in signing_keys() {
+            let signed = sign_xml(DOCUMENT, &key).expect("Failed to sign");
+
+            let injected = signed.replace(
+                "</Signature></bom>",
+                r#"</Signature><ds:Signature xmlns:ds="http://www.w3.org/2000/09/xmldsig#"/></bom>"#,
+            );
+
+            assert_ne!(injected, signed);
+            assert!(matches!(
+                verify_xml(&injected, &key.verifying_key()),
+                Err(SignatureError::InvalidSignature)
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_verify_a_reformatted_document() {
+        for key in signing_keys() {
+            let signed = sign_xml(DOCUMENT, &key).expect("Failed to sign");
+
+            let reformatted = signed
+                .replace(r#"<?xml version="1.0" encoding="utf-8"?>"#, "")
+                .replace(
+                    r#"serialNumber="urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79" version="1""#,
+                    r#"version='1' serialNumber='urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79'"#,
+                )
+                .replace(
+                    "<name>component</name>",
+                    "<name><![CDATA[component]]></name>",
+                );
+
+            assert_ne!(reformatted, signed);
+            verify_xml(&reformatted, &key.verifying_key()).expect("Failed to verify");
+        }
+    }
+
+    #[test]
+    fn it_should_sign_an_empty_root_element() {
+        let mut output = Vec::new();
+        Bom::default()
+            .output_as_xml_v1_5(&mut output)
+            .expect("Failed to output");
+        let document = String::from_utf8(output).expect("Failed to read output as a string");
+
+        for key in signing_keys() {
+            let signed = sign_xml(&document, &key).expect("Failed to sign");
+
+            verify_xml(&signed, &key.verifying_key()).expect("Failed to verify");
+            Bom::parse_from_xml_v1_5(signed.as_bytes()).expect("Failed to parse signed document");
+        }
+    }
+
+    #[test]
+    fn it_should_fail_to_verify_with_a_key_for_a_different_algorithm() {
+        let keys = signing_keys();
+        let signed = sign_xml(DOCUMENT, &keys[0]).expect("Failed to sign");
+
+        assert!(matches!(
+            verify_xml(&signed, &keys[2].verifying_key()),
+            Err(SignatureError::AlgorithmMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_fail_to_verify_an_unsigned_document() {
+        let keys = signing_keys();
+
+        assert!(matches!(
+            verify_xml(DOCUMENT, &keys[0].verifying_key()),
+            Err(SignatureError::MissingSignature)
+        ));
+    }
+}
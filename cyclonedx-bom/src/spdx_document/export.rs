@@ -0,0 +1,924 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use time::{
+    format_description::well_known::{Iso8601, Rfc3339},
+    OffsetDateTime, UtcOffset,
+};
+
+use crate::conversion::{ConversionLoss, ConversionLossKind};
+use crate::models::{
+    attached_text::{AttachedText, Encoding},
+    bom::{Bom, UrnUuid},
+    component::{Component, Components},
+    dependency::Dependencies,
+    external_reference::{ExternalReferenceType, ExternalReferences},
+    hash::Hashes,
+    license::{LicenseChoice, LicenseIdentifier, Licenses},
+    metadata::Metadata,
+    organization::OrganizationalEntity,
+    service::Services,
+    tool::Tools,
+};
+use crate::spdx_document::{
+    sanitize_id, Checksum, CreationInfo, ExternalRef, ExtractedLicensingInfo, Package,
+    Relationship, SpdxDocument, CHECKSUM_ALGORITHMS, DATA_LICENSE, DOCUMENT_SPDX_ID, NOASSERTION,
+    PACKAGE_PURPOSES, SPDX_VERSION,
+};
+
+/// Convert the BOM into an SPDX document, returning every field that SPDX cannot represent
+///
+/// The document namespace is the serial number of the BOM and the creation time is the timestamp
+/// of its metadata, so that the same BOM is always converted to the same document. A BOM without
+/// either gets a random namespace or the current time, as SPDX requires both.
+pub(crate) fn export(bom: Bom) -> (SpdxDocument, Vec<ConversionLoss>) {
+    let mut export = Export::default();
+    let document = export.document(bom);
+    (document, export.losses)
+}
+
+#[derive(Default)]
+struct Export {
+    packages: Vec<Package>,
+    relationships: Vec<Relationship>,
+    extracted_licensing_infos: Vec<ExtractedLicensingInfo>,
+    losses: Vec<ConversionLoss>,
+    /// Every SPDX identifier that has been assigned, to keep them unique
+    spdx_ids: HashSet<String>,
+    /// The SPDX identifiers of the packages, by the bom-ref of their component
+    packages_by_bom_ref: HashMap<String, String>,
+    /// The `LicenseRef-` identifiers of the licenses without an SPDX identifier, by name
+    license_refs: HashMap<String, String>,
+}
+
+impl Export {
+    fn drop<T>(&mut self, field: &Option<T>, path: String) {
+        if field.is_some() {
+            self.losses.push(ConversionLoss {
+                path,
+                kind: ConversionLossKind::Dropped,
+            });
+        }
+    }
+
+    fn rewrite(&mut self, path: String, from: String, to: String) {
+        self.losses.push(ConversionLoss {
+            path,
+            kind: ConversionLossKind::Rewritten { from, to },
+        });
+    }
+
+    fn unique_id(&mut self, prefix: &str, name: &str) -> String {
+        let id = format!("{}-{}", prefix, sanitize_id(name));
+        let mut unique = id.clone();
+        let mut counter = 1;
+        while !self.spdx_ids.insert(unique.clone()) {
+            counter += 1;
+            unique = format!("{}-{}", id, counter);
+        }
+        unique
+    }
+
+    fn document(&mut self, bom: Bom) -> SpdxDocument {
+        let document_namespace = bom
+            .serial_number
+            .unwrap_or_else(UrnUuid::generate)
+            .to_string();
+
+        let mut name = "CycloneDX BOM".to_string();
+        let mut created = None;
+        let mut creators = Vec::new();
+        let mut has_root = false;
+        if let Some(metadata) = bom.metadata {
+            if let Some(component) = &metadata.component {
+                name = component.name.to_string();
+                has_root = true;
+            }
+            created = metadata.timestamp.as_ref().map(|t| t.to_string());
+            creators = self.creators(metadata);
+        }
+        if creators.is_empty() {
+            creators.push(format!("Tool: cyclonedx-bom-{}", env!("CARGO_PKG_VERSION")));
+        }
+
+        // without a component in the metadata, the document describes all of the components
+        let describes = if has_root {
+            None
+        } else {
+            Some((DOCUMENT_SPDX_ID, "DESCRIBES"))
+        };
+        self.components(bom.components, "components", describes);
+
+        let created = self.created(created);
+
+        self.dependencies(bom.dependencies);
+
+        self.drop(&bom.services, "services".to_string());
+        self.drop(&bom.external_references, "externalReferences".to_string());
+        self.drop(&bom.compositions, "compositions".to_string());
+        self.drop(&bom.properties, "properties".to_string());
+        self.drop(&bom.vulnerabilities, "vulnerabilities".to_string());
+        self.drop(&bom.annotations, "annotations".to_string());
+        self.drop(&bom.formulation, "formulation".to_string());
+        self.drop(&bom.signature, "signature".to_string());
+
+        SpdxDocument {
+            spdx_version: SPDX_VERSION.to_string(),
+            data_license: DATA_LICENSE.to_string(),
+            spdx_id: DOCUMENT_SPDX_ID.to_string(),
+            name,
+            document_namespace,
+            creation_info: CreationInfo {
+                created,
+                creators,
+                unmapped: BTreeMap::new(),
+            },
+            packages: std::mem::take(&mut self.packages),
+            has_extracted_licensing_infos: std::mem::take(&mut self.extracted_licensing_infos),
+            relationships: std::mem::take(&mut self.relationships),
+            unmapped: BTreeMap::new(),
+        }
+    }
+
+    /// SPDX requires the creation time in UTC and without fractional seconds
+    fn created(&mut self, timestamp: Option<String>) -> String {
+        let parsed = timestamp
+            .as_ref()
+            .map(|t| OffsetDateTime::parse(t, &Iso8601::DEFAULT));
+        let date_time = match parsed {
+            Some(Ok(date_time)) => date_time,
+            _ => OffsetDateTime::now_utc(),
+        };
+
+        let created = date_time
+            .to_offset(UtcOffset::UTC)
+            .replace_nanosecond(0)
+            .ok()
+            .and_then(|date_time| date_time.format(&Rfc3339).ok())
+            .unwrap_or_default();
+
+        if let (Some(timestamp), Some(Err(_))) = (timestamp, parsed) {
+            self.rewrite("metadata.timestamp".to_string(), timestamp, created.clone());
+        }
+
+        created
+    }
+
+    /// Tools that are components or services only keep their name and version as creators
+    fn tool_creators(
+        &mut self,
+        components: Option<Components>,
+        services: Option<Services>,
+        creators: &mut Vec<String>,
+    ) {
+        let components = components.map(|c| c.0).unwrap_or_default();
+        for (index, component) in components.into_iter().enumerate() {
+            creators.push(format!(
+                "Tool: {}-{}",
+                component.name.0, component.version.0
+            ));
+            self.rewrite(
+                format!("metadata.tools.components[{index}]"),
+                "component".to_string(),
+                "creator".to_string(),
+            );
+        }
+
+        let services = services.map(|s| s.0).unwrap_or_default();
+        for (index, service) in services.into_iter().enumerate() {
+            match &service.version {
+                Some(version) => creators.push(format!("Tool: {}-{}", service.name.0, version.0)),
+                None => creators.push(format!("Tool: {}", service.name.0)),
+            }
+            self.rewrite(
+                format!("metadata.tools.services[{index}]"),
+                "service".to_string(),
+                "creator".to_string(),
+            );
+        }
+    }
+
+    fn creators(&mut self, metadata: Metadata) -> Vec<String> {
+        let mut creators = Vec::new();
+
+        let tools = match metadata.tools {
+            Some(Tools::Object {
+                components,
+                services,
+            }) => {
+                self.tool_creators(components, services, &mut creators);
+                Vec::new()
+            }
+            Some(Tools::List(tools)) => tools,
+            None => Vec::new(),
+        };
+
+        for (index, tool) in tools.into_iter().enumerate() {
+            let path = format!("metadata.tools[{index}]");
+            match (&tool.name, &tool.version) {
+                (Some(name), Some(version)) => {
+                    creators.push(format!("Tool: {}-{}", name.0, version.0))
+                }
+                (Some(name), None) => creators.push(format!("Tool: {}", name.0)),
+                (None, _) => {
+                    self.losses.push(ConversionLoss {
+                        path,
+                        kind: ConversionLossKind::Dropped,
+                    });
+                    continue;
+                }
+            }
+            self.drop(&tool.vendor, format!("{path}.vendor"));
+            self.drop(&tool.hashes, format!("{path}.hashes"));
+            self.drop(
+                &tool.external_references,
+                format!("{path}.externalReferences"),
+            );
+        }
+
+        for (index, author) in metadata.authors.into_iter().flatten().enumerate() {
+            let path = format!("metadata.authors[{index}]");
+            match (&author.name, &author.email) {
+                (Some(name), Some(email)) => {
+                    creators.push(format!("Person: {} ({})", name.0, email.0))
+                }
+                (Some(name), None) => creators.push(format!("Person: {}", name.0)),
+                (None, _) => {
+                    self.losses.push(ConversionLoss {
+                        path,
+                        kind: ConversionLossKind::Dropped,
+                    });
+                    continue;
+                }
+            }
+            self.drop(&author.phone, format!("{path}.phone"));
+        }
+
+        if let Some(manufacture) = metadata.manufacture {
+            if let Some(name) = self.organization(manufacture, "metadata.manufacture") {
+                creators.push(name);
+            }
+        }
+
+        if let Some(component) = metadata.component {
+            self.component(
+                component,
+                "metadata.component",
+                Some((DOCUMENT_SPDX_ID, "DESCRIBES")),
+            );
+        }
+
+        self.drop(&metadata.lifecycles, "metadata.lifecycles".to_string());
+        self.drop(&metadata.supplier, "metadata.supplier".to_string());
+        self.drop(&metadata.licenses, "metadata.licenses".to_string());
+        self.drop(&metadata.properties, "metadata.properties".to_string());
+
+        creators
+    }
+
+    /// Format an organization as an SPDX `Organization:` actor, which only has room for its name
+    fn organization(&mut self, organization: OrganizationalEntity, path: &str) -> Option<String> {
+        self.drop(&organization.url, format!("{path}.url"));
+        self.drop(&organization.contact, format!("{path}.contact"));
+
+        match organization.name {
+            Some(name) => Some(format!("Organization: {}", name.0)),
+            None => {
+                self.losses.push(ConversionLoss {
+                    path: path.to_string(),
+                    kind: ConversionLossKind::Dropped,
+                });
+                None
+            }
+        }
+    }
+
+    fn components(
+        &mut self,
+        components: Option<Components>,
+        path: &str,
+        relationship: Option<(&str, &str)>,
+    ) {
+        for (index, component) in components.into_iter().flat_map(|c| c.0).enumerate() {
+            self.component(component, &format!("{path}[{index}]"), relationship);
+        }
+    }
+
+    /// Add the component and its nested components as packages, with the given element and
+    /// relationship type pointing at the component
+    fn component(&mut self, component: Component, path: &str, relationship: Option<(&str, &str)>) {
+        let package_name = if component.version.0.is_empty() {
+            component.name.0.clone()
+        } else {
+            format!("{}-{}", component.name.0, component.version.0)
+        };
+        let spdx_id = self.unique_id("SPDXRef-Package", &package_name);
+        if let Some(bom_ref) = &component.bom_ref {
+            self.packages_by_bom_ref
+                .insert(bom_ref.clone(), spdx_id.clone());
+        }
+
+        let component_type = component.component_type.to_string();
+        let primary_package_purpose = PACKAGE_PURPOSES
+            .iter()
+            .find(|(cyclonedx, _)| *cyclonedx == component_type)
+            .map(|(_, spdx)| spdx.to_string())
+            .unwrap_or_else(|| {
+                self.rewrite(format!("{path}.type"), component_type, "OTHER".to_string());
+                "OTHER".to_string()
+            });
+
+        let supplier = component
+            .supplier
+            .and_then(|s| self.organization(s, &format!("{path}.supplier")));
+
+        let mut external_refs = Vec::new();
+        if let Some(purl) = component.purl {
+            external_refs.push(ExternalRef {
+                reference_category: "PACKAGE-MANAGER".to_string(),
+                reference_type: "purl".to_string(),
+                reference_locator: purl.to_string(),
+                unmapped: BTreeMap::new(),
+            });
+        }
+        if let Some(cpe) = component.cpe {
+            let reference_type = if cpe.0.starts_with("cpe:2.3:") {
+                "cpe23Type"
+            } else {
+                "cpe22Type"
+            };
+            external_refs.push(ExternalRef {
+                reference_category: "SECURITY".to_string(),
+                reference_type: reference_type.to_string(),
+                reference_locator: cpe.0,
+                unmapped: BTreeMap::new(),
+            });
+        }
+        let (homepage, download_location) = self.external_references(
+            component.external_references,
+            &format!("{path}.externalReferences"),
+            &mut external_refs,
+        );
+
+        let checksums = self.checksums(component.hashes, &format!("{path}.hashes"));
+        let license_declared =
+            self.license_expression(component.licenses, &format!("{path}.licenses"));
+
+        self.drop(&component.mime_type, format!("{path}.mimeType"));
+        self.drop(&component.publisher, format!("{path}.publisher"));
+        self.drop(&component.group, format!("{path}.group"));
+        self.drop(&component.scope, format!("{path}.scope"));
+        self.drop(&component.swid, format!("{path}.swid"));
+        self.drop(&component.modified, format!("{path}.modified"));
+        self.drop(&component.pedigree, format!("{path}.pedigree"));
+        self.drop(&component.properties, format!("{path}.properties"));
+        self.drop(&component.evidence, format!("{path}.evidence"));
+        self.drop(&component.release_notes, format!("{path}.releaseNotes"));
+        self.drop(&component.model_card, format!("{path}.modelCard"));
+        self.drop(&component.data, format!("{path}.data"));
+        self.drop(&component.signature, format!("{path}.signature"));
+
+        let version = component.version.to_string();
+        self.packages.push(Package {
+            spdx_id: spdx_id.clone(),
+            name: component.name.to_string(),
+            version_info: Some(version).filter(|v| !v.is_empty()),
+            supplier,
+            originator: component.author.map(|a| format!("Person: {}", a.0)),
+            download_location: download_location.unwrap_or_else(|| NOASSERTION.to_string()),
+            files_analyzed: false,
+            checksums,
+            homepage,
+            license_concluded: NOASSERTION.to_string(),
+            license_declared,
+            copyright_text: component
+                .copyright
+                .map(|c| c.to_string())
+                .unwrap_or_else(|| NOASSERTION.to_string()),
+            description: component.description.map(|d| d.to_string()),
+            external_refs,
+            primary_package_purpose: Some(primary_package_purpose),
+            unmapped: BTreeMap::new(),
+        });
+
+        if let Some((spdx_element_id, relationship_type)) = relationship {
+            self.relationships.push(Relationship {
+                spdx_element_id: spdx_element_id.to_string(),
+                relationship_type: relationship_type.to_string(),
+                related_spdx_element: spdx_id.clone(),
+                unmapped: BTreeMap::new(),
+            });
+        }
+
+        self.components(
+            component.components,
+            &format!("{path}.components"),
+            Some((&spdx_id, "CONTAINS")),
+        );
+    }
+
+    fn checksums(&mut self, hashes: Option<Hashes>, path: &str) -> Vec<Checksum> {
+        let mut checksums = Vec::new();
+
+        for (index, hash) in hashes.into_iter().flat_map(|h| h.0).enumerate() {
+            let alg = hash.alg.to_string();
+            match CHECKSUM_ALGORITHMS
+                .iter()
+                .find(|(cyclonedx, _)| *cyclonedx == alg)
+            {
+                Some((_, algorithm)) => checksums.push(Checksum {
+                    algorithm: algorithm.to_string(),
+                    checksum_value: hash.content.0,
+                }),
+                None => self.losses.push(ConversionLoss {
+                    path: format!("{path}[{index}]"),
+                    kind: ConversionLossKind::Dropped,
+                }),
+            }
+        }
+
+        checksums
+    }
+
+    /// Map the first website to the homepage and the first distribution to the download location
+    /// of the package, and every other reference to an external reference of the `OTHER` category
+    fn external_references(
+        &mut self,
+        external_references: Option<ExternalReferences>,
+        path: &str,
+        external_refs: &mut Vec<ExternalRef>,
+    ) -> (Option<String>, Option<String>) {
+        let mut homepage = None;
+        let mut download_location = None;
+
+        for (index, external_reference) in external_references
+            .into_iter()
+            .flat_map(|e| e.0)
+            .enumerate()
+        {
+            let path = format!("{path}[{index}]");
+            let url = external_reference.url.to_string();
+            match external_reference.external_reference_type {
+                ExternalReferenceType::Website if homepage.is_none() => homepage = Some(url),
+                ExternalReferenceType::Distribution if download_location.is_none() => {
+                    download_location = Some(url)
+                }
+                reference_type => external_refs.push(ExternalRef {
+                    reference_category: "OTHER".to_string(),
+                    reference_type: reference_type.to_string(),
+                    reference_locator: url,
+                    unmapped: BTreeMap::new(),
+                }),
+            }
+            self.drop(&external_reference.comment, format!("{path}.comment"));
+            self.drop(&external_reference.hashes, format!("{path}.hashes"));
+        }
+
+        (homepage, download_location)
+    }
+
+    /// Combine the license choices into a single expression, in which all of them apply
+    fn license_expression(&mut self, licenses: Option<Licenses>, path: &str) -> String {
+        let mut expressions = Vec::new();
+
+        for (index, license) in licenses.into_iter().flat_map(|l| l.0).enumerate() {
+            let path = format!("{path}[{index}]");
+            match license {
+                LicenseChoice::Expression(expression) => expressions.push(expression.0),
+                LicenseChoice::License(license) => match license.license_identifier {
+                    LicenseIdentifier::SpdxId(id) => {
+                        self.drop(&license.text, format!("{path}.license.text"));
+                        self.drop(&license.url, format!("{path}.license.url"));
+                        expressions.push(id.0);
+                    }
+                    LicenseIdentifier::Name(name) => {
+                        let name = name.to_string();
+                        let license_id = match self.license_refs.get(&name) {
+                            Some(license_id) => license_id.clone(),
+                            None => {
+                                let license_id = self.unique_id("LicenseRef", &name);
+                                self.license_refs.insert(name.clone(), license_id.clone());
+                                self.extracted_licensing_infos.push(ExtractedLicensingInfo {
+                                    license_id: license_id.clone(),
+                                    extracted_text: license
+                                        .text
+                                        .map(decode_text)
+                                        .unwrap_or_else(|| name.clone()),
+                                    name,
+                                    see_alsos: license.url.into_iter().map(|u| u.0).collect(),
+                                    unmapped: BTreeMap::new(),
+                                });
+                                license_id
+                            }
+                        };
+                        expressions.push(license_id);
+                    }
+                },
+            }
+        }
+
+        match expressions.len() {
+            0 => NOASSERTION.to_string(),
+            1 => expressions.remove(0),
+            _ => expressions
+                .into_iter()
+                .map(|e| {
+                    if e.contains(' ') {
+                        format!("({})", e)
+                    } else {
+                        e
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" AND "),
+        }
+    }
+
+    fn dependencies(&mut self, dependencies: Option<Dependencies>) {
+        for (index, dependency) in dependencies.into_iter().flat_map(|d| d.0).enumerate() {
+            let path = format!("dependencies[{index}]");
+            let spdx_id = match self.packages_by_bom_ref.get(&dependency.dependency_ref) {
+                Some(spdx_id) => spdx_id.clone(),
+                None => {
+                    self.losses.push(ConversionLoss {
+                        path,
+                        kind: ConversionLossKind::Dropped,
+                    });
+                    continue;
+                }
+            };
+
+            for (depends_on_index, depends_on) in dependency.dependencies.iter().enumerate() {
+                match self.packages_by_bom_ref.get(depends_on) {
+                    Some(related_spdx_element) => self.relationships.push(Relationship {
+                        spdx_element_id: spdx_id.clone(),
+                        relationship_type: "DEPENDS_ON".to_string(),
+                        related_spdx_element: related_spdx_element.clone(),
+                        unmapped: BTreeMap::new(),
+                    }),
+                    None => self.losses.push(ConversionLoss {
+                        path: format!("{path}.dependsOn[{depends_on_index}]"),
+                        kind: ConversionLossKind::Dropped,
+                    }),
+                }
+            }
+        }
+    }
+}
+
+/// SPDX extracted texts are plain text, so base64 encoded license texts are decoded
+fn decode_text(text: AttachedText) -> String {
+    match text.encoding {
+        Some(Encoding::Base64) => base64::decode(&text.content)
+            .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+            .unwrap_or(text.content),
+        _ => text.content,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::external_models::{
+        date_time::DateTime, normalized_string::NormalizedString, spdx::SpdxExpression, uri::Purl,
+        uri::Uri,
+    };
+    use crate::models::{
+        component::Classification,
+        dependency::Dependency,
+        external_reference::ExternalReference,
+        hash::{Hash, HashAlgorithm, HashValue},
+        license::License,
+        organization::OrganizationalContact,
+        service::{Service, Services},
+        tool::{Tool, Tools},
+    };
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn example_bom() -> Bom {
+        let mut root = Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("app-1".to_string()),
+        );
+        root.licenses = Some(Licenses(vec![
+            LicenseChoice::Expression(SpdxExpression("MIT OR Apache-2.0".to_string())),
+            LicenseChoice::License(License::named_license("Custom License")),
+        ]));
+
+        let mut library = Component::new(
+            Classification::Library,
+            "lib",
+            "2.0.0",
+            Some("lib-2".to_string()),
+        );
+        library.purl = Some(Purl("pkg:cargo/lib@2.0.0".to_string()));
+        library.hashes = Some(Hashes(vec![
+            Hash {
+                alg: HashAlgorithm::SHA256,
+                content: HashValue("abcdef".to_string()),
+            },
+            Hash {
+                alg: HashAlgorithm::UnknownHashAlgorithm("CRC32".to_string()),
+                content: HashValue("1234".to_string()),
+            },
+        ]));
+        library.external_references = Some(ExternalReferences(vec![ExternalReference::new(
+            ExternalReferenceType::Website,
+            Uri("https://example.com".to_string()),
+        )]));
+        library.group = Some(NormalizedString::new("group"));
+        library.components = Some(Components(vec![Component::new(
+            Classification::Platform,
+            "platform",
+            "",
+            None,
+        )]));
+
+        Bom {
+            serial_number: Some(UrnUuid(
+                "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string(),
+            )),
+            metadata: Some(Metadata {
+                timestamp: Some(DateTime("2023-11-14T23:13:20.5+01:00".to_string())),
+                tools: Some(Tools::List(vec![Tool::new(
+                    "CycloneDX",
+                    "cargo-cyclonedx",
+                    "0.4.0",
+                )])),
+                authors: Some(vec![OrganizationalContact::new(
+                    "Jane Doe",
+                    Some("jane@example.com"),
+                )]),
+                component: Some(root),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![library])),
+            services: Some(Services(vec![Service::new("service", None)])),
+            dependencies: Some(Dependencies(vec![
+                Dependency {
+                    dependency_ref: "app-1".to_string(),
+                    dependencies: vec!["lib-2".to_string(), "service-1".to_string()],
+                },
+                Dependency {
+                    dependency_ref: "service-1".to_string(),
+                    dependencies: vec![],
+                },
+            ])),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_export_a_bom_as_spdx() {
+        let (document, _) = export(example_bom());
+
+        assert_eq!(
+            serde_json::to_value(document).expect("Failed to serialize document"),
+            json!({
+                "spdxVersion": "SPDX-2.3",
+                "dataLicense": "CC0-1.0",
+                "SPDXID": "SPDXRef-DOCUMENT",
+                "name": "app",
+                "documentNamespace": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+                "creationInfo": {
+                    "created": "2023-11-14T22:13:20Z",
+                    "creators": [
+                        "Tool: cargo-cyclonedx-0.4.0",
+                        "Person: Jane Doe (jane@example.com)"
+                    ]
+                },
+                "packages": [
+                    {
+                        "SPDXID": "SPDXRef-Package-app-1.0.0",
+                        "name": "app",
+                        "versionInfo": "1.0.0",
+                        "downloadLocation": "NOASSERTION",
+                        "filesAnalyzed": false,
+                        "licenseConcluded": "NOASSERTION",
+                        "licenseDeclared": "(MIT OR Apache-2.0) AND LicenseRef-Custom-License",
+                        "copyrightText": "NOASSERTION",
+                        "primaryPackagePurpose": "APPLICATION"
+                    },
+                    {
+                        "SPDXID": "SPDXRef-Package-lib-2.0.0",
+                        "name": "lib",
+                        "versionInfo": "2.0.0",
+                        "downloadLocation": "NOASSERTION",
+                        "filesAnalyzed": false,
+                        "checksums": [
+                            {
+                                "algorithm": "SHA256",
+                                "checksumValue": "abcdef"
+                            }
+                        ],
+                        "homepage": "https://example.com",
+                        "licenseConcluded": "NOASSERTION",
+                        "licenseDeclared": "NOASSERTION",
+                        "copyrightText": "NOASSERTION",
+                        "externalRefs": [
+                            {
+                                "referenceCategory": "PACKAGE-MANAGER",
+                                "referenceType": "purl",
+                                "referenceLocator": "pkg:cargo/lib@2.0.0"
+                            }
+                        ],
+                        "primaryPackagePurpose": "LIBRARY"
+                    },
+                    {
+                        "SPDXID": "SPDXRef-Package-platform",
+                        "name": "platform",
+                        "downloadLocation": "NOASSERTION",
+                        "filesAnalyzed": false,
+                        "licenseConcluded": "NOASSERTION",
+                        "licenseDeclared": "NOASSERTION",
+                        "copyrightText": "NOASSERTION",
+                        "primaryPackagePurpose": "OTHER"
+                    }
+                ],
+                "hasExtractedLicensingInfos": [
+                    {
+                        "licenseId": "LicenseRef-Custom-License",
+                        "extractedText": "Custom License",
+                        "name": "Custom License"
+                    }
+                ],
+                "relationships": [
+                    {
+                        "spdxElementId": "SPDXRef-DOCUMENT",
+                        "relationshipType": "DESCRIBES",
+                        "relatedSpdxElement": "SPDXRef-Package-app-1.0.0"
+                    },
+                    {
+                        "spdxElementId": "SPDXRef-Package-lib-2.0.0",
+                        "relationshipType": "CONTAINS",
+                        "relatedSpdxElement": "SPDXRef-Package-platform"
+                    },
+                    {
+                        "spdxElementId": "SPDXRef-Package-app-1.0.0",
+                        "relationshipType": "DEPENDS_ON",
+                        "relatedSpdxElement": "SPDXRef-Package-lib-2.0.0"
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_report_what_spdx_cannot_represent() {
+        let (_, losses) = export(example_bom());
+
+        assert_eq!(
+            losses,
+            vec![
+                ConversionLoss {
+                    path: "metadata.tools[0].vendor".to_string(),
+                    kind: ConversionLossKind::Dropped,
+                },
+                ConversionLoss {
+                    path: "components[0].hashes[1]".to_string(),
+                    kind: ConversionLossKind::Dropped,
+                },
+                ConversionLoss {
+                    path: "components[0].group".to_string(),
+                    kind: ConversionLossKind::Dropped,
+                },
+                ConversionLoss {
+                    path: "components[0].components[0].type".to_string(),
+                    kind: ConversionLossKind::Rewritten {
+                        from: "platform".to_string(),
+                        to: "OTHER".to_string(),
+                    },
+                },
+                ConversionLoss {
+                    path: "dependencies[0].dependsOn[1]".to_string(),
+                    kind: ConversionLossKind::Dropped,
+                },
+                ConversionLoss {
+                    path: "dependencies[1]".to_string(),
+                    kind: ConversionLossKind::Dropped,
+                },
+                ConversionLoss {
+                    path: "services".to_string(),
+                    kind: ConversionLossKind::Dropped,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_give_packages_unique_identifiers() {
+        let bom = Bom {
+            components: Some(Components(vec![
+                Component::new(Classification::Library, "lib", "1.0.0", None),
+                Component::new(Classification::Library, "lib", "1.0.0", None),
+            ])),
+            ..Bom::default()
+        };
+
+        let (document, _) = export(bom);
+
+        let spdx_ids: Vec<_> = document.packages.iter().map(|p| &p.spdx_id).collect();
+        assert_eq!(
+            spdx_ids,
+            vec!["SPDXRef-Package-lib-1.0.0", "SPDXRef-Package-lib-1.0.0-2"]
+        );
+        assert_eq!(
+            document.relationships[1],
+            Relationship {
+                spdx_element_id: "SPDXRef-DOCUMENT".to_string(),
+                relationship_type: "DESCRIBES".to_string(),
+                related_spdx_element: "SPDXRef-Package-lib-1.0.0-2".to_string(),
+                unmapped: BTreeMap::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_report_an_invalid_timestamp() {
+        let bom = Bom {
+            metadata: Some(Metadata {
+                timestamp: Some(DateTime("invalid".to_string())),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+
+        let (document, losses) = export(bom);
+
+        assert_eq!(
+            losses,
+            vec![ConversionLoss {
+                path: "metadata.timestamp".to_string(),
+                kind: ConversionLossKind::Rewritten {
+                    from: "invalid".to_string(),
+                    to: document.creation_info.created,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_export_tool_components_and_services_as_creators() {
+        let bom = Bom {
+            metadata: Some(Metadata {
+                tools: Some(Tools::Object {
+                    components: Some(Components(vec![Component::new(
+                        Classification::Application,
+                        "generator",
+                        "1.0.0",
+                        None,
+                    )])),
+                    services: Some(Services(vec![Service::new("scanner", None)])),
+                }),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+
+        let (document, losses) = export(bom);
+
+        assert_eq!(
+            document.creation_info.creators,
+            vec!["Tool: generator-1.0.0", "Tool: scanner"]
+        );
+        assert_eq!(
+            losses,
+            vec![
+                ConversionLoss {
+                    path: "metadata.tools.components[0]".to_string(),
+                    kind: ConversionLossKind::Rewritten {
+                        from: "component".to_string(),
+                        to: "creator".to_string(),
+                    },
+                },
+                ConversionLoss {
+                    path: "metadata.tools.services[0]".to_string(),
+                    kind: ConversionLossKind::Rewritten {
+                        from: "service".to_string(),
+                        to: "creator".to_string(),
+                    },
+                },
+            ]
+        );
+    }
+}
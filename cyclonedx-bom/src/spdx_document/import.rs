@@ -0,0 +1,870 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::conversion::{ConversionLoss, ConversionLossKind};
+use crate::errors::SpdxReadError;
+use crate::external_models::{
+    date_time::DateTime, normalized_string::NormalizedString, spdx::SpdxExpression, uri::Purl,
+    uri::Uri,
+};
+use crate::models::{
+    attached_text::AttachedText,
+    bom::{Bom, UrnUuid},
+    component::{Classification, Component, Components, Cpe},
+    dependency::{Dependencies, Dependency},
+    external_reference::{ExternalReference, ExternalReferenceType, ExternalReferences},
+    hash::{Hash, HashAlgorithm, HashValue, Hashes},
+    license::{License, LicenseChoice, Licenses},
+    metadata::Metadata,
+    organization::{OrganizationalContact, OrganizationalEntity},
+    tool::{Tool, Tools},
+};
+use crate::spdx_document::{
+    CreationInfo, ExtractedLicensingInfo, Package, SpdxDocument, CHECKSUM_ALGORITHMS, NOASSERTION,
+    PACKAGE_PURPOSES, SPDX_VERSION,
+};
+
+/// Convert the SPDX document into a BOM, returning every element that the BOM cannot represent
+///
+/// Packages become components, identified by their SPDX identifier as the bom-ref. `CONTAINS`
+/// relationships nest components, `DEPENDS_ON` relationships become dependencies, and a single
+/// package that the document describes becomes the component of the metadata.
+pub(crate) fn import(document: SpdxDocument) -> Result<(Bom, Vec<ConversionLoss>), SpdxReadError> {
+    if document.spdx_version != SPDX_VERSION {
+        return Err(SpdxReadError::UnsupportedSpdxVersion(document.spdx_version));
+    }
+
+    let mut import = Import::default();
+    let bom = import.document(document);
+    Ok((bom, import.losses))
+}
+
+#[derive(Default)]
+struct Import {
+    losses: Vec<ConversionLoss>,
+    /// The licenses without an SPDX identifier, by their `LicenseRef-` identifier
+    extracted_licensing_infos: HashMap<String, (usize, ExtractedLicensingInfo)>,
+    /// The `LicenseRef-` identifiers that have been converted into a named license
+    named_licenses: HashSet<String>,
+}
+
+impl Import {
+    fn drop(&mut self, path: String) {
+        self.losses.push(ConversionLoss {
+            path,
+            kind: ConversionLossKind::Dropped,
+        });
+    }
+
+    fn drop_unmapped(&mut self, unmapped: BTreeMap<String, serde_json::Value>, path: &str) {
+        for key in unmapped.into_keys() {
+            if path.is_empty() {
+                self.drop(key);
+            } else {
+                self.drop(format!("{path}.{key}"));
+            }
+        }
+    }
+
+    fn document(&mut self, mut document: SpdxDocument) -> Bom {
+        let serial_number = self.serial_number(document.document_namespace);
+        let (timestamp, tools, authors, manufacture) = self.creation_info(document.creation_info);
+
+        // `documentDescribes` is the deprecated form of `DESCRIBES` relationships
+        let mut described = Vec::new();
+        if let Some(value) = document.unmapped.remove("documentDescribes") {
+            match serde_json::from_value::<Vec<String>>(value) {
+                Ok(spdx_ids) => described = spdx_ids,
+                Err(_) => self.drop("documentDescribes".to_string()),
+            }
+        }
+        self.drop_unmapped(document.unmapped, "");
+
+        for (index, info) in document
+            .has_extracted_licensing_infos
+            .into_iter()
+            .enumerate()
+        {
+            self.extracted_licensing_infos
+                .insert(info.license_id.clone(), (index, info));
+        }
+
+        let package_indexes: HashMap<String, usize> = document
+            .packages
+            .iter()
+            .enumerate()
+            .map(|(index, package)| (package.spdx_id.clone(), index))
+            .collect();
+
+        let mut parents: Vec<Option<usize>> = vec![None; document.packages.len()];
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); document.packages.len()];
+        let mut dependencies: Vec<Vec<String>> = vec![Vec::new(); document.packages.len()];
+
+        for (index, relationship) in document.relationships.into_iter().enumerate() {
+            let path = format!("relationships[{index}]");
+
+            let element = package_indexes.get(&relationship.spdx_element_id).copied();
+            let related = package_indexes
+                .get(&relationship.related_spdx_element)
+                .copied();
+            let document_id = document.spdx_id.as_str();
+
+            match (relationship.relationship_type.as_str(), element, related) {
+                ("DESCRIBES", None, Some(_)) if relationship.spdx_element_id == document_id => {
+                    described.push(relationship.related_spdx_element)
+                }
+                ("DESCRIBED_BY", Some(_), None)
+                    if relationship.related_spdx_element == document_id =>
+                {
+                    described.push(relationship.spdx_element_id)
+                }
+                ("CONTAINS", Some(parent), Some(child))
+                | ("CONTAINED_BY", Some(child), Some(parent))
+                    if !breaks_nesting(&parents, parent, child) =>
+                {
+                    parents[child] = Some(parent);
+                    children[parent].push(child);
+                }
+                ("DEPENDS_ON", Some(dependent), Some(_)) => {
+                    dependencies[dependent].push(relationship.related_spdx_element)
+                }
+                ("DEPENDENCY_OF", Some(_), Some(dependent)) => {
+                    dependencies[dependent].push(relationship.spdx_element_id)
+                }
+                _ => {
+                    self.drop(path);
+                    continue;
+                }
+            }
+            self.drop_unmapped(relationship.unmapped, &path);
+        }
+
+        let mut components: Vec<Option<Component>> = document
+            .packages
+            .into_iter()
+            .enumerate()
+            .map(|(index, package)| Some(self.package(package, &format!("packages[{index}]"))))
+            .collect();
+
+        let dependencies: Vec<Dependency> = components
+            .iter()
+            .zip(dependencies)
+            .filter(|(_, dependencies)| !dependencies.is_empty())
+            .filter_map(|(component, dependencies)| {
+                Some(Dependency {
+                    dependency_ref: component.as_ref()?.bom_ref.clone()?,
+                    dependencies,
+                })
+            })
+            .collect();
+
+        // only a single described package can be the subject of the BOM
+        let roots: Vec<usize> = (0..components.len())
+            .filter(|index| parents[*index].is_none())
+            .collect();
+        described.sort();
+        described.dedup();
+        let metadata_component = match described.as_slice() {
+            [spdx_id] => package_indexes
+                .get(spdx_id)
+                .copied()
+                .filter(|index| parents[*index].is_none()),
+            _ => None,
+        };
+
+        let metadata_component =
+            metadata_component.and_then(|index| nest(&mut components, &children, index));
+        let components: Vec<Component> = roots
+            .into_iter()
+            .filter_map(|index| nest(&mut components, &children, index))
+            .collect();
+
+        let name = document.name;
+        let name_matches = metadata_component
+            .as_ref()
+            .map(|component| component.name.0 == name)
+            .unwrap_or_default();
+        if !name.is_empty() && !name_matches {
+            self.drop("name".to_string());
+        }
+
+        let mut unused: Vec<(usize, String)> = self
+            .extracted_licensing_infos
+            .iter()
+            .filter(|(license_id, _)| !self.named_licenses.contains(*license_id))
+            .map(|(_, (index, _))| (*index, format!("hasExtractedLicensingInfos[{index}]")))
+            .collect();
+        unused.sort();
+        for (_, path) in unused {
+            self.drop(path);
+        }
+
+        Bom {
+            version: 1,
+            serial_number,
+            metadata: Some(Metadata {
+                timestamp,
+                tools: Some(tools).filter(|t| !t.is_empty()).map(Tools::List),
+                authors: Some(authors).filter(|a| !a.is_empty()),
+                component: metadata_component,
+                manufacture,
+                ..Metadata::default()
+            }),
+            components: Some(Components(components)).filter(|c| !c.0.is_empty()),
+            dependencies: Some(Dependencies(dependencies)).filter(|d| !d.0.is_empty()),
+            ..Bom::default()
+        }
+    }
+
+    /// Use the document namespace as the serial number, or derive one from it if it is not a UUID
+    fn serial_number(&mut self, document_namespace: String) -> Option<UrnUuid> {
+        if document_namespace.is_empty() {
+            return None;
+        }
+
+        match UrnUuid::new(document_namespace.clone()) {
+            Ok(serial_number) => Some(serial_number),
+            Err(_) => {
+                let serial_number =
+                    UrnUuid::new_v5(&uuid::Uuid::NAMESPACE_URL, document_namespace.as_bytes());
+                self.losses.push(ConversionLoss {
+                    path: "documentNamespace".to_string(),
+                    kind: ConversionLossKind::Rewritten {
+                        from: document_namespace,
+                        to: serial_number.to_string(),
+                    },
+                });
+                Some(serial_number)
+            }
+        }
+    }
+
+    fn creation_info(
+        &mut self,
+        creation_info: CreationInfo,
+    ) -> (
+        Option<DateTime>,
+        Vec<Tool>,
+        Vec<OrganizationalContact>,
+        Option<OrganizationalEntity>,
+    ) {
+        let mut tools = Vec::new();
+        let mut authors = Vec::new();
+        let mut manufacture = None;
+
+        for (index, creator) in creation_info.creators.into_iter().enumerate() {
+            match creator.split_once(':').map(|(t, c)| (t, c.trim())) {
+                Some(("Tool", tool)) => tools.push(parse_tool(tool)),
+                Some(("Person", person)) => authors.push(parse_person(person)),
+                Some(("Organization", organization)) if manufacture.is_none() => {
+                    manufacture = Some(OrganizationalEntity {
+                        name: Some(NormalizedString::new(organization)),
+                        url: None,
+                        contact: None,
+                    })
+                }
+                _ => self.drop(format!("creationInfo.creators[{index}]")),
+            }
+        }
+        self.drop_unmapped(creation_info.unmapped, "creationInfo");
+
+        let timestamp = Some(creation_info.created)
+            .filter(|created| !created.is_empty())
+            .map(DateTime);
+
+        (timestamp, tools, authors, manufacture)
+    }
+
+    fn package(&mut self, package: Package, path: &str) -> Component {
+        let component_type = match &package.primary_package_purpose {
+            Some(purpose) => match PACKAGE_PURPOSES.iter().find(|(_, spdx)| spdx == purpose) {
+                Some((cyclonedx, _)) => Classification::new_unchecked(cyclonedx),
+                None => {
+                    self.losses.push(ConversionLoss {
+                        path: format!("{path}.primaryPackagePurpose"),
+                        kind: ConversionLossKind::Rewritten {
+                            from: purpose.clone(),
+                            to: Classification::Library.to_string(),
+                        },
+                    });
+                    Classification::Library
+                }
+            },
+            None => Classification::Library,
+        };
+
+        let mut component = Component::new(
+            component_type,
+            &package.name,
+            package.version_info.as_deref().unwrap_or_default(),
+            Some(package.spdx_id),
+        );
+
+        component.supplier = assertion(package.supplier).map(|supplier| {
+            match supplier.split_once(':').map(|(t, s)| (t, s.trim())) {
+                Some(("Person", person)) => OrganizationalEntity {
+                    name: None,
+                    url: None,
+                    contact: Some(vec![parse_person(person)]),
+                },
+                Some((_, organization)) => OrganizationalEntity {
+                    name: Some(NormalizedString::new(organization)),
+                    url: None,
+                    contact: None,
+                },
+                None => OrganizationalEntity {
+                    name: Some(NormalizedString::new(&supplier)),
+                    url: None,
+                    contact: None,
+                },
+            }
+        });
+        component.author = assertion(package.originator).map(|originator| {
+            let originator = originator
+                .split_once(':')
+                .map(|(_, o)| o.trim())
+                .unwrap_or(&originator);
+            NormalizedString::new(originator)
+        });
+        component.description = package.description.map(|d| NormalizedString::new(&d));
+        component.copyright =
+            assertion(Some(package.copyright_text)).map(|c| NormalizedString::new(&c));
+
+        let mut external_references = Vec::new();
+        if let Some(homepage) = assertion(package.homepage) {
+            external_references.push(ExternalReference::new(
+                ExternalReferenceType::Website,
+                Uri(homepage),
+            ));
+        }
+        if let Some(download_location) = assertion(Some(package.download_location)) {
+            external_references.push(ExternalReference::new(
+                ExternalReferenceType::Distribution,
+                Uri(download_location),
+            ));
+        }
+
+        for (index, external_ref) in package.external_refs.into_iter().enumerate() {
+            let path = format!("{path}.externalRefs[{index}]");
+            self.drop_unmapped(external_ref.unmapped, &path);
+
+            let locator = external_ref.reference_locator;
+            match (
+                external_ref.reference_category.as_str(),
+                external_ref.reference_type.as_str(),
+            ) {
+                ("PACKAGE-MANAGER" | "PACKAGE_MANAGER", "purl") if component.purl.is_none() => {
+                    component.purl = Some(Purl(locator))
+                }
+                ("SECURITY", "cpe22Type" | "cpe23Type") if component.cpe.is_none() => {
+                    component.cpe = Some(Cpe(locator))
+                }
+                ("OTHER", reference_type) => {
+                    match ExternalReferenceType::new_unchecked(reference_type) {
+                        ExternalReferenceType::UnknownExternalReferenceType(_) => self.drop(path),
+                        reference_type => external_references
+                            .push(ExternalReference::new(reference_type, Uri(locator))),
+                    }
+                }
+                _ => self.drop(path),
+            }
+        }
+        component.external_references =
+            Some(ExternalReferences(external_references)).filter(|e| !e.0.is_empty());
+
+        let mut hashes = Vec::new();
+        for (index, checksum) in package.checksums.into_iter().enumerate() {
+            match CHECKSUM_ALGORITHMS
+                .iter()
+                .find(|(_, spdx)| *spdx == checksum.algorithm)
+            {
+                Some((cyclonedx, _)) => hashes.push(Hash {
+                    alg: HashAlgorithm::new_unchecked(cyclonedx),
+                    content: HashValue(checksum.checksum_value),
+                }),
+                None => self.drop(format!("{path}.checksums[{index}]")),
+            }
+        }
+        component.hashes = Some(Hashes(hashes)).filter(|h| !h.0.is_empty());
+
+        let license_declared = assertion(Some(package.license_declared));
+        if let Some(license_concluded) = assertion(Some(package.license_concluded)) {
+            if Some(&license_concluded) != license_declared.as_ref() {
+                self.drop(format!("{path}.licenseConcluded"));
+            }
+        }
+        component.licenses = license_declared.map(|license| Licenses(vec![self.license(license)]));
+
+        self.drop_unmapped(package.unmapped, path);
+
+        component
+    }
+
+    /// Convert a reference to a license without an SPDX identifier back into a named license,
+    /// and keep any other expression as it is
+    fn license(&mut self, expression: String) -> LicenseChoice {
+        let (index, info) = match self.extracted_licensing_infos.get(&expression) {
+            Some((index, info)) => (*index, info),
+            None => return LicenseChoice::Expression(SpdxExpression(expression)),
+        };
+
+        let name = assertion(Some(info.name.clone())).unwrap_or_else(|| info.license_id.clone());
+        let mut license = License::named_license(&name);
+        if info.extracted_text != name {
+            license.text = Some(AttachedText::new(None, &info.extracted_text));
+        }
+        license.url = info.see_alsos.first().map(|url| Uri(url.clone()));
+
+        // report what the named license has no room for only once, however often it is used
+        let path = format!("hasExtractedLicensingInfos[{index}]");
+        let mut dropped: Vec<String> = (1..info.see_alsos.len())
+            .map(|see_also| format!("{path}.seeAlsos[{see_also}]"))
+            .collect();
+        dropped.extend(info.unmapped.keys().map(|key| format!("{path}.{key}")));
+        if self.named_licenses.insert(expression) {
+            for path in dropped {
+                self.drop(path);
+            }
+        }
+
+        LicenseChoice::License(license)
+    }
+}
+
+/// Whether making the child a part of the parent would make a package contain itself, or give
+/// the child a second parent
+fn breaks_nesting(parents: &[Option<usize>], parent: usize, child: usize) -> bool {
+    if parents[child].is_some() {
+        return true;
+    }
+
+    let mut ancestor = Some(parent);
+    while let Some(index) = ancestor {
+        if index == child {
+            return true;
+        }
+        ancestor = parents[index];
+    }
+    false
+}
+
+/// Take the component out of the list and nest the components that it contains inside of it
+fn nest(
+    components: &mut Vec<Option<Component>>,
+    children: &[Vec<usize>],
+    index: usize,
+) -> Option<Component> {
+    let mut component = components[index].take()?;
+    let nested: Vec<Component> = children[index]
+        .iter()
+        .filter_map(|child| nest(components, children, *child))
+        .collect();
+    component.components = Some(Components(nested)).filter(|c| !c.0.is_empty());
+    Some(component)
+}
+
+/// `NOASSERTION` and `NONE` mean that SPDX has no value for the field, just like an empty one
+fn assertion(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.is_empty() && v != NOASSERTION && v != "NONE")
+}
+
+/// Split a tool in the `name-version` form, assuming the version starts with a digit
+fn parse_tool(tool: &str) -> Tool {
+    match tool.rsplit_once('-') {
+        Some((name, version)) if version.starts_with(|c: char| c.is_ascii_digit()) => Tool {
+            name: Some(NormalizedString::new(name)),
+            version: Some(NormalizedString::new(version)),
+            ..Tool::default()
+        },
+        _ => Tool {
+            name: Some(NormalizedString::new(tool)),
+            ..Tool::default()
+        },
+    }
+}
+
+/// Split a person in the `name (email)` form
+fn parse_person(person: &str) -> OrganizationalContact {
+    match person
+        .strip_suffix(')')
+        .and_then(|person| person.rsplit_once('('))
+    {
+        Some((name, email)) => OrganizationalContact::new(name.trim(), Some(email.trim())),
+        None => OrganizationalContact::new(person, None),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn parse(document: serde_json::Value) -> (Bom, Vec<ConversionLoss>) {
+        let document = serde_json::from_value(document).expect("Failed to parse document");
+        import(document).expect("Failed to import document")
+    }
+
+    fn example_document() -> serde_json::Value {
+        json!({
+            "spdxVersion": "SPDX-2.3",
+            "dataLicense": "CC0-1.0",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "name": "app",
+            "documentNamespace": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+            "creationInfo": {
+                "created": "2023-11-14T22:13:20Z",
+                "creators": [
+                    "Tool: cargo-cyclonedx-0.4.0",
+                    "Person: Jane Doe (jane@example.com)",
+                    "Organization: Example"
+                ]
+            },
+            "packages": [
+                {
+                    "SPDXID": "SPDXRef-Package-app",
+                    "name": "app",
+                    "versionInfo": "1.0.0",
+                    "downloadLocation": "NOASSERTION",
+                    "filesAnalyzed": false,
+                    "licenseConcluded": "NOASSERTION",
+                    "licenseDeclared": "MIT OR Apache-2.0",
+                    "copyrightText": "NOASSERTION",
+                    "primaryPackagePurpose": "APPLICATION"
+                },
+                {
+                    "SPDXID": "SPDXRef-Package-lib",
+                    "name": "lib",
+                    "versionInfo": "2.0.0",
+                    "supplier": "Organization: Example",
+                    "originator": "Person: Jane Doe",
+                    "downloadLocation": "https://example.com/lib-2.0.0.tar.gz",
+                    "checksums": [
+                        {
+                            "algorithm": "SHA256",
+                            "checksumValue": "abcdef"
+                        }
+                    ],
+                    "homepage": "https://example.com",
+                    "licenseDeclared": "LicenseRef-Custom",
+                    "copyrightText": "Copyright Example",
+                    "externalRefs": [
+                        {
+                            "referenceCategory": "PACKAGE-MANAGER",
+                            "referenceType": "purl",
+                            "referenceLocator": "pkg:cargo/lib@2.0.0"
+                        },
+                        {
+                            "referenceCategory": "OTHER",
+                            "referenceType": "vcs",
+                            "referenceLocator": "https://example.com/lib.git"
+                        }
+                    ]
+                },
+                {
+                    "SPDXID": "SPDXRef-Package-nested",
+                    "name": "nested",
+                    "primaryPackagePurpose": "LIBRARY"
+                }
+            ],
+            "hasExtractedLicensingInfos": [
+                {
+                    "licenseId": "LicenseRef-Custom",
+                    "extractedText": "Custom license text",
+                    "name": "Custom License",
+                    "seeAlsos": ["https://example.com/license"]
+                }
+            ],
+            "relationships": [
+                {
+                    "spdxElementId": "SPDXRef-DOCUMENT",
+                    "relationshipType": "DESCRIBES",
+                    "relatedSpdxElement": "SPDXRef-Package-app"
+                },
+                {
+                    "spdxElementId": "SPDXRef-Package-lib",
+                    "relationshipType": "CONTAINS",
+                    "relatedSpdxElement": "SPDXRef-Package-nested"
+                },
+                {
+                    "spdxElementId": "SPDXRef-Package-app",
+                    "relationshipType": "DEPENDS_ON",
+                    "relatedSpdxElement": "SPDXRef-Package-lib"
+                },
+                {
+                    "spdxElementId": "SPDXRef-Package-nested",
+                    "relationshipType": "DEPENDENCY_OF",
+                    "relatedSpdxElement": "SPDXRef-Package-lib"
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn it_should_import_an_spdx_document() {
+        let (bom, losses) = parse(example_document());
+
+        let mut root = Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("SPDXRef-Package-app".to_string()),
+        );
+        root.licenses = Some(Licenses(vec![LicenseChoice::Expression(SpdxExpression(
+            "MIT OR Apache-2.0".to_string(),
+        ))]));
+
+        let mut library = Component::new(
+            Classification::Library,
+            "lib",
+            "2.0.0",
+            Some("SPDXRef-Package-lib".to_string()),
+        );
+        library.supplier = Some(OrganizationalEntity {
+            name: Some(NormalizedString::new("Example")),
+            url: None,
+            contact: None,
+        });
+        library.author = Some(NormalizedString::new("Jane Doe"));
+        library.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA256,
+            content: HashValue("abcdef".to_string()),
+        }]));
+        let mut license = License::named_license("Custom License");
+        license.text = Some(AttachedText::new(None, "Custom license text"));
+        license.url = Some(Uri("https://example.com/license".to_string()));
+        library.licenses = Some(Licenses(vec![LicenseChoice::License(license)]));
+        library.copyright = Some(NormalizedString::new("Copyright Example"));
+        library.purl = Some(Purl("pkg:cargo/lib@2.0.0".to_string()));
+        library.external_references = Some(ExternalReferences(vec![
+            ExternalReference::new(
+                ExternalReferenceType::Website,
+                Uri("https://example.com".to_string()),
+            ),
+            ExternalReference::new(
+                ExternalReferenceType::Distribution,
+                Uri("https://example.com/lib-2.0.0.tar.gz".to_string()),
+            ),
+            ExternalReference::new(
+                ExternalReferenceType::Vcs,
+                Uri("https://example.com/lib.git".to_string()),
+            ),
+        ]));
+        library.components = Some(Components(vec![Component::new(
+            Classification::Library,
+            "nested",
+            "",
+            Some("SPDXRef-Package-nested".to_string()),
+        )]));
+
+        assert_eq!(
+            bom,
+            Bom {
+                serial_number: Some(UrnUuid(
+                    "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()
+                )),
+                metadata: Some(Metadata {
+                    timestamp: Some(DateTime("2023-11-14T22:13:20Z".to_string())),
+                    tools: Some(Tools::List(vec![Tool {
+                        name: Some(NormalizedString::new("cargo-cyclonedx")),
+                        version: Some(NormalizedString::new("0.4.0")),
+                        ..Tool::default()
+                    }])),
+                    authors: Some(vec![OrganizationalContact::new(
+                        "Jane Doe",
+                        Some("jane@example.com")
+                    )]),
+                    component: Some(root),
+                    manufacture: Some(OrganizationalEntity {
+                        name: Some(NormalizedString::new("Example")),
+                        url: None,
+                        contact: None,
+                    }),
+                    ..Metadata::default()
+                }),
+                components: Some(Components(vec![library])),
+                dependencies: Some(Dependencies(vec![
+                    Dependency {
+                        dependency_ref: "SPDXRef-Package-app".to_string(),
+                        dependencies: vec!["SPDXRef-Package-lib".to_string()],
+                    },
+                    Dependency {
+                        dependency_ref: "SPDXRef-Package-lib".to_string(),
+                        dependencies: vec!["SPDXRef-Package-nested".to_string()],
+                    },
+                ])),
+                ..Bom::default()
+            }
+        );
+        assert_eq!(losses, Vec::new());
+    }
+
+    #[test]
+    fn it_should_report_what_the_bom_cannot_represent() {
+        let mut document = example_document();
+        document["documentNamespace"] = json!("https://example.com/spdx/app");
+        document["creationInfo"]["licenseListVersion"] = json!("3.21");
+        document["creationInfo"]["creators"] = json!(["Organization: A", "Organization: B"]);
+        document["files"] = json!([]);
+        document["packages"][1]["licenseConcluded"] = json!("MIT");
+        document["packages"][1]["packageFileName"] = json!("lib-2.0.0.tar.gz");
+        document["packages"][1]["checksums"] = json!([
+            { "algorithm": "SHA224", "checksumValue": "1234" }
+        ]);
+        document["packages"][1]["externalRefs"] = json!([
+            {
+                "referenceCategory": "PERSISTENT-ID",
+                "referenceType": "swh",
+                "referenceLocator": "swh:1:cnt:94a9ed024d3859793618152ea559a168bbcbb5e2",
+                "comment": "Software Heritage"
+            }
+        ]);
+        document["packages"][2]["primaryPackagePurpose"] = json!("SOURCE");
+        document["hasExtractedLicensingInfos"][0]["seeAlsos"] =
+            json!(["https://example.com/license", "https://example.org/license"]);
+        document["hasExtractedLicensingInfos"]
+            .as_array_mut()
+            .expect("Failed to get extracted licensing infos")
+            .push(json!({
+                "licenseId": "LicenseRef-Unused",
+                "extractedText": "Unused license text"
+            }));
+        document["relationships"]
+            .as_array_mut()
+            .expect("Failed to get relationships")
+            .extend(vec![
+                json!({
+                    "spdxElementId": "SPDXRef-Package-nested",
+                    "relationshipType": "CONTAINS",
+                    "relatedSpdxElement": "SPDXRef-Package-lib"
+                }),
+                json!({
+                    "spdxElementId": "SPDXRef-Package-app",
+                    "relationshipType": "GENERATED_FROM",
+                    "relatedSpdxElement": "SPDXRef-Package-lib",
+                    "comment": "Built from source"
+                }),
+            ]);
+
+        let (_, losses) = parse(document);
+
+        let dropped = |path: &str| ConversionLoss {
+            path: path.to_string(),
+            kind: ConversionLossKind::Dropped,
+        };
+        assert_eq!(
+            losses,
+            vec![
+                ConversionLoss {
+                    path: "documentNamespace".to_string(),
+                    kind: ConversionLossKind::Rewritten {
+                        from: "https://example.com/spdx/app".to_string(),
+                        to: "urn:uuid:69fab08c-4c14-59ff-9843-dc1f63810d8a".to_string(),
+                    },
+                },
+                dropped("creationInfo.creators[1]"),
+                dropped("creationInfo.licenseListVersion"),
+                dropped("files"),
+                dropped("relationships[4]"),
+                dropped("relationships[5]"),
+                dropped("packages[1].externalRefs[0].comment"),
+                dropped("packages[1].externalRefs[0]"),
+                dropped("packages[1].checksums[0]"),
+                dropped("packages[1].licenseConcluded"),
+                dropped("hasExtractedLicensingInfos[0].seeAlsos[1]"),
+                dropped("packages[1].packageFileName"),
+                ConversionLoss {
+                    path: "packages[2].primaryPackagePurpose".to_string(),
+                    kind: ConversionLossKind::Rewritten {
+                        from: "SOURCE".to_string(),
+                        to: "library".to_string(),
+                    },
+                },
+                dropped("hasExtractedLicensingInfos[1]"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_only_describe_a_single_package_in_the_metadata() {
+        let mut document = example_document();
+        document["relationships"] = json!([
+            {
+                "spdxElementId": "SPDXRef-DOCUMENT",
+                "relationshipType": "DESCRIBES",
+                "relatedSpdxElement": "SPDXRef-Package-app"
+            }
+        ]);
+        document["documentDescribes"] = json!(["SPDXRef-Package-lib"]);
+
+        let (bom, losses) = parse(document);
+
+        assert_eq!(bom.metadata.and_then(|m| m.component), None);
+        let names: Vec<_> = bom
+            .components
+            .map(|c| c.0.into_iter().map(|c| c.name.0).collect())
+            .unwrap_or_default();
+        assert_eq!(names, vec!["app", "lib", "nested"]);
+        assert_eq!(
+            losses,
+            vec![ConversionLoss {
+                path: "name".to_string(),
+                kind: ConversionLossKind::Dropped,
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip_an_exported_bom() {
+        let (bom, _) = parse(example_document());
+        let (document, export_losses) = crate::spdx_document::export::export(bom);
+        let document = serde_json::to_value(document).expect("Failed to serialize document");
+        let (bom, import_losses) = parse(document);
+
+        assert_eq!(export_losses, Vec::new());
+        assert_eq!(import_losses, Vec::new());
+        let component = bom
+            .metadata
+            .and_then(|m| m.component)
+            .expect("No component");
+        assert_eq!(component.name.0, "app");
+        assert_eq!(
+            component.licenses,
+            Some(Licenses(vec![LicenseChoice::Expression(SpdxExpression(
+                "MIT OR Apache-2.0".to_string()
+            ))]))
+        );
+    }
+
+    #[test]
+    fn it_should_fail_to_import_other_spdx_versions() {
+        let mut document = example_document();
+        document["spdxVersion"] = json!("SPDX-2.2");
+        let document = serde_json::from_value(document).expect("Failed to parse document");
+
+        match import(document) {
+            Err(SpdxReadError::UnsupportedSpdxVersion(version)) => assert_eq!(version, "SPDX-2.2"),
+            other => panic!(
+                "Expected an unsupported version, got {:?}",
+                other.map(|_| ())
+            ),
+        }
+    }
+}
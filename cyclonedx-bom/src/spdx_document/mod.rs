@@ -0,0 +1,194 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Conversion between the [`Bom`](crate::models::bom::Bom) model and documents conforming to
+//! [version 2.3 of the SPDX specification](https://spdx.github.io/spdx-spec/v2.3/).
+//!
+//! Components become SPDX packages, nested components and dependencies become `CONTAINS` and
+//! `DEPENDS_ON` relationships, and license choices become license expressions. Every field that
+//! SPDX has no equivalent for is reported as a [`ConversionLoss`](crate::conversion::ConversionLoss).
+//!
+//! Importing goes the other way, with each package identified by its SPDX identifier as the
+//! bom-ref of its component, and every element the `Bom` model cannot hold reported in the same way.
+
+pub(crate) mod export;
+pub(crate) mod import;
+pub(crate) mod tag_value;
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+pub(crate) const SPDX_VERSION: &str = "SPDX-2.3";
+pub(crate) const DATA_LICENSE: &str = "CC0-1.0";
+pub(crate) const DOCUMENT_SPDX_ID: &str = "SPDXRef-DOCUMENT";
+pub(crate) const NOASSERTION: &str = "NOASSERTION";
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SpdxDocument {
+    pub(crate) spdx_version: String,
+    #[serde(default)]
+    pub(crate) data_license: String,
+    #[serde(rename = "SPDXID")]
+    pub(crate) spdx_id: String,
+    #[serde(default)]
+    pub(crate) name: String,
+    #[serde(default)]
+    pub(crate) document_namespace: String,
+    #[serde(default)]
+    pub(crate) creation_info: CreationInfo,
+    #[serde(default)]
+    pub(crate) packages: Vec<Package>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) has_extracted_licensing_infos: Vec<ExtractedLicensingInfo>,
+    #[serde(default)]
+    pub(crate) relationships: Vec<Relationship>,
+    /// The fields that have no equivalent in the `Bom` model, here and in the nested types,
+    /// which are reported when importing
+    #[serde(flatten, skip_serializing)]
+    pub(crate) unmapped: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct CreationInfo {
+    pub(crate) created: String,
+    #[serde(default)]
+    pub(crate) creators: Vec<String>,
+    #[serde(flatten, skip_serializing)]
+    pub(crate) unmapped: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Package {
+    #[serde(rename = "SPDXID")]
+    pub(crate) spdx_id: String,
+    #[serde(default)]
+    pub(crate) name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) version_info: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) supplier: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) originator: Option<String>,
+    #[serde(default)]
+    pub(crate) download_location: String,
+    #[serde(default)]
+    pub(crate) files_analyzed: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) checksums: Vec<Checksum>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) homepage: Option<String>,
+    #[serde(default)]
+    pub(crate) license_concluded: String,
+    #[serde(default)]
+    pub(crate) license_declared: String,
+    #[serde(default)]
+    pub(crate) copyright_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) external_refs: Vec<ExternalRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) primary_package_purpose: Option<String>,
+    #[serde(flatten, skip_serializing)]
+    pub(crate) unmapped: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Checksum {
+    pub(crate) algorithm: String,
+    pub(crate) checksum_value: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ExternalRef {
+    pub(crate) reference_category: String,
+    pub(crate) reference_type: String,
+    pub(crate) reference_locator: String,
+    #[serde(flatten, skip_serializing)]
+    pub(crate) unmapped: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ExtractedLicensingInfo {
+    pub(crate) license_id: String,
+    pub(crate) extracted_text: String,
+    #[serde(default)]
+    pub(crate) name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) see_alsos: Vec<String>,
+    #[serde(flatten, skip_serializing)]
+    pub(crate) unmapped: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Relationship {
+    pub(crate) spdx_element_id: String,
+    pub(crate) relationship_type: String,
+    pub(crate) related_spdx_element: String,
+    #[serde(flatten, skip_serializing)]
+    pub(crate) unmapped: BTreeMap<String, serde_json::Value>,
+}
+
+/// The SPDX names of the CycloneDX hash algorithms, all of which SPDX 2.3 supports
+pub(crate) const CHECKSUM_ALGORITHMS: &[(&str, &str)] = &[
+    ("MD5", "MD5"),
+    ("SHA-1", "SHA1"),
+    ("SHA-256", "SHA256"),
+    ("SHA-384", "SHA384"),
+    ("SHA-512", "SHA512"),
+    ("SHA3-256", "SHA3-256"),
+    ("SHA3-384", "SHA3-384"),
+    ("SHA3-512", "SHA3-512"),
+    ("BLAKE2b-256", "BLAKE2b-256"),
+    ("BLAKE2b-384", "BLAKE2b-384"),
+    ("BLAKE2b-512", "BLAKE2b-512"),
+    ("BLAKE3", "BLAKE3"),
+];
+
+/// The SPDX package purposes of the CycloneDX component types that have one
+pub(crate) const PACKAGE_PURPOSES: &[(&str, &str)] = &[
+    ("application", "APPLICATION"),
+    ("framework", "FRAMEWORK"),
+    ("library", "LIBRARY"),
+    ("container", "CONTAINER"),
+    ("operating-system", "OPERATING-SYSTEM"),
+    ("device", "DEVICE"),
+    ("firmware", "FIRMWARE"),
+    ("file", "FILE"),
+];
+
+/// Replace every character that SPDX does not allow in an element or license identifier
+pub(crate) fn sanitize_id(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,244 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::io::Write;
+
+use crate::spdx_document::{Package, SpdxDocument};
+
+/// Write the document in the tag-value format, with the creation information first, followed by
+/// the packages, the extracted licenses and the relationships
+pub(crate) fn write_tag_value<W: Write>(
+    document: &SpdxDocument,
+    writer: &mut W,
+) -> std::io::Result<()> {
+    write_tag(writer, "SPDXVersion", &document.spdx_version)?;
+    write_tag(writer, "DataLicense", &document.data_license)?;
+    write_tag(writer, "SPDXID", &document.spdx_id)?;
+    write_tag(writer, "DocumentName", &document.name)?;
+    write_tag(writer, "DocumentNamespace", &document.document_namespace)?;
+    for creator in &document.creation_info.creators {
+        write_tag(writer, "Creator", creator)?;
+    }
+    write_tag(writer, "Created", &document.creation_info.created)?;
+
+    for package in &document.packages {
+        writeln!(writer)?;
+        write_package(writer, package)?;
+    }
+
+    for extracted_licensing_info in &document.has_extracted_licensing_infos {
+        writeln!(writer)?;
+        write_tag(writer, "LicenseID", &extracted_licensing_info.license_id)?;
+        write_tag(
+            writer,
+            "ExtractedText",
+            &extracted_licensing_info.extracted_text,
+        )?;
+        write_tag(writer, "LicenseName", &extracted_licensing_info.name)?;
+        for see_also in &extracted_licensing_info.see_alsos {
+            write_tag(writer, "LicenseCrossReference", see_also)?;
+        }
+    }
+
+    if !document.relationships.is_empty() {
+        writeln!(writer)?;
+    }
+    for relationship in &document.relationships {
+        write_tag(
+            writer,
+            "Relationship",
+            &format!(
+                "{} {} {}",
+                relationship.spdx_element_id,
+                relationship.relationship_type,
+                relationship.related_spdx_element
+            ),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn write_package<W: Write>(writer: &mut W, package: &Package) -> std::io::Result<()> {
+    write_tag(writer, "PackageName", &package.name)?;
+    write_tag(writer, "SPDXID", &package.spdx_id)?;
+    if let Some(version_info) = &package.version_info {
+        write_tag(writer, "PackageVersion", version_info)?;
+    }
+    if let Some(supplier) = &package.supplier {
+        write_tag(writer, "PackageSupplier", supplier)?;
+    }
+    if let Some(originator) = &package.originator {
+        write_tag(writer, "PackageOriginator", originator)?;
+    }
+    write_tag(
+        writer,
+        "PackageDownloadLocation",
+        &package.download_location,
+    )?;
+    write_tag(writer, "FilesAnalyzed", &package.files_analyzed.to_string())?;
+    for checksum in &package.checksums {
+        write_tag(
+            writer,
+            "PackageChecksum",
+            &format!("{}: {}", checksum.algorithm, checksum.checksum_value),
+        )?;
+    }
+    if let Some(homepage) = &package.homepage {
+        write_tag(writer, "PackageHomePage", homepage)?;
+    }
+    write_tag(
+        writer,
+        "PackageLicenseConcluded",
+        &package.license_concluded,
+    )?;
+    write_tag(writer, "PackageLicenseDeclared", &package.license_declared)?;
+    write_tag(writer, "PackageCopyrightText", &package.copyright_text)?;
+    if let Some(description) = &package.description {
+        write_tag(writer, "PackageDescription", description)?;
+    }
+    for external_ref in &package.external_refs {
+        write_tag(
+            writer,
+            "ExternalRef",
+            &format!(
+                "{} {} {}",
+                external_ref.reference_category,
+                external_ref.reference_type,
+                external_ref.reference_locator
+            ),
+        )?;
+    }
+    if let Some(primary_package_purpose) = &package.primary_package_purpose {
+        write_tag(writer, "PrimaryPackagePurpose", primary_package_purpose)?;
+    }
+
+    Ok(())
+}
+
+/// Values that span multiple lines are wrapped in `<text>` tags
+fn write_tag<W: Write>(writer: &mut W, tag: &str, value: &str) -> std::io::Result<()> {
+    if value.contains('\n') {
+        writeln!(writer, "{}: <text>{}</text>", tag, value)
+    } else {
+        writeln!(writer, "{}: {}", tag, value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::spdx_document::{
+        Checksum, CreationInfo, ExternalRef, ExtractedLicensingInfo, Relationship,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_write_tag_value() {
+        let document = SpdxDocument {
+            spdx_version: "SPDX-2.3".to_string(),
+            data_license: "CC0-1.0".to_string(),
+            spdx_id: "SPDXRef-DOCUMENT".to_string(),
+            name: "app".to_string(),
+            document_namespace: "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string(),
+            creation_info: CreationInfo {
+                created: "2023-11-14T22:13:20Z".to_string(),
+                creators: vec!["Tool: cargo-cyclonedx-0.4.0".to_string()],
+                unmapped: BTreeMap::new(),
+            },
+            packages: vec![Package {
+                spdx_id: "SPDXRef-Package-app-1.0.0".to_string(),
+                name: "app".to_string(),
+                version_info: Some("1.0.0".to_string()),
+                supplier: Some("Organization: Example".to_string()),
+                originator: None,
+                download_location: "NOASSERTION".to_string(),
+                files_analyzed: false,
+                checksums: vec![Checksum {
+                    algorithm: "SHA256".to_string(),
+                    checksum_value: "abcdef".to_string(),
+                }],
+                homepage: None,
+                license_concluded: "NOASSERTION".to_string(),
+                license_declared: "MIT AND LicenseRef-Custom".to_string(),
+                copyright_text: "Copyright 2023\nExample".to_string(),
+                description: None,
+                external_refs: vec![ExternalRef {
+                    reference_category: "PACKAGE-MANAGER".to_string(),
+                    reference_type: "purl".to_string(),
+                    reference_locator: "pkg:cargo/app@1.0.0".to_string(),
+                    unmapped: BTreeMap::new(),
+                }],
+                primary_package_purpose: Some("APPLICATION".to_string()),
+                unmapped: BTreeMap::new(),
+            }],
+            has_extracted_licensing_infos: vec![ExtractedLicensingInfo {
+                license_id: "LicenseRef-Custom".to_string(),
+                extracted_text: "Custom".to_string(),
+                name: "Custom".to_string(),
+                see_alsos: vec!["https://example.com/license".to_string()],
+                unmapped: BTreeMap::new(),
+            }],
+            relationships: vec![Relationship {
+                spdx_element_id: "SPDXRef-DOCUMENT".to_string(),
+                relationship_type: "DESCRIBES".to_string(),
+                related_spdx_element: "SPDXRef-Package-app-1.0.0".to_string(),
+                unmapped: BTreeMap::new(),
+            }],
+            unmapped: BTreeMap::new(),
+        };
+
+        let mut output = Vec::new();
+        write_tag_value(&document, &mut output).expect("Failed to write tag-value");
+
+        assert_eq!(
+            String::from_utf8(output).expect("Failed to read output as a string"),
+            r#"SPDXVersion: SPDX-2.3
+DataLicense: CC0-1.0
+SPDXID: SPDXRef-DOCUMENT
+DocumentName: app
+DocumentNamespace: urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79
+Creator: Tool: cargo-cyclonedx-0.4.0
+Created: 2023-11-14T22:13:20Z
+
+PackageName: app
+SPDXID: SPDXRef-Package-app-1.0.0
+PackageVersion: 1.0.0
+PackageSupplier: Organization: Example
+PackageDownloadLocation: NOASSERTION
+FilesAnalyzed: false
+PackageChecksum: SHA256: abcdef
+PackageLicenseConcluded: NOASSERTION
+PackageLicenseDeclared: MIT AND LicenseRef-Custom
+PackageCopyrightText: <text>Copyright 2023
+Example</text>
+ExternalRef: PACKAGE-MANAGER purl pkg:cargo/app@1.0.0
+PrimaryPackagePurpose: APPLICATION
+
+LicenseID: LicenseRef-Custom
+ExtractedText: Custom
+LicenseName: Custom
+LicenseCrossReference: https://example.com/license
+
+Relationship: SPDXRef-DOCUMENT DESCRIBES SPDXRef-Package-app-1.0.0
+"#
+        );
+    }
+}
@@ -17,3 +17,5 @@
  */
 
 pub(crate) mod v1_3;
+pub(crate) mod v1_4;
+pub(crate) mod v1_5;
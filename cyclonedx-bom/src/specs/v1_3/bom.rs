@@ -31,6 +31,7 @@ use crate::{
         external_reference::ExternalReferences, metadata::Metadata, property::Properties,
         service::Services,
     },
+    specs::v1_4::signature::Signature,
     xml::ToXml,
 };
 use serde::{Deserialize, Serialize};
@@ -57,6 +58,10 @@ pub(crate) struct Bom {
     compositions: Option<Compositions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     properties: Option<Properties>,
+    /// Version 1.3 has no signature property, but the JSF signature of a signed JSON document is
+    /// carried along, so that the document can still be verified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<Signature>,
 }
 
 impl From<models::bom::Bom> for Bom {
@@ -73,6 +78,7 @@ impl From<models::bom::Bom> for Bom {
             dependencies: convert_optional(other.dependencies),
             compositions: convert_optional(other.compositions),
             properties: convert_optional(other.properties),
+            signature: convert_optional(other.signature),
         }
     }
 }
@@ -89,6 +95,10 @@ impl From<Bom> for models::bom::Bom {
             dependencies: convert_optional(other.dependencies),
             compositions: convert_optional(other.compositions),
             properties: convert_optional(other.properties),
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
+            signature: convert_optional(other.signature),
         }
     }
 }
@@ -306,6 +316,7 @@ impl FromXmlDocument for Bom {
             dependencies,
             compositions,
             properties,
+            signature: None,
         })
     }
 }
@@ -362,6 +373,7 @@ pub(crate) mod test {
             dependencies: None,
             compositions: None,
             properties: None,
+            signature: None,
         }
     }
 
@@ -378,6 +390,7 @@ pub(crate) mod test {
             dependencies: Some(example_dependencies()),
             compositions: Some(example_compositions()),
             properties: Some(example_properties()),
+            signature: None,
         }
     }
 
@@ -392,6 +405,10 @@ pub(crate) mod test {
             dependencies: Some(corresponding_dependencies()),
             compositions: Some(corresponding_compositions()),
             properties: Some(corresponding_properties()),
+            vulnerabilities: None,
+            annotations: None,
+            formulation: None,
+            signature: None,
         }
     }
 
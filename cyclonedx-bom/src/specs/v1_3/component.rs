@@ -207,6 +207,10 @@ impl From<Component> for models::component::Component {
             properties: convert_optional(other.properties),
             components: convert_optional(other.components),
             evidence: convert_optional(other.evidence),
+            release_notes: None,
+            model_card: None,
+            data: None,
+            signature: None,
         }
     }
 }
@@ -765,6 +769,9 @@ impl From<ComponentEvidence> for models::component::ComponentEvidence {
         Self {
             licenses: convert_optional(other.licenses),
             copyright: convert_optional(other.copyright),
+            identity: None,
+            occurrences: None,
+            callstack: None,
         }
     }
 }
@@ -1235,6 +1242,10 @@ pub(crate) mod test {
             properties: Some(corresponding_properties()),
             components: Some(corresponding_empty_components()),
             evidence: Some(corresponding_evidence()),
+            release_notes: None,
+            model_card: None,
+            data: None,
+            signature: None,
         }
     }
 
@@ -1311,6 +1322,9 @@ pub(crate) mod test {
         models::component::ComponentEvidence {
             licenses: Some(corresponding_licenses()),
             copyright: Some(corresponding_copyright_texts()),
+            identity: None,
+            occurrences: None,
+            callstack: None,
         }
     }
 
@@ -209,6 +209,7 @@ impl From<License> for models::license::License {
             license_identifier: other.license_identifier.into(),
             text: convert_optional(other.text),
             url: other.url.map(Uri),
+            licensing: None,
         }
     }
 }
@@ -432,6 +433,7 @@ pub(crate) mod test {
             )),
             text: Some(corresponding_attached_text()),
             url: Some(Uri("url".to_string())),
+            licensing: None,
         })
     }
 
@@ -451,6 +453,7 @@ pub(crate) mod test {
             ),
             text: Some(corresponding_attached_text()),
             url: Some(Uri("url".to_string())),
+            licensing: None,
         })
     }
 
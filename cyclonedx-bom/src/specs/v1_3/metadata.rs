@@ -72,6 +72,7 @@ impl From<Metadata> for models::metadata::Metadata {
     fn from(other: Metadata) -> Self {
         Self {
             timestamp: other.timestamp.map(DateTime),
+            lifecycles: None,
             tools: convert_optional(other.tools),
             authors: convert_optional_vec(other.authors),
             component: convert_optional(other.component),
@@ -304,6 +305,7 @@ pub(crate) mod test {
     pub(crate) fn corresponding_metadata() -> models::metadata::Metadata {
         models::metadata::Metadata {
             timestamp: Some(DateTime("timestamp".to_string())),
+            lifecycles: None,
             tools: Some(corresponding_tools()),
             authors: Some(vec![corresponding_contact()]),
             component: Some(corresponding_component()),
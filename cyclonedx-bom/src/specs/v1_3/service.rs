@@ -155,11 +155,14 @@ impl From<Service> for models::service::Service {
                 .map(|endpoints| endpoints.into_iter().map(Uri).collect()),
             authenticated: other.authenticated,
             x_trust_boundary: other.x_trust_boundary,
+            trust_zone: None,
             data: convert_optional_vec(other.data),
             licenses: convert_optional(other.licenses),
             external_references: convert_optional(other.external_references),
             properties: convert_optional(other.properties),
             services: convert_optional(other.services),
+            release_notes: None,
+            signature: None,
         }
     }
 }
@@ -535,11 +538,14 @@ pub(crate) mod test {
             endpoints: Some(vec![Uri("endpoint".to_string())]),
             authenticated: Some(true),
             x_trust_boundary: Some(true),
+            trust_zone: None,
             data: Some(vec![corresponding_data_classification()]),
             licenses: Some(corresponding_licenses()),
             external_references: Some(corresponding_external_references()),
             properties: Some(corresponding_properties()),
             services: Some(models::service::Services(vec![])),
+            release_notes: None,
+            signature: None,
         }
     }
 
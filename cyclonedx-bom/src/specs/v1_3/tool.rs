@@ -36,13 +36,13 @@ pub(crate) struct Tools(Vec<Tool>);
 
 impl From<models::tool::Tools> for Tools {
     fn from(other: models::tool::Tools) -> Self {
-        Tools(convert_vec(other.0))
+        Tools(convert_vec(other.into_list()))
     }
 }
 
 impl From<Tools> for models::tool::Tools {
     fn from(other: Tools) -> Self {
-        models::tool::Tools(convert_vec(other.0))
+        models::tool::Tools::List(convert_vec(other.0))
     }
 }
 
@@ -112,6 +112,7 @@ impl From<Tool> for models::tool::Tool {
             name: other.name.map(NormalizedString::new_unchecked),
             version: other.version.map(NormalizedString::new_unchecked),
             hashes: convert_optional(other.hashes),
+            external_references: None,
         }
     }
 }
@@ -231,7 +232,7 @@ pub(crate) mod test {
     }
 
     pub(crate) fn corresponding_tools() -> models::tool::Tools {
-        models::tool::Tools(vec![corresponding_tool()])
+        models::tool::Tools::List(vec![corresponding_tool()])
     }
 
     pub(crate) fn example_tool() -> Tool {
@@ -249,6 +250,7 @@ pub(crate) mod test {
             name: Some(NormalizedString::new_unchecked("name".to_string())),
             version: Some(NormalizedString::new_unchecked("version".to_string())),
             hashes: Some(corresponding_hashes()),
+            external_references: None,
         }
     }
 
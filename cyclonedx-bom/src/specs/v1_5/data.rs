@@ -0,0 +1,756 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::{
+    errors::{XmlReadError, XmlWriteError},
+    external_models::{normalized_string::NormalizedString, uri::Uri},
+    models,
+    specs::v1_5::{
+        attached_text::AttachedText,
+        organization::{OrganizationalContact, OrganizationalEntity},
+        property::Properties,
+        unsupported::{unsupported_element_error, Unsupported},
+    },
+    utilities::{convert_optional, convert_optional_vec},
+    xml::{
+        optional_attribute, read_lax_validation_tag, read_list_tag, read_simple_tag,
+        to_xml_read_error, to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml,
+        ToInnerXml, ToXml,
+    },
+};
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ComponentData {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(rename = "type")]
+    data_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contents: Option<DataContents>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    classification: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sensitive_data: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    governance: Option<DataGovernance>,
+    #[serde(default, skip_serializing)]
+    graphics: Option<Unsupported>,
+}
+
+impl From<models::data::ComponentData> for ComponentData {
+    fn from(other: models::data::ComponentData) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            data_type: other.data_type.to_string(),
+            name: other.name.map(|n| n.to_string()),
+            contents: convert_optional(other.contents),
+            classification: other.classification.map(|c| c.to_string()),
+            sensitive_data: other.sensitive_data,
+            description: other.description,
+            governance: convert_optional(other.governance),
+            graphics: None,
+        }
+    }
+}
+
+impl From<ComponentData> for models::data::ComponentData {
+    fn from(other: ComponentData) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            data_type: models::data::ComponentDataType::new_unchecked(other.data_type),
+            name: other.name.map(NormalizedString::new_unchecked),
+            contents: convert_optional(other.contents),
+            classification: other.classification.map(NormalizedString::new_unchecked),
+            sensitive_data: other.sensitive_data,
+            description: other.description,
+            governance: convert_optional(other.governance),
+        }
+    }
+}
+
+const BOM_REF_ATTR: &str = "bom-ref";
+const TYPE_TAG: &str = "type";
+const NAME_TAG: &str = "name";
+const CONTENTS_TAG: &str = "contents";
+const CLASSIFICATION_TAG: &str = "classification";
+const SENSITIVE_DATA_TAG: &str = "sensitiveData";
+const DESCRIPTION_TAG: &str = "description";
+const GOVERNANCE_TAG: &str = "governance";
+const GRAPHICS_TAG: &str = "graphics";
+
+impl ToInnerXml for ComponentData {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        let mut data_start_tag = XmlEvent::start_element(tag);
+
+        if let Some(bom_ref) = &self.bom_ref {
+            data_start_tag = data_start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+
+        writer
+            .write(data_start_tag)
+            .map_err(to_xml_write_error(tag))?;
+
+        write_simple_tag(writer, TYPE_TAG, &self.data_type)?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(contents) = &self.contents {
+            contents.write_xml_element(writer)?;
+        }
+
+        if let Some(classification) = &self.classification {
+            write_simple_tag(writer, CLASSIFICATION_TAG, classification)?;
+        }
+
+        if let Some(sensitive_data) = &self.sensitive_data {
+            for sensitive_data in sensitive_data {
+                write_simple_tag(writer, SENSITIVE_DATA_TAG, sensitive_data)?;
+            }
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(governance) = &self.governance {
+            governance.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(tag))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for ComponentData {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut data_type: Option<String> = None;
+        let mut data_name: Option<String> = None;
+        let mut contents: Option<DataContents> = None;
+        let mut classification: Option<String> = None;
+        let mut sensitive_data: Option<Vec<String>> = None;
+        let mut description: Option<String> = None;
+        let mut governance: Option<DataGovernance> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(&element_name.local_name))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TYPE_TAG => {
+                    data_type = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    data_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == CONTENTS_TAG => {
+                    contents = Some(DataContents::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == CLASSIFICATION_TAG =>
+                {
+                    classification = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == SENSITIVE_DATA_TAG =>
+                {
+                    sensitive_data
+                        .get_or_insert_with(Vec::new)
+                        .push(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == GOVERNANCE_TAG => {
+                    governance = Some(DataGovernance::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == GRAPHICS_TAG => {
+                    return Err(unsupported_element_error(&name))
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let data_type = data_type.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TYPE_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            data_type,
+            name: data_name,
+            contents,
+            classification,
+            sensitive_data,
+            description,
+            governance,
+            graphics: None,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct DataContents {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachment: Option<AttachedText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::data::DataContents> for DataContents {
+    fn from(other: models::data::DataContents) -> Self {
+        Self {
+            attachment: convert_optional(other.attachment),
+            url: other.url.map(|u| u.to_string()),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<DataContents> for models::data::DataContents {
+    fn from(other: DataContents) -> Self {
+        Self {
+            attachment: convert_optional(other.attachment),
+            url: other.url.map(Uri),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const ATTACHMENT_TAG: &str = "attachment";
+const URL_TAG: &str = "url";
+const PROPERTIES_TAG: &str = "properties";
+
+impl ToXml for DataContents {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(CONTENTS_TAG))
+            .map_err(to_xml_write_error(CONTENTS_TAG))?;
+
+        if let Some(attachment) = &self.attachment {
+            attachment.write_xml_named_element(writer, ATTACHMENT_TAG)?;
+        }
+
+        if let Some(url) = &self.url {
+            write_simple_tag(writer, URL_TAG, url)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(CONTENTS_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for DataContents {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut attachment: Option<AttachedText> = None;
+        let mut url: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(CONTENTS_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ATTACHMENT_TAG => {
+                    attachment = Some(AttachedText::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == URL_TAG => {
+                    url = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            attachment,
+            url,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct DataGovernance {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custodians: Option<Vec<DataGovernanceResponsibleParty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stewards: Option<Vec<DataGovernanceResponsibleParty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owners: Option<Vec<DataGovernanceResponsibleParty>>,
+}
+
+impl From<models::data::DataGovernance> for DataGovernance {
+    fn from(other: models::data::DataGovernance) -> Self {
+        Self {
+            custodians: convert_optional_vec(other.custodians),
+            stewards: convert_optional_vec(other.stewards),
+            owners: convert_optional_vec(other.owners),
+        }
+    }
+}
+
+impl From<DataGovernance> for models::data::DataGovernance {
+    fn from(other: DataGovernance) -> Self {
+        Self {
+            custodians: convert_optional_vec(other.custodians),
+            stewards: convert_optional_vec(other.stewards),
+            owners: convert_optional_vec(other.owners),
+        }
+    }
+}
+
+const CUSTODIANS_TAG: &str = "custodians";
+const CUSTODIAN_TAG: &str = "custodian";
+const STEWARDS_TAG: &str = "stewards";
+const STEWARD_TAG: &str = "steward";
+const OWNERS_TAG: &str = "owners";
+const OWNER_TAG: &str = "owner";
+
+impl ToXml for DataGovernance {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(GOVERNANCE_TAG))
+            .map_err(to_xml_write_error(GOVERNANCE_TAG))?;
+
+        for (list_tag, item_tag, parties) in [
+            (CUSTODIANS_TAG, CUSTODIAN_TAG, &self.custodians),
+            (STEWARDS_TAG, STEWARD_TAG, &self.stewards),
+            (OWNERS_TAG, OWNER_TAG, &self.owners),
+        ] {
+            if let Some(parties) = parties {
+                writer
+                    .write(XmlEvent::start_element(list_tag))
+                    .map_err(to_xml_write_error(list_tag))?;
+
+                for party in parties {
+                    party.write_xml_named_element(writer, item_tag)?;
+                }
+
+                writer
+                    .write(XmlEvent::end_element())
+                    .map_err(to_xml_write_error(list_tag))?;
+            }
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(GOVERNANCE_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for DataGovernance {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut custodians: Option<Vec<DataGovernanceResponsibleParty>> = None;
+        let mut stewards: Option<Vec<DataGovernanceResponsibleParty>> = None;
+        let mut owners: Option<Vec<DataGovernanceResponsibleParty>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(GOVERNANCE_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == CUSTODIANS_TAG =>
+                {
+                    custodians = Some(read_list_tag(event_reader, &name, CUSTODIAN_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == STEWARDS_TAG => {
+                    stewards = Some(read_list_tag(event_reader, &name, STEWARD_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == OWNERS_TAG => {
+                    owners = Some(read_list_tag(event_reader, &name, OWNER_TAG)?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            custodians,
+            stewards,
+            owners,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+enum DataGovernanceResponsibleParty {
+    Organization(OrganizationalEntity),
+    Contact(OrganizationalContact),
+}
+
+impl From<models::data::DataGovernanceResponsibleParty> for DataGovernanceResponsibleParty {
+    fn from(other: models::data::DataGovernanceResponsibleParty) -> Self {
+        match other {
+            models::data::DataGovernanceResponsibleParty::Organization(organization) => {
+                Self::Organization(organization.into())
+            }
+            models::data::DataGovernanceResponsibleParty::Contact(contact) => {
+                Self::Contact(contact.into())
+            }
+        }
+    }
+}
+
+impl From<DataGovernanceResponsibleParty> for models::data::DataGovernanceResponsibleParty {
+    fn from(other: DataGovernanceResponsibleParty) -> Self {
+        match other {
+            DataGovernanceResponsibleParty::Organization(organization) => {
+                Self::Organization(organization.into())
+            }
+            DataGovernanceResponsibleParty::Contact(contact) => Self::Contact(contact.into()),
+        }
+    }
+}
+
+const ORGANIZATION_TAG: &str = "organization";
+const CONTACT_TAG: &str = "contact";
+
+impl ToInnerXml for DataGovernanceResponsibleParty {
+    fn write_xml_named_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+        tag: &str,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(tag))
+            .map_err(to_xml_write_error(tag))?;
+
+        match self {
+            DataGovernanceResponsibleParty::Organization(organization) => {
+                organization.write_xml_named_element(writer, ORGANIZATION_TAG)?
+            }
+            DataGovernanceResponsibleParty::Contact(contact) => {
+                contact.write_xml_named_element(writer, CONTACT_TAG)?
+            }
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(tag))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for DataGovernanceResponsibleParty {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut party: Option<Self> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(&element_name.local_name))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ORGANIZATION_TAG => {
+                    party = Some(Self::Organization(OrganizationalEntity::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?))
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == CONTACT_TAG => {
+                    party = Some(Self::Contact(OrganizationalContact::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?))
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        party.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: format!("{} or {}", ORGANIZATION_TAG, CONTACT_TAG),
+            element: element_name.local_name.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use crate::{
+        specs::v1_5::{
+            attached_text::test::{corresponding_attached_text, example_attached_text},
+            organization::test::{
+                corresponding_contact, corresponding_entity, example_contact, example_entity,
+            },
+            property::test::{corresponding_properties, example_properties},
+        },
+        xml::test::{
+            read_element_from_string, try_read_element_from_string, write_named_element_to_string,
+        },
+    };
+
+    use super::*;
+
+    pub(crate) fn example_component_data() -> ComponentData {
+        ComponentData {
+            bom_ref: Some("bom-ref".to_string()),
+            data_type: "dataset".to_string(),
+            name: Some("name".to_string()),
+            contents: Some(DataContents {
+                attachment: Some(example_attached_text()),
+                url: Some("url".to_string()),
+                properties: Some(example_properties()),
+            }),
+            classification: Some("classification".to_string()),
+            sensitive_data: Some(vec!["sensitive data".to_string()]),
+            description: Some("description".to_string()),
+            governance: Some(DataGovernance {
+                custodians: Some(vec![DataGovernanceResponsibleParty::Organization(
+                    example_entity(),
+                )]),
+                stewards: None,
+                owners: Some(vec![DataGovernanceResponsibleParty::Contact(
+                    example_contact(),
+                )]),
+            }),
+            graphics: None,
+        }
+    }
+
+    pub(crate) fn corresponding_component_data() -> models::data::ComponentData {
+        models::data::ComponentData {
+            bom_ref: Some("bom-ref".to_string()),
+            data_type: models::data::ComponentDataType::Dataset,
+            name: Some(NormalizedString::new_unchecked("name".to_string())),
+            contents: Some(models::data::DataContents {
+                attachment: Some(corresponding_attached_text()),
+                url: Some(Uri("url".to_string())),
+                properties: Some(corresponding_properties()),
+            }),
+            classification: Some(NormalizedString::new_unchecked(
+                "classification".to_string(),
+            )),
+            sensitive_data: Some(vec!["sensitive data".to_string()]),
+            description: Some("description".to_string()),
+            governance: Some(models::data::DataGovernance {
+                custodians: Some(vec![
+                    models::data::DataGovernanceResponsibleParty::Organization(
+                        corresponding_entity(),
+                    ),
+                ]),
+                stewards: None,
+                owners: Some(vec![models::data::DataGovernanceResponsibleParty::Contact(
+                    corresponding_contact(),
+                )]),
+            }),
+        }
+    }
+
+    #[test]
+    fn it_can_convert_to_the_internal_model() {
+        let actual: models::data::ComponentData = example_component_data().into();
+        assert_eq!(actual, corresponding_component_data());
+    }
+
+    #[test]
+    fn it_can_convert_from_the_internal_model() {
+        let actual: ComponentData = corresponding_component_data().into();
+        assert_eq!(actual, example_component_data());
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_named_element_to_string(example_component_data(), "data");
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<data bom-ref="bom-ref">
+  <type>dataset</type>
+  <name>name</name>
+  <contents>
+    <attachment content-type="content type" encoding="encoding">content</attachment>
+    <url>url</url>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+  </contents>
+  <classification>classification</classification>
+  <sensitiveData>sensitive data</sensitiveData>
+  <description>description</description>
+  <governance>
+    <custodians>
+      <custodian>
+        <organization>
+          <name>name</name>
+          <url>url</url>
+          <contact>
+            <name>name</name>
+            <email>email</email>
+            <phone>phone</phone>
+          </contact>
+        </organization>
+      </custodian>
+    </custodians>
+    <owners>
+      <owner>
+        <contact>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </contact>
+      </owner>
+    </owners>
+  </governance>
+</data>
+"#;
+        let actual: ComponentData = read_element_from_string(input);
+        let expected = example_component_data();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_fail_to_read_xml_with_graphics() {
+        let input = r#"
+<data>
+  <type>dataset</type>
+  <graphics>
+    <description>graphics</description>
+  </graphics>
+</data>
+"#;
+        let error =
+            try_read_element_from_string::<ComponentData>(input).expect_err("Read the graphics");
+        assert!(matches!(
+            error,
+            XmlReadError::UnsupportedElement { element } if element == "graphics"
+        ));
+    }
+}
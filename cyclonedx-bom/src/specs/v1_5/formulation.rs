@@ -0,0 +1,1232 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::{
+    errors::{XmlReadError, XmlWriteError},
+    external_models::{date_time::DateTime, normalized_string::NormalizedString},
+    models,
+    specs::v1_5::{
+        component::Components,
+        dependency::Dependencies,
+        property::Properties,
+        service::Services,
+        unsupported::{unsupported_element_error, Unsupported},
+    },
+    utilities::{convert_optional, convert_optional_vec, convert_vec},
+    xml::{
+        attribute_or_error, optional_attribute, read_lax_validation_list_tag,
+        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
+    },
+};
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(transparent)]
+pub(crate) struct Formulation(Vec<Formula>);
+
+impl From<models::formulation::Formulation> for Formulation {
+    fn from(other: models::formulation::Formulation) -> Self {
+        Formulation(convert_vec(other.0))
+    }
+}
+
+impl From<Formulation> for models::formulation::Formulation {
+    fn from(other: Formulation) -> Self {
+        models::formulation::Formulation(convert_vec(other.0))
+    }
+}
+
+const FORMULATION_TAG: &str = "formulation";
+
+impl ToXml for Formulation {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(FORMULATION_TAG))
+            .map_err(to_xml_write_error(FORMULATION_TAG))?;
+
+        for formula in &self.0 {
+            formula.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(FORMULATION_TAG))?;
+        Ok(())
+    }
+}
+
+impl FromXml for Formulation {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        read_lax_validation_list_tag(event_reader, element_name, FORMULA_TAG).map(Formulation)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Formula {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Components>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    services: Option<Services>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workflows: Option<Vec<Workflow>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Formula> for Formula {
+    fn from(other: models::formulation::Formula) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            components: convert_optional(other.components),
+            services: convert_optional(other.services),
+            workflows: convert_optional_vec(other.workflows),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Formula> for models::formulation::Formula {
+    fn from(other: Formula) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            components: convert_optional(other.components),
+            services: convert_optional(other.services),
+            workflows: convert_optional_vec(other.workflows),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const FORMULA_TAG: &str = "formula";
+const BOM_REF_ATTR: &str = "bom-ref";
+const COMPONENTS_TAG: &str = "components";
+const SERVICES_TAG: &str = "services";
+const WORKFLOWS_TAG: &str = "workflows";
+const WORKFLOW_TAG: &str = "workflow";
+const PROPERTIES_TAG: &str = "properties";
+
+impl ToXml for Formula {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        let mut formula_start_tag = XmlEvent::start_element(FORMULA_TAG);
+
+        if let Some(bom_ref) = &self.bom_ref {
+            formula_start_tag = formula_start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+
+        writer
+            .write(formula_start_tag)
+            .map_err(to_xml_write_error(FORMULA_TAG))?;
+
+        if let Some(components) = &self.components {
+            components.write_xml_element(writer)?;
+        }
+
+        if let Some(services) = &self.services {
+            services.write_xml_element(writer)?;
+        }
+
+        if let Some(workflows) = &self.workflows {
+            writer
+                .write(XmlEvent::start_element(WORKFLOWS_TAG))
+                .map_err(to_xml_write_error(WORKFLOWS_TAG))?;
+
+            for workflow in workflows {
+                workflow.write_xml_element(writer)?;
+            }
+
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(WORKFLOWS_TAG))?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(FORMULA_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Formula {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+        let mut components: Option<Components> = None;
+        let mut services: Option<Services> = None;
+        let mut workflows: Option<Vec<Workflow>> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(FORMULA_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == COMPONENTS_TAG => {
+                    components = Some(Components::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SERVICES_TAG => {
+                    services = Some(Services::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == WORKFLOWS_TAG => {
+                    workflows = Some(read_list_tag(event_reader, &name, WORKFLOW_TAG)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            bom_ref,
+            components,
+            services,
+            workflows,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Workflow {
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tasks: Option<Vec<Task>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    task_dependencies: Option<Dependencies>,
+    task_types: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    steps: Option<Vec<Step>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_start: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+    #[serde(flatten, skip_serializing)]
+    unsupported: UnsupportedFields,
+}
+
+impl From<models::formulation::Workflow> for Workflow {
+    fn from(other: models::formulation::Workflow) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: other.uid.to_string(),
+            name: other.name.map(|n| n.to_string()),
+            description: other.description,
+            tasks: convert_optional_vec(other.tasks),
+            task_dependencies: convert_optional(other.task_dependencies),
+            task_types: other.task_types.iter().map(ToString::to_string).collect(),
+            steps: convert_optional_vec(other.steps),
+            time_start: other.time_start.map(|t| t.to_string()),
+            time_end: other.time_end.map(|t| t.to_string()),
+            properties: convert_optional(other.properties),
+            unsupported: UnsupportedFields::default(),
+        }
+    }
+}
+
+impl From<Workflow> for models::formulation::Workflow {
+    fn from(other: Workflow) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: NormalizedString::new_unchecked(other.uid),
+            name: other.name.map(NormalizedString::new_unchecked),
+            description: other.description,
+            tasks: convert_optional_vec(other.tasks),
+            task_dependencies: convert_optional(other.task_dependencies),
+            task_types: other
+                .task_types
+                .into_iter()
+                .map(models::formulation::TaskType::new_unchecked)
+                .collect(),
+            steps: convert_optional_vec(other.steps),
+            time_start: other.time_start.map(DateTime),
+            time_end: other.time_end.map(DateTime),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+/// Workflow and task fields that the model cannot hold yet
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct UnsupportedFields {
+    #[serde(default)]
+    resource_references: Option<Unsupported>,
+    #[serde(default)]
+    trigger: Option<Unsupported>,
+    #[serde(default)]
+    inputs: Option<Unsupported>,
+    #[serde(default)]
+    outputs: Option<Unsupported>,
+    #[serde(default)]
+    workspaces: Option<Unsupported>,
+    #[serde(default)]
+    runtime_topology: Option<Unsupported>,
+}
+
+const UNSUPPORTED_TAGS: &[&str] = &[
+    "resourceReferences",
+    "trigger",
+    "inputs",
+    "outputs",
+    "workspaces",
+    "runtimeTopology",
+];
+
+const UID_TAG: &str = "uid";
+const NAME_TAG: &str = "name";
+const DESCRIPTION_TAG: &str = "description";
+const TASKS_TAG: &str = "tasks";
+const TASK_TAG: &str = "task";
+const TASK_DEPENDENCIES_TAG: &str = "taskDependencies";
+const TASK_TYPES_TAG: &str = "taskTypes";
+const TASK_TYPE_TAG: &str = "taskType";
+const STEPS_TAG: &str = "steps";
+const STEP_TAG: &str = "step";
+const TIME_START_TAG: &str = "timeStart";
+const TIME_END_TAG: &str = "timeEnd";
+
+impl ToXml for Workflow {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(WORKFLOW_TAG).attr(BOM_REF_ATTR, &self.bom_ref))
+            .map_err(to_xml_write_error(WORKFLOW_TAG))?;
+
+        write_simple_tag(writer, UID_TAG, &self.uid)?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(tasks) = &self.tasks {
+            writer
+                .write(XmlEvent::start_element(TASKS_TAG))
+                .map_err(to_xml_write_error(TASKS_TAG))?;
+
+            for task in tasks {
+                task.write_xml_element(writer)?;
+            }
+
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(TASKS_TAG))?;
+        }
+
+        if let Some(task_dependencies) = &self.task_dependencies {
+            task_dependencies.write_xml_named_element(writer, TASK_DEPENDENCIES_TAG)?;
+        }
+
+        write_task_types(writer, &self.task_types)?;
+
+        if let Some(steps) = &self.steps {
+            write_steps(writer, steps)?;
+        }
+
+        if let Some(time_start) = &self.time_start {
+            write_simple_tag(writer, TIME_START_TAG, time_start)?;
+        }
+
+        if let Some(time_end) = &self.time_end {
+            write_simple_tag(writer, TIME_END_TAG, time_end)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(WORKFLOW_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Workflow {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = attribute_or_error(element_name, attributes, BOM_REF_ATTR)?;
+        let mut uid: Option<String> = None;
+        let mut workflow_name: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut tasks: Option<Vec<Task>> = None;
+        let mut task_dependencies: Option<Dependencies> = None;
+        let mut task_types: Option<Vec<String>> = None;
+        let mut steps: Option<Vec<Step>> = None;
+        let mut time_start: Option<String> = None;
+        let mut time_end: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(WORKFLOW_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == UID_TAG => {
+                    uid = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    workflow_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TASKS_TAG => {
+                    tasks = Some(read_list_tag(event_reader, &name, TASK_TAG)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == TASK_DEPENDENCIES_TAG => {
+                    task_dependencies = Some(Dependencies::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TASK_TYPES_TAG =>
+                {
+                    task_types = Some(read_list_tag(event_reader, &name, TASK_TYPE_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == STEPS_TAG => {
+                    steps = Some(read_list_tag(event_reader, &name, STEP_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TIME_START_TAG =>
+                {
+                    time_start = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TIME_END_TAG => {
+                    time_end = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if UNSUPPORTED_TAGS.contains(&name.local_name.as_str()) =>
+                {
+                    return Err(unsupported_element_error(&name))
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let uid = uid.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: UID_TAG.to_string(),
+            element: WORKFLOW_TAG.to_string(),
+        })?;
+        let task_types = task_types.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TASK_TYPES_TAG.to_string(),
+            element: WORKFLOW_TAG.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            uid,
+            name: workflow_name,
+            description,
+            tasks,
+            task_dependencies,
+            task_types,
+            steps,
+            time_start,
+            time_end,
+            properties,
+            unsupported: UnsupportedFields::default(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Task {
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    uid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    task_types: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    steps: Option<Vec<Step>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_start: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+    #[serde(flatten, skip_serializing)]
+    unsupported: UnsupportedFields,
+}
+
+impl From<models::formulation::Task> for Task {
+    fn from(other: models::formulation::Task) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: other.uid.to_string(),
+            name: other.name.map(|n| n.to_string()),
+            description: other.description,
+            task_types: other.task_types.iter().map(ToString::to_string).collect(),
+            steps: convert_optional_vec(other.steps),
+            time_start: other.time_start.map(|t| t.to_string()),
+            time_end: other.time_end.map(|t| t.to_string()),
+            properties: convert_optional(other.properties),
+            unsupported: UnsupportedFields::default(),
+        }
+    }
+}
+
+impl From<Task> for models::formulation::Task {
+    fn from(other: Task) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            uid: NormalizedString::new_unchecked(other.uid),
+            name: other.name.map(NormalizedString::new_unchecked),
+            description: other.description,
+            task_types: other
+                .task_types
+                .into_iter()
+                .map(models::formulation::TaskType::new_unchecked)
+                .collect(),
+            steps: convert_optional_vec(other.steps),
+            time_start: other.time_start.map(DateTime),
+            time_end: other.time_end.map(DateTime),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl ToXml for Task {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(TASK_TAG).attr(BOM_REF_ATTR, &self.bom_ref))
+            .map_err(to_xml_write_error(TASK_TAG))?;
+
+        write_simple_tag(writer, UID_TAG, &self.uid)?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        write_task_types(writer, &self.task_types)?;
+
+        if let Some(steps) = &self.steps {
+            write_steps(writer, steps)?;
+        }
+
+        if let Some(time_start) = &self.time_start {
+            write_simple_tag(writer, TIME_START_TAG, time_start)?;
+        }
+
+        if let Some(time_end) = &self.time_end {
+            write_simple_tag(writer, TIME_END_TAG, time_end)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(TASK_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Task {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = attribute_or_error(element_name, attributes, BOM_REF_ATTR)?;
+        let mut uid: Option<String> = None;
+        let mut task_name: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut task_types: Option<Vec<String>> = None;
+        let mut steps: Option<Vec<Step>> = None;
+        let mut time_start: Option<String> = None;
+        let mut time_end: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(TASK_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == UID_TAG => {
+                    uid = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    task_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TASK_TYPES_TAG =>
+                {
+                    task_types = Some(read_list_tag(event_reader, &name, TASK_TYPE_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == STEPS_TAG => {
+                    steps = Some(read_list_tag(event_reader, &name, STEP_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == TIME_START_TAG =>
+                {
+                    time_start = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TIME_END_TAG => {
+                    time_end = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if UNSUPPORTED_TAGS.contains(&name.local_name.as_str()) =>
+                {
+                    return Err(unsupported_element_error(&name))
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let uid = uid.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: UID_TAG.to_string(),
+            element: TASK_TAG.to_string(),
+        })?;
+        let task_types = task_types.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TASK_TYPES_TAG.to_string(),
+            element: TASK_TAG.to_string(),
+        })?;
+
+        Ok(Self {
+            bom_ref,
+            uid,
+            name: task_name,
+            description,
+            task_types,
+            steps,
+            time_start,
+            time_end,
+            properties,
+            unsupported: UnsupportedFields::default(),
+        })
+    }
+}
+
+fn write_task_types<W: std::io::Write>(
+    writer: &mut xml::EventWriter<W>,
+    task_types: &[String],
+) -> Result<(), XmlWriteError> {
+    writer
+        .write(XmlEvent::start_element(TASK_TYPES_TAG))
+        .map_err(to_xml_write_error(TASK_TYPES_TAG))?;
+
+    for task_type in task_types {
+        write_simple_tag(writer, TASK_TYPE_TAG, task_type)?;
+    }
+
+    writer
+        .write(XmlEvent::end_element())
+        .map_err(to_xml_write_error(TASK_TYPES_TAG))?;
+
+    Ok(())
+}
+
+fn write_steps<W: std::io::Write>(
+    writer: &mut xml::EventWriter<W>,
+    steps: &[Step],
+) -> Result<(), XmlWriteError> {
+    writer
+        .write(XmlEvent::start_element(STEPS_TAG))
+        .map_err(to_xml_write_error(STEPS_TAG))?;
+
+    for step in steps {
+        step.write_xml_element(writer)?;
+    }
+
+    writer
+        .write(XmlEvent::end_element())
+        .map_err(to_xml_write_error(STEPS_TAG))?;
+
+    Ok(())
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Step {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commands: Option<Vec<Command>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Step> for Step {
+    fn from(other: models::formulation::Step) -> Self {
+        Self {
+            name: other.name.map(|n| n.to_string()),
+            description: other.description,
+            commands: convert_optional_vec(other.commands),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Step> for models::formulation::Step {
+    fn from(other: Step) -> Self {
+        Self {
+            name: other.name.map(NormalizedString::new_unchecked),
+            description: other.description,
+            commands: convert_optional_vec(other.commands),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const COMMANDS_TAG: &str = "commands";
+const COMMAND_TAG: &str = "command";
+
+impl ToXml for Step {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(STEP_TAG))
+            .map_err(to_xml_write_error(STEP_TAG))?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(commands) = &self.commands {
+            writer
+                .write(XmlEvent::start_element(COMMANDS_TAG))
+                .map_err(to_xml_write_error(COMMANDS_TAG))?;
+
+            for command in commands {
+                command.write_xml_element(writer)?;
+            }
+
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(COMMANDS_TAG))?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(STEP_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Step {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut step_name: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut commands: Option<Vec<Command>> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(STEP_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    step_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == COMMANDS_TAG => {
+                    commands = Some(read_list_tag(event_reader, &name, COMMAND_TAG)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            name: step_name,
+            description,
+            commands,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Command {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    executed: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::formulation::Command> for Command {
+    fn from(other: models::formulation::Command) -> Self {
+        Self {
+            executed: other.executed,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Command> for models::formulation::Command {
+    fn from(other: Command) -> Self {
+        Self {
+            executed: other.executed,
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const EXECUTED_TAG: &str = "executed";
+
+impl ToXml for Command {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(COMMAND_TAG))
+            .map_err(to_xml_write_error(COMMAND_TAG))?;
+
+        if let Some(executed) = &self.executed {
+            write_simple_tag(writer, EXECUTED_TAG, executed)?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(COMMAND_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Command {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut executed: Option<String> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(COMMAND_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == EXECUTED_TAG => {
+                    executed = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            executed,
+            properties,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use crate::{
+        specs::v1_5::{
+            dependency::test::{corresponding_dependencies, example_dependencies},
+            property::test::{corresponding_properties, example_properties},
+        },
+        xml::test::{
+            read_element_from_string, try_read_element_from_string, write_element_to_string,
+        },
+    };
+
+    use super::*;
+
+    pub(crate) fn example_formulation() -> Formulation {
+        Formulation(vec![Formula {
+            bom_ref: Some("formula-1".to_string()),
+            components: None,
+            services: None,
+            workflows: Some(vec![Workflow {
+                bom_ref: "workflow-1".to_string(),
+                uid: "uid".to_string(),
+                name: Some("name".to_string()),
+                description: Some("description".to_string()),
+                tasks: Some(vec![Task {
+                    bom_ref: "task-1".to_string(),
+                    uid: "task uid".to_string(),
+                    name: Some("task name".to_string()),
+                    description: None,
+                    task_types: vec!["build".to_string()],
+                    steps: None,
+                    time_start: None,
+                    time_end: None,
+                    properties: None,
+                    unsupported: UnsupportedFields::default(),
+                }]),
+                task_dependencies: Some(example_dependencies()),
+                task_types: vec!["clone".to_string(), "build".to_string()],
+                steps: Some(vec![Step {
+                    name: Some("step".to_string()),
+                    description: Some("description".to_string()),
+                    commands: Some(vec![Command {
+                        executed: Some("cargo build".to_string()),
+                        properties: None,
+                    }]),
+                    properties: None,
+                }]),
+                time_start: Some("time start".to_string()),
+                time_end: Some("time end".to_string()),
+                properties: None,
+                unsupported: UnsupportedFields::default(),
+            }]),
+            properties: Some(example_properties()),
+        }])
+    }
+
+    pub(crate) fn corresponding_formulation() -> models::formulation::Formulation {
+        models::formulation::Formulation(vec![models::formulation::Formula {
+            bom_ref: Some("formula-1".to_string()),
+            components: None,
+            services: None,
+            workflows: Some(vec![models::formulation::Workflow {
+                bom_ref: "workflow-1".to_string(),
+                uid: NormalizedString::new_unchecked("uid".to_string()),
+                name: Some(NormalizedString::new_unchecked("name".to_string())),
+                description: Some("description".to_string()),
+                tasks: Some(vec![models::formulation::Task {
+                    bom_ref: "task-1".to_string(),
+                    uid: NormalizedString::new_unchecked("task uid".to_string()),
+                    name: Some(NormalizedString::new_unchecked("task name".to_string())),
+                    description: None,
+                    task_types: vec![models::formulation::TaskType::Build],
+                    steps: None,
+                    time_start: None,
+                    time_end: None,
+                    properties: None,
+                }]),
+                task_dependencies: Some(corresponding_dependencies()),
+                task_types: vec![
+                    models::formulation::TaskType::Clone,
+                    models::formulation::TaskType::Build,
+                ],
+                steps: Some(vec![models::formulation::Step {
+                    name: Some(NormalizedString::new_unchecked("step".to_string())),
+                    description: Some("description".to_string()),
+                    commands: Some(vec![models::formulation::Command {
+                        executed: Some("cargo build".to_string()),
+                        properties: None,
+                    }]),
+                    properties: None,
+                }]),
+                time_start: Some(DateTime("time start".to_string())),
+                time_end: Some(DateTime("time end".to_string())),
+                properties: None,
+            }]),
+            properties: Some(corresponding_properties()),
+        }])
+    }
+
+    #[test]
+    fn it_can_convert_to_the_internal_model() {
+        let actual: models::formulation::Formulation = example_formulation().into();
+        assert_eq!(actual, corresponding_formulation());
+    }
+
+    #[test]
+    fn it_can_convert_from_the_internal_model() {
+        let actual: Formulation = corresponding_formulation().into();
+        assert_eq!(actual, example_formulation());
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_formulation());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<formulation>
+  <formula bom-ref="formula-1">
+    <workflows>
+      <workflow bom-ref="workflow-1">
+        <uid>uid</uid>
+        <name>name</name>
+        <description>description</description>
+        <tasks>
+          <task bom-ref="task-1">
+            <uid>task uid</uid>
+            <name>task name</name>
+            <taskTypes>
+              <taskType>build</taskType>
+            </taskTypes>
+          </task>
+        </tasks>
+        <taskDependencies>
+          <dependency ref="ref">
+            <dependency ref="depends on" />
+          </dependency>
+        </taskDependencies>
+        <taskTypes>
+          <taskType>clone</taskType>
+          <taskType>build</taskType>
+        </taskTypes>
+        <steps>
+          <step>
+            <name>step</name>
+            <description>description</description>
+            <commands>
+              <command>
+                <executed>cargo build</executed>
+              </command>
+            </commands>
+          </step>
+        </steps>
+        <timeStart>time start</timeStart>
+        <timeEnd>time end</timeEnd>
+      </workflow>
+    </workflows>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+  </formula>
+</formulation>
+"#;
+        let actual: Formulation = read_element_from_string(input);
+        let expected = example_formulation();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_fail_to_read_json_with_unsupported_fields() {
+        let input = r#"{
+  "bom-ref": "workflow-1",
+  "uid": "uid",
+  "taskTypes": ["build"],
+  "trigger": {
+    "bom-ref": "trigger-1",
+    "uid": "trigger",
+    "type": "manual"
+  }
+}"#;
+        let error = serde_json::from_str::<Workflow>(input).expect_err("Read the trigger");
+        assert!(error.to_string().contains("not supported"));
+    }
+
+    #[test]
+    fn it_should_fail_to_read_xml_with_unsupported_elements() {
+        let input = r#"
+<task bom-ref="task-1">
+  <uid>task uid</uid>
+  <taskTypes>
+    <taskType>build</taskType>
+  </taskTypes>
+  <inputs>
+    <input>
+      <resource>
+        <ref>component-1</ref>
+      </resource>
+    </input>
+  </inputs>
+</task>
+"#;
+        let error = try_read_element_from_string::<Task>(input).expect_err("Read the inputs");
+        assert!(matches!(
+            error,
+            XmlReadError::UnsupportedElement { element } if element == "inputs"
+        ));
+    }
+}
@@ -0,0 +1,546 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::{
+    errors::XmlReadError,
+    external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
+    models,
+    specs::v1_5::{
+        attached_text::AttachedText,
+        code::{Issue, ISSUE_TAG},
+        property::Properties,
+    },
+    utilities::{convert_optional, convert_optional_vec},
+    xml::{
+        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
+    },
+};
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ReleaseNotes {
+    #[serde(rename = "type")]
+    release_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    featured_image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    social_image: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    aliases: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolves: Option<Vec<Issue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notes: Option<Vec<Note>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::release_notes::ReleaseNotes> for ReleaseNotes {
+    fn from(other: models::release_notes::ReleaseNotes) -> Self {
+        Self {
+            release_type: other.release_type.to_string(),
+            title: other.title.map(|t| t.to_string()),
+            featured_image: other.featured_image.map(|f| f.to_string()),
+            social_image: other.social_image.map(|s| s.to_string()),
+            description: other.description,
+            timestamp: other.timestamp.map(|t| t.to_string()),
+            aliases: other
+                .aliases
+                .map(|aliases| aliases.into_iter().map(|a| a.to_string()).collect()),
+            tags: other
+                .tags
+                .map(|tags| tags.into_iter().map(|t| t.to_string()).collect()),
+            resolves: convert_optional_vec(other.resolves),
+            notes: convert_optional_vec(other.notes),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<ReleaseNotes> for models::release_notes::ReleaseNotes {
+    fn from(other: ReleaseNotes) -> Self {
+        Self {
+            release_type: NormalizedString::new_unchecked(other.release_type),
+            title: other.title.map(NormalizedString::new_unchecked),
+            featured_image: other.featured_image.map(Uri),
+            social_image: other.social_image.map(Uri),
+            description: other.description,
+            timestamp: other.timestamp.map(DateTime),
+            aliases: other.aliases.map(|aliases| {
+                aliases
+                    .into_iter()
+                    .map(NormalizedString::new_unchecked)
+                    .collect()
+            }),
+            tags: other.tags.map(|tags| {
+                tags.into_iter()
+                    .map(NormalizedString::new_unchecked)
+                    .collect()
+            }),
+            resolves: convert_optional_vec(other.resolves),
+            notes: convert_optional_vec(other.notes),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const RELEASE_NOTES_TAG: &str = "releaseNotes";
+const TYPE_TAG: &str = "type";
+const TITLE_TAG: &str = "title";
+const FEATURED_IMAGE_TAG: &str = "featuredImage";
+const SOCIAL_IMAGE_TAG: &str = "socialImage";
+const DESCRIPTION_TAG: &str = "description";
+const TIMESTAMP_TAG: &str = "timestamp";
+const ALIASES_TAG: &str = "aliases";
+const ALIAS_TAG: &str = "alias";
+const TAGS_TAG: &str = "tags";
+const TAG_TAG: &str = "tag";
+const RESOLVES_TAG: &str = "resolves";
+const NOTES_TAG: &str = "notes";
+const PROPERTIES_TAG: &str = "properties";
+
+impl ToXml for ReleaseNotes {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(writer::XmlEvent::start_element(RELEASE_NOTES_TAG))
+            .map_err(to_xml_write_error(RELEASE_NOTES_TAG))?;
+
+        write_simple_tag(writer, TYPE_TAG, &self.release_type)?;
+
+        if let Some(title) = &self.title {
+            write_simple_tag(writer, TITLE_TAG, title)?;
+        }
+
+        if let Some(featured_image) = &self.featured_image {
+            write_simple_tag(writer, FEATURED_IMAGE_TAG, featured_image)?;
+        }
+
+        if let Some(social_image) = &self.social_image {
+            write_simple_tag(writer, SOCIAL_IMAGE_TAG, social_image)?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(timestamp) = &self.timestamp {
+            write_simple_tag(writer, TIMESTAMP_TAG, timestamp)?;
+        }
+
+        if let Some(aliases) = &self.aliases {
+            write_simple_list_tag(writer, ALIASES_TAG, ALIAS_TAG, aliases)?;
+        }
+
+        if let Some(tags) = &self.tags {
+            write_simple_list_tag(writer, TAGS_TAG, TAG_TAG, tags)?;
+        }
+
+        if let Some(resolves) = &self.resolves {
+            writer
+                .write(writer::XmlEvent::start_element(RESOLVES_TAG))
+                .map_err(to_xml_write_error(RESOLVES_TAG))?;
+
+            for issue in resolves {
+                issue.write_xml_element(writer)?;
+            }
+
+            writer
+                .write(writer::XmlEvent::end_element())
+                .map_err(to_xml_write_error(RESOLVES_TAG))?;
+        }
+
+        if let Some(notes) = &self.notes {
+            writer
+                .write(writer::XmlEvent::start_element(NOTES_TAG))
+                .map_err(to_xml_write_error(NOTES_TAG))?;
+
+            for note in notes {
+                note.write_xml_element(writer)?;
+            }
+
+            writer
+                .write(writer::XmlEvent::end_element())
+                .map_err(to_xml_write_error(NOTES_TAG))?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(writer::XmlEvent::end_element())
+            .map_err(to_xml_write_error(RELEASE_NOTES_TAG))?;
+
+        Ok(())
+    }
+}
+
+pub(crate) fn write_simple_list_tag<W: std::io::Write>(
+    writer: &mut xml::EventWriter<W>,
+    list_tag: &str,
+    item_tag: &str,
+    items: &[String],
+) -> Result<(), crate::errors::XmlWriteError> {
+    writer
+        .write(writer::XmlEvent::start_element(list_tag))
+        .map_err(to_xml_write_error(list_tag))?;
+
+    for item in items {
+        write_simple_tag(writer, item_tag, item)?;
+    }
+
+    writer
+        .write(writer::XmlEvent::end_element())
+        .map_err(to_xml_write_error(list_tag))?;
+
+    Ok(())
+}
+
+impl FromXml for ReleaseNotes {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut release_type: Option<String> = None;
+        let mut title: Option<String> = None;
+        let mut featured_image: Option<String> = None;
+        let mut social_image: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut timestamp: Option<String> = None;
+        let mut aliases: Option<Vec<String>> = None;
+        let mut tags: Option<Vec<String>> = None;
+        let mut resolves: Option<Vec<Issue>> = None;
+        let mut notes: Option<Vec<Note>> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(RELEASE_NOTES_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TYPE_TAG => {
+                    release_type = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TITLE_TAG => {
+                    title = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == FEATURED_IMAGE_TAG =>
+                {
+                    featured_image = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == SOCIAL_IMAGE_TAG =>
+                {
+                    social_image = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TIMESTAMP_TAG => {
+                    timestamp = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == ALIASES_TAG => {
+                    aliases = Some(read_list_tag(event_reader, &name, ALIAS_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TAGS_TAG => {
+                    tags = Some(read_list_tag(event_reader, &name, TAG_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == RESOLVES_TAG => {
+                    resolves = Some(read_list_tag(event_reader, &name, ISSUE_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NOTES_TAG => {
+                    notes = Some(read_list_tag(event_reader, &name, NOTE_TAG)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let release_type = release_type.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TYPE_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            release_type,
+            title,
+            featured_image,
+            social_image,
+            description,
+            timestamp,
+            aliases,
+            tags,
+            resolves,
+            notes,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Note {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+    text: AttachedText,
+}
+
+impl From<models::release_notes::Note> for Note {
+    fn from(other: models::release_notes::Note) -> Self {
+        Self {
+            locale: other.locale.map(|l| l.0),
+            text: other.text.into(),
+        }
+    }
+}
+
+impl From<Note> for models::release_notes::Note {
+    fn from(other: Note) -> Self {
+        Self {
+            locale: other.locale.map(models::release_notes::Locale),
+            text: other.text.into(),
+        }
+    }
+}
+
+const NOTE_TAG: &str = "note";
+const LOCALE_TAG: &str = "locale";
+const TEXT_TAG: &str = "text";
+
+impl ToXml for Note {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(writer::XmlEvent::start_element(NOTE_TAG))
+            .map_err(to_xml_write_error(NOTE_TAG))?;
+
+        if let Some(locale) = &self.locale {
+            write_simple_tag(writer, LOCALE_TAG, locale)?;
+        }
+
+        self.text.write_xml_named_element(writer, TEXT_TAG)?;
+
+        writer
+            .write(writer::XmlEvent::end_element())
+            .map_err(to_xml_write_error(NOTE_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Note {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut locale: Option<String> = None;
+        let mut text: Option<AttachedText> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(NOTE_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == LOCALE_TAG => {
+                    locale = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == TEXT_TAG => {
+                    text = Some(AttachedText::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let text = text.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: TEXT_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self { locale, text })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::{
+        specs::v1_5::{
+            attached_text::test::{corresponding_attached_text, example_attached_text},
+            code::test::{corresponding_issue, example_issue},
+            property::test::{corresponding_properties, example_properties},
+        },
+        xml::test::{read_element_from_string, write_element_to_string},
+    };
+
+    pub(crate) fn example_release_notes() -> ReleaseNotes {
+        ReleaseNotes {
+            release_type: "major".to_string(),
+            title: Some("title".to_string()),
+            featured_image: Some("featured image".to_string()),
+            social_image: Some("social image".to_string()),
+            description: Some("description".to_string()),
+            timestamp: Some("timestamp".to_string()),
+            aliases: Some(vec!["alias".to_string()]),
+            tags: Some(vec!["tag".to_string()]),
+            resolves: Some(vec![example_issue()]),
+            notes: Some(vec![Note {
+                locale: Some("locale".to_string()),
+                text: example_attached_text(),
+            }]),
+            properties: Some(example_properties()),
+        }
+    }
+
+    pub(crate) fn corresponding_release_notes() -> models::release_notes::ReleaseNotes {
+        models::release_notes::ReleaseNotes {
+            release_type: NormalizedString::new_unchecked("major".to_string()),
+            title: Some(NormalizedString::new_unchecked("title".to_string())),
+            featured_image: Some(Uri("featured image".to_string())),
+            social_image: Some(Uri("social image".to_string())),
+            description: Some("description".to_string()),
+            timestamp: Some(DateTime("timestamp".to_string())),
+            aliases: Some(vec![NormalizedString::new_unchecked("alias".to_string())]),
+            tags: Some(vec![NormalizedString::new_unchecked("tag".to_string())]),
+            resolves: Some(vec![corresponding_issue()]),
+            notes: Some(vec![models::release_notes::Note {
+                locale: Some(models::release_notes::Locale("locale".to_string())),
+                text: corresponding_attached_text(),
+            }]),
+            properties: Some(corresponding_properties()),
+        }
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_release_notes());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<releaseNotes>
+  <type>major</type>
+  <title>title</title>
+  <featuredImage>featured image</featuredImage>
+  <socialImage>social image</socialImage>
+  <description>description</description>
+  <timestamp>timestamp</timestamp>
+  <aliases>
+    <alias>alias</alias>
+  </aliases>
+  <tags>
+    <tag>tag</tag>
+  </tags>
+  <resolves>
+    <issue type="issue type">
+      <id>id</id>
+      <name>name</name>
+      <description>description</description>
+      <source>
+        <name>name</name>
+        <url>url</url>
+      </source>
+      <references>
+        <url>reference</url>
+      </references>
+    </issue>
+  </resolves>
+  <notes>
+    <note>
+      <locale>locale</locale>
+      <text content-type="content type" encoding="encoding">content</text>
+    </note>
+  </notes>
+  <properties>
+    <property name="name">value</property>
+  </properties>
+</releaseNotes>
+"#;
+        let actual: ReleaseNotes = read_element_from_string(input);
+        let expected = example_release_notes();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_can_convert_to_the_internal_model() {
+        let model: models::release_notes::ReleaseNotes = example_release_notes().into();
+        assert_eq!(model, corresponding_release_notes());
+    }
+
+    #[test]
+    fn it_can_convert_from_the_internal_model() {
+        let spec: ReleaseNotes = corresponding_release_notes().into();
+        assert_eq!(spec, example_release_notes());
+    }
+}
@@ -0,0 +1,89 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// JSF signatures only exist in the JSON format, the XML format relies on XML signatures instead
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Signature {
+    algorithm: String,
+    value: String,
+}
+
+impl From<models::signature::Signature> for Signature {
+    fn from(other: models::signature::Signature) -> Self {
+        Self {
+            algorithm: other.algorithm.to_string(),
+            value: other.value,
+        }
+    }
+}
+
+impl From<Signature> for models::signature::Signature {
+    fn from(other: Signature) -> Self {
+        Self {
+            algorithm: models::signature::Algorithm::new_unchecked(other.algorithm),
+            value: other.value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example_signature() -> Signature {
+        Signature {
+            algorithm: "HS512".to_string(),
+            value: "1234567890".to_string(),
+        }
+    }
+
+    fn corresponding_signature() -> models::signature::Signature {
+        models::signature::Signature {
+            algorithm: models::signature::Algorithm::HS512,
+            value: "1234567890".to_string(),
+        }
+    }
+
+    #[test]
+    fn it_should_serialize_to_json() {
+        insta::assert_json_snapshot!(example_signature());
+    }
+
+    #[test]
+    fn it_should_deserialize_from_json() {
+        let input = r#"{ "algorithm": "HS512", "value": "1234567890" }"#;
+        let actual: Signature = serde_json::from_str(input).expect("Failed to parse signature");
+        assert_eq!(actual, example_signature());
+    }
+
+    #[test]
+    fn it_can_convert_to_the_internal_model() {
+        let model: models::signature::Signature = example_signature().into();
+        assert_eq!(model, corresponding_signature());
+    }
+
+    #[test]
+    fn it_can_convert_from_the_internal_model() {
+        let spec: Signature = corresponding_signature().into();
+        assert_eq!(spec, example_signature());
+    }
+}
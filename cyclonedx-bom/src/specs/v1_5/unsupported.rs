@@ -0,0 +1,71 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Elements of the specification that the model cannot hold yet.
+//!
+//! Reading a document that contains one of them fails, rather than silently losing its data.
+
+use serde::{de::Error, Deserialize, Deserializer};
+
+use crate::errors::XmlReadError;
+
+/// A JSON field that the model cannot hold, which fails to deserialize if it is present
+#[derive(Debug, PartialEq)]
+pub(crate) struct Unsupported;
+
+impl<'de> Deserialize<'de> for Unsupported {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Err(D::Error::custom(
+            "this field is not supported yet and cannot be read without losing data",
+        ))
+    }
+}
+
+/// The error for an XML element that the model cannot hold
+pub(crate) fn unsupported_element_error(element: &xml::name::OwnedName) -> XmlReadError {
+    XmlReadError::UnsupportedElement {
+        element: element.local_name.clone(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Example {
+        #[serde(default)]
+        field: Option<Unsupported>,
+    }
+
+    #[test]
+    fn it_should_read_a_missing_field() {
+        let example: Example = serde_json::from_str("{}").expect("Failed to read JSON");
+        assert_eq!(example.field, None);
+    }
+
+    #[test]
+    fn it_should_fail_to_read_a_present_field() {
+        let error = serde_json::from_str::<Example>(r#"{"field": {"uid": "trigger"}}"#)
+            .expect_err("Read an unsupported field");
+        assert!(error.to_string().contains("not supported"));
+    }
+}
@@ -0,0 +1,1965 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::{
+    errors::XmlReadError,
+    external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
+    models,
+    specs::v1_5::{
+        attached_text::AttachedText,
+        organization::{OrganizationalContact, OrganizationalEntity},
+        property::Properties,
+        tool::Tools,
+    },
+    utilities::{convert_optional, convert_optional_vec, convert_vec},
+    xml::{
+        optional_attribute, read_lax_validation_list_tag, read_lax_validation_tag, read_list_tag,
+        read_simple_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
+        write_simple_tag, FromXml, FromXmlType, ToInnerXml, ToXml,
+    },
+};
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(transparent)]
+pub(crate) struct Vulnerabilities(Vec<Vulnerability>);
+
+impl From<models::vulnerability::Vulnerabilities> for Vulnerabilities {
+    fn from(other: models::vulnerability::Vulnerabilities) -> Self {
+        Vulnerabilities(convert_vec(other.0))
+    }
+}
+
+impl From<Vulnerabilities> for models::vulnerability::Vulnerabilities {
+    fn from(other: Vulnerabilities) -> Self {
+        models::vulnerability::Vulnerabilities(convert_vec(other.0))
+    }
+}
+
+const VULNERABILITIES_TAG: &str = "vulnerabilities";
+
+impl ToXml for Vulnerabilities {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(VULNERABILITIES_TAG))
+            .map_err(to_xml_write_error(VULNERABILITIES_TAG))?;
+
+        for vulnerability in &self.0 {
+            vulnerability.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(VULNERABILITIES_TAG))?;
+        Ok(())
+    }
+}
+
+impl FromXml for Vulnerabilities {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        read_lax_validation_list_tag(event_reader, element_name, VULNERABILITY_TAG)
+            .map(Vulnerabilities)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Vulnerability {
+    #[serde(rename = "bom-ref", skip_serializing_if = "Option::is_none")]
+    bom_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<VulnerabilitySource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    references: Option<Vec<VulnerabilityReference>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ratings: Option<Vec<VulnerabilityRating>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cwes: Option<Vec<u32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recommendation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workaround: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof_of_concept: Option<ProofOfConcept>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    advisories: Option<Vec<Advisory>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    published: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rejected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    credits: Option<VulnerabilityCredits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Tools>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analysis: Option<VulnerabilityAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    affects: Option<Vec<VulnerabilityTarget>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<Properties>,
+}
+
+impl From<models::vulnerability::Vulnerability> for Vulnerability {
+    fn from(other: models::vulnerability::Vulnerability) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            id: other.id.map(|i| i.to_string()),
+            source: convert_optional(other.source),
+            references: convert_optional_vec(other.references),
+            ratings: convert_optional_vec(other.ratings),
+            cwes: other.cwes,
+            description: other.description,
+            detail: other.detail,
+            recommendation: other.recommendation,
+            workaround: other.workaround,
+            proof_of_concept: convert_optional(other.proof_of_concept),
+            advisories: convert_optional_vec(other.advisories),
+            created: other.created.map(|c| c.to_string()),
+            published: other.published.map(|p| p.to_string()),
+            updated: other.updated.map(|u| u.to_string()),
+            rejected: other.rejected.map(|r| r.to_string()),
+            credits: convert_optional(other.credits),
+            tools: convert_optional(other.tools),
+            analysis: convert_optional(other.analysis),
+            affects: convert_optional_vec(other.affects),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+impl From<Vulnerability> for models::vulnerability::Vulnerability {
+    fn from(other: Vulnerability) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            id: other.id.map(NormalizedString::new_unchecked),
+            source: convert_optional(other.source),
+            references: convert_optional_vec(other.references),
+            ratings: convert_optional_vec(other.ratings),
+            cwes: other.cwes,
+            description: other.description,
+            detail: other.detail,
+            recommendation: other.recommendation,
+            workaround: other.workaround,
+            proof_of_concept: convert_optional(other.proof_of_concept),
+            advisories: convert_optional_vec(other.advisories),
+            created: other.created.map(DateTime),
+            published: other.published.map(DateTime),
+            updated: other.updated.map(DateTime),
+            rejected: other.rejected.map(DateTime),
+            credits: convert_optional(other.credits),
+            tools: convert_optional(other.tools),
+            analysis: convert_optional(other.analysis),
+            affects: convert_optional_vec(other.affects),
+            properties: convert_optional(other.properties),
+        }
+    }
+}
+
+const VULNERABILITY_TAG: &str = "vulnerability";
+const BOM_REF_ATTR: &str = "bom-ref";
+const ID_TAG: &str = "id";
+const SOURCE_TAG: &str = "source";
+const REFERENCES_TAG: &str = "references";
+const REFERENCE_TAG: &str = "reference";
+const RATINGS_TAG: &str = "ratings";
+const RATING_TAG: &str = "rating";
+const CWES_TAG: &str = "cwes";
+const CWE_TAG: &str = "cwe";
+const DESCRIPTION_TAG: &str = "description";
+const DETAIL_TAG: &str = "detail";
+const RECOMMENDATION_TAG: &str = "recommendation";
+const WORKAROUND_TAG: &str = "workaround";
+const PROOF_OF_CONCEPT_TAG: &str = "proofOfConcept";
+const ADVISORIES_TAG: &str = "advisories";
+const ADVISORY_TAG: &str = "advisory";
+const CREATED_TAG: &str = "created";
+const PUBLISHED_TAG: &str = "published";
+const UPDATED_TAG: &str = "updated";
+const REJECTED_TAG: &str = "rejected";
+const CREDITS_TAG: &str = "credits";
+const TOOLS_TAG: &str = "tools";
+const ANALYSIS_TAG: &str = "analysis";
+const AFFECTS_TAG: &str = "affects";
+const TARGET_TAG: &str = "target";
+const PROPERTIES_TAG: &str = "properties";
+
+impl ToXml for Vulnerability {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let mut vulnerability_start_tag = XmlEvent::start_element(VULNERABILITY_TAG);
+
+        if let Some(bom_ref) = &self.bom_ref {
+            vulnerability_start_tag = vulnerability_start_tag.attr(BOM_REF_ATTR, bom_ref);
+        }
+
+        writer
+            .write(vulnerability_start_tag)
+            .map_err(to_xml_write_error(VULNERABILITY_TAG))?;
+
+        if let Some(id) = &self.id {
+            write_simple_tag(writer, ID_TAG, id)?;
+        }
+
+        if let Some(source) = &self.source {
+            source.write_xml_element(writer)?;
+        }
+
+        if let Some(references) = &self.references {
+            writer
+                .write(XmlEvent::start_element(REFERENCES_TAG))
+                .map_err(to_xml_write_error(REFERENCES_TAG))?;
+            for reference in references {
+                reference.write_xml_element(writer)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(REFERENCES_TAG))?;
+        }
+
+        if let Some(ratings) = &self.ratings {
+            writer
+                .write(XmlEvent::start_element(RATINGS_TAG))
+                .map_err(to_xml_write_error(RATINGS_TAG))?;
+            for rating in ratings {
+                rating.write_xml_element(writer)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(RATINGS_TAG))?;
+        }
+
+        if let Some(cwes) = &self.cwes {
+            writer
+                .write(XmlEvent::start_element(CWES_TAG))
+                .map_err(to_xml_write_error(CWES_TAG))?;
+            for cwe in cwes {
+                write_simple_tag(writer, CWE_TAG, &format!("{}", cwe))?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(CWES_TAG))?;
+        }
+
+        if let Some(description) = &self.description {
+            write_simple_tag(writer, DESCRIPTION_TAG, description)?;
+        }
+
+        if let Some(detail) = &self.detail {
+            write_simple_tag(writer, DETAIL_TAG, detail)?;
+        }
+
+        if let Some(recommendation) = &self.recommendation {
+            write_simple_tag(writer, RECOMMENDATION_TAG, recommendation)?;
+        }
+
+        if let Some(workaround) = &self.workaround {
+            write_simple_tag(writer, WORKAROUND_TAG, workaround)?;
+        }
+
+        if let Some(proof_of_concept) = &self.proof_of_concept {
+            proof_of_concept.write_xml_element(writer)?;
+        }
+
+        if let Some(advisories) = &self.advisories {
+            writer
+                .write(XmlEvent::start_element(ADVISORIES_TAG))
+                .map_err(to_xml_write_error(ADVISORIES_TAG))?;
+            for advisory in advisories {
+                advisory.write_xml_element(writer)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(ADVISORIES_TAG))?;
+        }
+
+        if let Some(created) = &self.created {
+            write_simple_tag(writer, CREATED_TAG, created)?;
+        }
+
+        if let Some(published) = &self.published {
+            write_simple_tag(writer, PUBLISHED_TAG, published)?;
+        }
+
+        if let Some(updated) = &self.updated {
+            write_simple_tag(writer, UPDATED_TAG, updated)?;
+        }
+
+        if let Some(rejected) = &self.rejected {
+            write_simple_tag(writer, REJECTED_TAG, rejected)?;
+        }
+
+        if let Some(credits) = &self.credits {
+            credits.write_xml_element(writer)?;
+        }
+
+        if let Some(tools) = &self.tools {
+            tools.write_xml_element(writer)?;
+        }
+
+        if let Some(analysis) = &self.analysis {
+            analysis.write_xml_element(writer)?;
+        }
+
+        if let Some(affects) = &self.affects {
+            writer
+                .write(XmlEvent::start_element(AFFECTS_TAG))
+                .map_err(to_xml_write_error(AFFECTS_TAG))?;
+            for target in affects {
+                target.write_xml_element(writer)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(AFFECTS_TAG))?;
+        }
+
+        if let Some(properties) = &self.properties {
+            properties.write_xml_element(writer)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(VULNERABILITY_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Vulnerability {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let bom_ref = optional_attribute(attributes, BOM_REF_ATTR);
+
+        let mut id: Option<String> = None;
+        let mut source: Option<VulnerabilitySource> = None;
+        let mut references: Option<Vec<VulnerabilityReference>> = None;
+        let mut ratings: Option<Vec<VulnerabilityRating>> = None;
+        let mut cwes: Option<Vec<u32>> = None;
+        let mut description: Option<String> = None;
+        let mut detail: Option<String> = None;
+        let mut recommendation: Option<String> = None;
+        let mut workaround: Option<String> = None;
+        let mut proof_of_concept: Option<ProofOfConcept> = None;
+        let mut advisories: Option<Vec<Advisory>> = None;
+        let mut created: Option<String> = None;
+        let mut published: Option<String> = None;
+        let mut updated: Option<String> = None;
+        let mut rejected: Option<String> = None;
+        let mut credits: Option<VulnerabilityCredits> = None;
+        let mut tools: Option<Tools> = None;
+        let mut analysis: Option<VulnerabilityAnalysis> = None;
+        let mut affects: Option<Vec<VulnerabilityTarget>> = None;
+        let mut properties: Option<Properties> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(VULNERABILITY_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == ID_TAG => {
+                    id = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SOURCE_TAG => {
+                    source = Some(VulnerabilitySource::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == REFERENCES_TAG =>
+                {
+                    references = Some(read_list_tag(event_reader, &name, REFERENCE_TAG)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == RATINGS_TAG => {
+                    ratings = Some(read_list_tag(event_reader, &name, RATING_TAG)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == CWES_TAG => {
+                    let values: Vec<String> = read_list_tag(event_reader, &name, CWE_TAG)?;
+                    cwes = Some(
+                        values
+                            .into_iter()
+                            .map(|value| u32::from_xml_value(CWE_TAG, value))
+                            .collect::<Result<Vec<u32>, XmlReadError>>()?,
+                    );
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == DESCRIPTION_TAG =>
+                {
+                    description = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == DETAIL_TAG => {
+                    detail = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == RECOMMENDATION_TAG =>
+                {
+                    recommendation = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == WORKAROUND_TAG =>
+                {
+                    workaround = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROOF_OF_CONCEPT_TAG => {
+                    proof_of_concept = Some(ProofOfConcept::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == ADVISORIES_TAG =>
+                {
+                    advisories = Some(read_list_tag(event_reader, &name, ADVISORY_TAG)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == CREATED_TAG => {
+                    created = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == PUBLISHED_TAG => {
+                    published = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == UPDATED_TAG => {
+                    updated = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == REJECTED_TAG => {
+                    rejected = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == CREDITS_TAG => {
+                    credits = Some(VulnerabilityCredits::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == TOOLS_TAG => {
+                    tools = Some(Tools::read_xml_element(event_reader, &name, &attributes)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == ANALYSIS_TAG => {
+                    analysis = Some(VulnerabilityAnalysis::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == AFFECTS_TAG => {
+                    affects = Some(read_list_tag(event_reader, &name, TARGET_TAG)?);
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == PROPERTIES_TAG => {
+                    properties = Some(Properties::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            bom_ref,
+            id,
+            source,
+            references,
+            ratings,
+            cwes,
+            description,
+            detail,
+            recommendation,
+            workaround,
+            proof_of_concept,
+            advisories,
+            created,
+            published,
+            updated,
+            rejected,
+            credits,
+            tools,
+            analysis,
+            affects,
+            properties,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct ProofOfConcept {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reproduction_steps: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supporting_material: Option<Vec<AttachedText>>,
+}
+
+impl From<models::vulnerability::ProofOfConcept> for ProofOfConcept {
+    fn from(other: models::vulnerability::ProofOfConcept) -> Self {
+        Self {
+            reproduction_steps: other.reproduction_steps,
+            environment: other.environment,
+            supporting_material: convert_optional_vec(other.supporting_material),
+        }
+    }
+}
+
+impl From<ProofOfConcept> for models::vulnerability::ProofOfConcept {
+    fn from(other: ProofOfConcept) -> Self {
+        Self {
+            reproduction_steps: other.reproduction_steps,
+            environment: other.environment,
+            supporting_material: convert_optional_vec(other.supporting_material),
+        }
+    }
+}
+
+const REPRODUCTION_STEPS_TAG: &str = "reproductionSteps";
+const ENVIRONMENT_TAG: &str = "environment";
+const SUPPORTING_MATERIAL_TAG: &str = "supportingMaterial";
+const ATTACHMENT_TAG: &str = "attachment";
+
+impl ToXml for ProofOfConcept {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(PROOF_OF_CONCEPT_TAG))
+            .map_err(to_xml_write_error(PROOF_OF_CONCEPT_TAG))?;
+
+        if let Some(reproduction_steps) = &self.reproduction_steps {
+            write_simple_tag(writer, REPRODUCTION_STEPS_TAG, reproduction_steps)?;
+        }
+
+        if let Some(environment) = &self.environment {
+            write_simple_tag(writer, ENVIRONMENT_TAG, environment)?;
+        }
+
+        if let Some(supporting_material) = &self.supporting_material {
+            writer
+                .write(XmlEvent::start_element(SUPPORTING_MATERIAL_TAG))
+                .map_err(to_xml_write_error(SUPPORTING_MATERIAL_TAG))?;
+            for attachment in supporting_material {
+                attachment.write_xml_named_element(writer, ATTACHMENT_TAG)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(SUPPORTING_MATERIAL_TAG))?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(PROOF_OF_CONCEPT_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for ProofOfConcept {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut reproduction_steps: Option<String> = None;
+        let mut environment: Option<String> = None;
+        let mut supporting_material: Option<Vec<AttachedText>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(PROOF_OF_CONCEPT_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == REPRODUCTION_STEPS_TAG =>
+                {
+                    reproduction_steps = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == ENVIRONMENT_TAG =>
+                {
+                    environment = Some(read_simple_tag(event_reader, &name)?);
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == SUPPORTING_MATERIAL_TAG =>
+                {
+                    supporting_material = Some(read_list_tag(event_reader, &name, ATTACHMENT_TAG)?);
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            reproduction_steps,
+            environment,
+            supporting_material,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct VulnerabilitySource {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+impl From<models::vulnerability::VulnerabilitySource> for VulnerabilitySource {
+    fn from(other: models::vulnerability::VulnerabilitySource) -> Self {
+        Self {
+            name: other.name.map(|n| n.to_string()),
+            url: other.url.map(|u| u.to_string()),
+        }
+    }
+}
+
+impl From<VulnerabilitySource> for models::vulnerability::VulnerabilitySource {
+    fn from(other: VulnerabilitySource) -> Self {
+        Self {
+            name: other.name.map(NormalizedString::new_unchecked),
+            url: other.url.map(Uri),
+        }
+    }
+}
+
+const NAME_TAG: &str = "name";
+const URL_TAG: &str = "url";
+
+impl ToXml for VulnerabilitySource {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(SOURCE_TAG))
+            .map_err(to_xml_write_error(SOURCE_TAG))?;
+
+        if let Some(name) = &self.name {
+            write_simple_tag(writer, NAME_TAG, name)?;
+        }
+
+        if let Some(url) = &self.url {
+            write_simple_tag(writer, URL_TAG, url)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(SOURCE_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for VulnerabilitySource {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut source_name: Option<String> = None;
+        let mut url: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(SOURCE_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == NAME_TAG => {
+                    source_name = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == URL_TAG => {
+                    url = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            name: source_name,
+            url,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct VulnerabilityReference {
+    id: String,
+    source: VulnerabilitySource,
+}
+
+impl From<models::vulnerability::VulnerabilityReference> for VulnerabilityReference {
+    fn from(other: models::vulnerability::VulnerabilityReference) -> Self {
+        Self {
+            id: other.id.to_string(),
+            source: other.source.into(),
+        }
+    }
+}
+
+impl From<VulnerabilityReference> for models::vulnerability::VulnerabilityReference {
+    fn from(other: VulnerabilityReference) -> Self {
+        Self {
+            id: NormalizedString::new_unchecked(other.id),
+            source: other.source.into(),
+        }
+    }
+}
+
+impl ToXml for VulnerabilityReference {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(REFERENCE_TAG))
+            .map_err(to_xml_write_error(REFERENCE_TAG))?;
+
+        write_simple_tag(writer, ID_TAG, &self.id)?;
+
+        self.source.write_xml_element(writer)?;
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(REFERENCE_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for VulnerabilityReference {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut id: Option<String> = None;
+        let mut source: Option<VulnerabilitySource> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(REFERENCE_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == ID_TAG => {
+                    id = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SOURCE_TAG => {
+                    source = Some(VulnerabilitySource::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let id = id.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: ID_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        let source = source.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: SOURCE_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self { id, source })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct VulnerabilityRating {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<VulnerabilitySource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vector: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    justification: Option<String>,
+}
+
+impl From<models::vulnerability::VulnerabilityRating> for VulnerabilityRating {
+    fn from(other: models::vulnerability::VulnerabilityRating) -> Self {
+        Self {
+            source: convert_optional(other.source),
+            score: other.score.map(|s| s.to_f32()),
+            severity: other.severity.map(|s| s.to_string()),
+            method: other.method.map(|m| m.to_string()),
+            vector: other.vector.map(|v| v.to_string()),
+            justification: other.justification,
+        }
+    }
+}
+
+impl From<VulnerabilityRating> for models::vulnerability::VulnerabilityRating {
+    fn from(other: VulnerabilityRating) -> Self {
+        Self {
+            source: convert_optional(other.source),
+            score: other.score.map(models::vulnerability::Score::from),
+            severity: other
+                .severity
+                .map(models::vulnerability::Severity::new_unchecked),
+            method: other
+                .method
+                .map(models::vulnerability::ScoreMethod::new_unchecked),
+            vector: other.vector.map(NormalizedString::new_unchecked),
+            justification: other.justification,
+        }
+    }
+}
+
+const SCORE_TAG: &str = "score";
+const SEVERITY_TAG: &str = "severity";
+const METHOD_TAG: &str = "method";
+const VECTOR_TAG: &str = "vector";
+const JUSTIFICATION_TAG: &str = "justification";
+
+impl ToXml for VulnerabilityRating {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(RATING_TAG))
+            .map_err(to_xml_write_error(RATING_TAG))?;
+
+        if let Some(source) = &self.source {
+            source.write_xml_element(writer)?;
+        }
+
+        if let Some(score) = &self.score {
+            write_simple_tag(writer, SCORE_TAG, &format!("{}", score))?;
+        }
+
+        if let Some(severity) = &self.severity {
+            write_simple_tag(writer, SEVERITY_TAG, severity)?;
+        }
+
+        if let Some(method) = &self.method {
+            write_simple_tag(writer, METHOD_TAG, method)?;
+        }
+
+        if let Some(vector) = &self.vector {
+            write_simple_tag(writer, VECTOR_TAG, vector)?;
+        }
+
+        if let Some(justification) = &self.justification {
+            write_simple_tag(writer, JUSTIFICATION_TAG, justification)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(RATING_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for VulnerabilityRating {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut source: Option<VulnerabilitySource> = None;
+        let mut score: Option<f32> = None;
+        let mut severity: Option<String> = None;
+        let mut method: Option<String> = None;
+        let mut vector: Option<String> = None;
+        let mut justification: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(RATING_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == SOURCE_TAG => {
+                    source = Some(VulnerabilitySource::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == SCORE_TAG => {
+                    score = Some(
+                        read_simple_tag(event_reader, &name)
+                            .and_then(|score| f32::from_xml_value(SCORE_TAG, score))?,
+                    )
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == SEVERITY_TAG => {
+                    severity = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == METHOD_TAG => {
+                    method = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == VECTOR_TAG => {
+                    vector = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == JUSTIFICATION_TAG =>
+                {
+                    justification = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            source,
+            score,
+            severity,
+            method,
+            vector,
+            justification,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Advisory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    url: String,
+}
+
+impl From<models::vulnerability::Advisory> for Advisory {
+    fn from(other: models::vulnerability::Advisory) -> Self {
+        Self {
+            title: other.title.map(|t| t.to_string()),
+            url: other.url.to_string(),
+        }
+    }
+}
+
+impl From<Advisory> for models::vulnerability::Advisory {
+    fn from(other: Advisory) -> Self {
+        Self {
+            title: other.title.map(NormalizedString::new_unchecked),
+            url: Uri(other.url),
+        }
+    }
+}
+
+const TITLE_TAG: &str = "title";
+
+impl ToXml for Advisory {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(ADVISORY_TAG))
+            .map_err(to_xml_write_error(ADVISORY_TAG))?;
+
+        if let Some(title) = &self.title {
+            write_simple_tag(writer, TITLE_TAG, title)?;
+        }
+
+        write_simple_tag(writer, URL_TAG, &self.url)?;
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(ADVISORY_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for Advisory {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut title: Option<String> = None;
+        let mut url: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(ADVISORY_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == TITLE_TAG => {
+                    title = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == URL_TAG => {
+                    url = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let url = url.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: URL_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self { title, url })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct VulnerabilityCredits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organizations: Option<Vec<OrganizationalEntity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    individuals: Option<Vec<OrganizationalContact>>,
+}
+
+impl From<models::vulnerability::VulnerabilityCredits> for VulnerabilityCredits {
+    fn from(other: models::vulnerability::VulnerabilityCredits) -> Self {
+        Self {
+            organizations: convert_optional_vec(other.organizations),
+            individuals: convert_optional_vec(other.individuals),
+        }
+    }
+}
+
+impl From<VulnerabilityCredits> for models::vulnerability::VulnerabilityCredits {
+    fn from(other: VulnerabilityCredits) -> Self {
+        Self {
+            organizations: convert_optional_vec(other.organizations),
+            individuals: convert_optional_vec(other.individuals),
+        }
+    }
+}
+
+const ORGANIZATIONS_TAG: &str = "organizations";
+const ORGANIZATION_TAG: &str = "organization";
+const INDIVIDUALS_TAG: &str = "individuals";
+const INDIVIDUAL_TAG: &str = "individual";
+
+impl ToXml for VulnerabilityCredits {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(CREDITS_TAG))
+            .map_err(to_xml_write_error(CREDITS_TAG))?;
+
+        if let Some(organizations) = &self.organizations {
+            writer
+                .write(XmlEvent::start_element(ORGANIZATIONS_TAG))
+                .map_err(to_xml_write_error(ORGANIZATIONS_TAG))?;
+            for organization in organizations {
+                organization.write_xml_named_element(writer, ORGANIZATION_TAG)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(ORGANIZATIONS_TAG))?;
+        }
+
+        if let Some(individuals) = &self.individuals {
+            writer
+                .write(XmlEvent::start_element(INDIVIDUALS_TAG))
+                .map_err(to_xml_write_error(INDIVIDUALS_TAG))?;
+            for individual in individuals {
+                individual.write_xml_named_element(writer, INDIVIDUAL_TAG)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(INDIVIDUALS_TAG))?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(CREDITS_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for VulnerabilityCredits {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut organizations: Option<Vec<OrganizationalEntity>> = None;
+        let mut individuals: Option<Vec<OrganizationalContact>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(CREDITS_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == ORGANIZATIONS_TAG =>
+                {
+                    organizations = Some(read_list_tag(event_reader, &name, ORGANIZATION_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == INDIVIDUALS_TAG =>
+                {
+                    individuals = Some(read_list_tag(event_reader, &name, INDIVIDUAL_TAG)?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            organizations,
+            individuals,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct VulnerabilityAnalysis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    justification: Option<String>,
+    #[serde(rename = "response", skip_serializing_if = "Option::is_none")]
+    responses: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first_issued: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_updated: Option<String>,
+}
+
+impl From<models::vulnerability::VulnerabilityAnalysis> for VulnerabilityAnalysis {
+    fn from(other: models::vulnerability::VulnerabilityAnalysis) -> Self {
+        Self {
+            state: other.state.map(|s| s.to_string()),
+            justification: other.justification.map(|j| j.to_string()),
+            responses: other
+                .responses
+                .map(|responses| responses.into_iter().map(|r| r.to_string()).collect()),
+            detail: other.detail,
+            first_issued: other.first_issued.map(|f| f.to_string()),
+            last_updated: other.last_updated.map(|l| l.to_string()),
+        }
+    }
+}
+
+impl From<VulnerabilityAnalysis> for models::vulnerability::VulnerabilityAnalysis {
+    fn from(other: VulnerabilityAnalysis) -> Self {
+        Self {
+            state: other
+                .state
+                .map(models::vulnerability::ImpactAnalysisState::new_unchecked),
+            justification: other
+                .justification
+                .map(models::vulnerability::ImpactAnalysisJustification::new_unchecked),
+            responses: other.responses.map(|responses| {
+                responses
+                    .into_iter()
+                    .map(models::vulnerability::ImpactAnalysisResponse::new_unchecked)
+                    .collect()
+            }),
+            detail: other.detail,
+            first_issued: other.first_issued.map(DateTime),
+            last_updated: other.last_updated.map(DateTime),
+        }
+    }
+}
+
+const STATE_TAG: &str = "state";
+const RESPONSES_TAG: &str = "responses";
+const RESPONSE_TAG: &str = "response";
+const FIRST_ISSUED_TAG: &str = "firstIssued";
+const LAST_UPDATED_TAG: &str = "lastUpdated";
+
+impl ToXml for VulnerabilityAnalysis {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(ANALYSIS_TAG))
+            .map_err(to_xml_write_error(ANALYSIS_TAG))?;
+
+        if let Some(state) = &self.state {
+            write_simple_tag(writer, STATE_TAG, state)?;
+        }
+
+        if let Some(justification) = &self.justification {
+            write_simple_tag(writer, JUSTIFICATION_TAG, justification)?;
+        }
+
+        if let Some(responses) = &self.responses {
+            writer
+                .write(XmlEvent::start_element(RESPONSES_TAG))
+                .map_err(to_xml_write_error(RESPONSES_TAG))?;
+            for response in responses {
+                write_simple_tag(writer, RESPONSE_TAG, response)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(RESPONSES_TAG))?;
+        }
+
+        if let Some(detail) = &self.detail {
+            write_simple_tag(writer, DETAIL_TAG, detail)?;
+        }
+
+        if let Some(first_issued) = &self.first_issued {
+            write_simple_tag(writer, FIRST_ISSUED_TAG, first_issued)?;
+        }
+
+        if let Some(last_updated) = &self.last_updated {
+            write_simple_tag(writer, LAST_UPDATED_TAG, last_updated)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(ANALYSIS_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for VulnerabilityAnalysis {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut state: Option<String> = None;
+        let mut justification: Option<String> = None;
+        let mut responses: Option<Vec<String>> = None;
+        let mut detail: Option<String> = None;
+        let mut first_issued: Option<String> = None;
+        let mut last_updated: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(ANALYSIS_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == STATE_TAG => {
+                    state = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == JUSTIFICATION_TAG =>
+                {
+                    justification = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == RESPONSES_TAG => {
+                    responses = Some(read_list_tag(event_reader, &name, RESPONSE_TAG)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == DETAIL_TAG => {
+                    detail = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == FIRST_ISSUED_TAG =>
+                {
+                    first_issued = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == LAST_UPDATED_TAG =>
+                {
+                    last_updated = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            state,
+            justification,
+            responses,
+            detail,
+            first_issued,
+            last_updated,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct VulnerabilityTarget {
+    #[serde(rename = "ref")]
+    bom_ref: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    versions: Option<Vec<AffectedVersion>>,
+}
+
+impl From<models::vulnerability::VulnerabilityTarget> for VulnerabilityTarget {
+    fn from(other: models::vulnerability::VulnerabilityTarget) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            versions: convert_optional_vec(other.versions),
+        }
+    }
+}
+
+impl From<VulnerabilityTarget> for models::vulnerability::VulnerabilityTarget {
+    fn from(other: VulnerabilityTarget) -> Self {
+        Self {
+            bom_ref: other.bom_ref,
+            versions: convert_optional_vec(other.versions),
+        }
+    }
+}
+
+const REF_TAG: &str = "ref";
+const VERSIONS_TAG: &str = "versions";
+const VERSION_TAG: &str = "version";
+
+impl ToXml for VulnerabilityTarget {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(TARGET_TAG))
+            .map_err(to_xml_write_error(TARGET_TAG))?;
+
+        write_simple_tag(writer, REF_TAG, &self.bom_ref)?;
+
+        if let Some(versions) = &self.versions {
+            writer
+                .write(XmlEvent::start_element(VERSIONS_TAG))
+                .map_err(to_xml_write_error(VERSIONS_TAG))?;
+            for version in versions {
+                version.write_xml_element(writer)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(VERSIONS_TAG))?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(TARGET_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for VulnerabilityTarget {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut bom_ref: Option<String> = None;
+        let mut versions: Option<Vec<AffectedVersion>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader.next().map_err(to_xml_read_error(TARGET_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == REF_TAG => {
+                    bom_ref = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == VERSIONS_TAG => {
+                    versions = Some(read_list_tag(event_reader, &name, VERSION_TAG)?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let bom_ref = bom_ref.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: REF_TAG.to_string(),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self { bom_ref, versions })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct AffectedVersion {
+    #[serde(flatten)]
+    version_range: VersionRange,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+enum VersionRange {
+    Version(String),
+    Range(String),
+}
+
+impl From<models::vulnerability::AffectedVersion> for AffectedVersion {
+    fn from(other: models::vulnerability::AffectedVersion) -> Self {
+        Self {
+            version_range: match other.version_range {
+                models::vulnerability::VersionRange::Version(version) => {
+                    VersionRange::Version(version.to_string())
+                }
+                models::vulnerability::VersionRange::Range(range) => {
+                    VersionRange::Range(range.to_string())
+                }
+            },
+            status: other.status.map(|s| s.to_string()),
+        }
+    }
+}
+
+impl From<AffectedVersion> for models::vulnerability::AffectedVersion {
+    fn from(other: AffectedVersion) -> Self {
+        Self {
+            version_range: match other.version_range {
+                VersionRange::Version(version) => models::vulnerability::VersionRange::Version(
+                    NormalizedString::new_unchecked(version),
+                ),
+                VersionRange::Range(range) => models::vulnerability::VersionRange::Range(
+                    NormalizedString::new_unchecked(range),
+                ),
+            },
+            status: other
+                .status
+                .map(models::vulnerability::AffectedStatus::new_unchecked),
+        }
+    }
+}
+
+const RANGE_TAG: &str = "range";
+const STATUS_TAG: &str = "status";
+
+impl ToXml for AffectedVersion {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(VERSION_TAG))
+            .map_err(to_xml_write_error(VERSION_TAG))?;
+
+        match &self.version_range {
+            VersionRange::Version(version) => write_simple_tag(writer, VERSION_TAG, version)?,
+            VersionRange::Range(range) => write_simple_tag(writer, RANGE_TAG, range)?,
+        }
+
+        if let Some(status) = &self.status {
+            write_simple_tag(writer, STATUS_TAG, status)?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(VERSION_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for AffectedVersion {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut version_range: Option<VersionRange> = None;
+        let mut status: Option<String> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(VERSION_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == VERSION_TAG => {
+                    version_range =
+                        Some(VersionRange::Version(read_simple_tag(event_reader, &name)?))
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == RANGE_TAG => {
+                    version_range = Some(VersionRange::Range(read_simple_tag(event_reader, &name)?))
+                }
+                reader::XmlEvent::StartElement { name, .. } if name.local_name == STATUS_TAG => {
+                    status = Some(read_simple_tag(event_reader, &name)?)
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        let version_range = version_range.ok_or_else(|| XmlReadError::RequiredDataMissing {
+            required_field: format!("{} or {}", VERSION_TAG, RANGE_TAG),
+            element: element_name.local_name.to_string(),
+        })?;
+
+        Ok(Self {
+            version_range,
+            status,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use crate::{
+        specs::v1_5::{
+            attached_text::test::{corresponding_attached_text, example_attached_text},
+            organization::test::{
+                corresponding_contact, corresponding_entity, example_contact, example_entity,
+            },
+            property::test::{corresponding_properties, example_properties},
+            tool::test::{corresponding_tools, example_tools},
+        },
+        xml::test::{read_element_from_string, write_element_to_string},
+    };
+
+    use super::*;
+
+    pub(crate) fn example_vulnerabilities() -> Vulnerabilities {
+        Vulnerabilities(vec![example_vulnerability()])
+    }
+
+    pub(crate) fn corresponding_vulnerabilities() -> models::vulnerability::Vulnerabilities {
+        models::vulnerability::Vulnerabilities(vec![corresponding_vulnerability()])
+    }
+
+    pub(crate) fn example_vulnerability() -> Vulnerability {
+        Vulnerability {
+            bom_ref: Some("bom-ref".to_string()),
+            id: Some("id".to_string()),
+            source: Some(example_vulnerability_source()),
+            references: Some(vec![VulnerabilityReference {
+                id: "reference id".to_string(),
+                source: example_vulnerability_source(),
+            }]),
+            ratings: Some(vec![VulnerabilityRating {
+                source: Some(example_vulnerability_source()),
+                score: Some(9.8),
+                severity: Some("critical".to_string()),
+                method: Some("CVSSv31".to_string()),
+                vector: Some("vector".to_string()),
+                justification: Some("justification".to_string()),
+            }]),
+            cwes: Some(vec![502]),
+            description: Some("description".to_string()),
+            detail: Some("detail".to_string()),
+            recommendation: Some("recommendation".to_string()),
+            workaround: Some("workaround".to_string()),
+            proof_of_concept: Some(ProofOfConcept {
+                reproduction_steps: Some("reproduction steps".to_string()),
+                environment: Some("environment".to_string()),
+                supporting_material: Some(vec![example_attached_text()]),
+            }),
+            advisories: Some(vec![Advisory {
+                title: Some("title".to_string()),
+                url: "url".to_string(),
+            }]),
+            created: Some("created".to_string()),
+            published: Some("published".to_string()),
+            updated: Some("updated".to_string()),
+            rejected: Some("rejected".to_string()),
+            credits: Some(VulnerabilityCredits {
+                organizations: Some(vec![example_entity()]),
+                individuals: Some(vec![example_contact()]),
+            }),
+            tools: Some(example_tools()),
+            analysis: Some(VulnerabilityAnalysis {
+                state: Some("not_affected".to_string()),
+                justification: Some("code_not_reachable".to_string()),
+                responses: Some(vec!["will_not_fix".to_string(), "update".to_string()]),
+                detail: Some("detail".to_string()),
+                first_issued: Some("first issued".to_string()),
+                last_updated: Some("last updated".to_string()),
+            }),
+            affects: Some(vec![VulnerabilityTarget {
+                bom_ref: "ref".to_string(),
+                versions: Some(vec![
+                    AffectedVersion {
+                        version_range: VersionRange::Version("version".to_string()),
+                        status: Some("affected".to_string()),
+                    },
+                    AffectedVersion {
+                        version_range: VersionRange::Range("range".to_string()),
+                        status: Some("unaffected".to_string()),
+                    },
+                ]),
+            }]),
+            properties: Some(example_properties()),
+        }
+    }
+
+    pub(crate) fn corresponding_vulnerability() -> models::vulnerability::Vulnerability {
+        models::vulnerability::Vulnerability {
+            bom_ref: Some("bom-ref".to_string()),
+            id: Some(NormalizedString::new_unchecked("id".to_string())),
+            source: Some(corresponding_vulnerability_source()),
+            references: Some(vec![models::vulnerability::VulnerabilityReference {
+                id: NormalizedString::new_unchecked("reference id".to_string()),
+                source: corresponding_vulnerability_source(),
+            }]),
+            ratings: Some(vec![models::vulnerability::VulnerabilityRating {
+                source: Some(corresponding_vulnerability_source()),
+                score: Some(models::vulnerability::Score::from(9.8)),
+                severity: Some(models::vulnerability::Severity::Critical),
+                method: Some(models::vulnerability::ScoreMethod::CVSSv31),
+                vector: Some(NormalizedString::new_unchecked("vector".to_string())),
+                justification: Some("justification".to_string()),
+            }]),
+            cwes: Some(vec![502]),
+            description: Some("description".to_string()),
+            detail: Some("detail".to_string()),
+            recommendation: Some("recommendation".to_string()),
+            workaround: Some("workaround".to_string()),
+            proof_of_concept: Some(models::vulnerability::ProofOfConcept {
+                reproduction_steps: Some("reproduction steps".to_string()),
+                environment: Some("environment".to_string()),
+                supporting_material: Some(vec![corresponding_attached_text()]),
+            }),
+            advisories: Some(vec![models::vulnerability::Advisory {
+                title: Some(NormalizedString::new_unchecked("title".to_string())),
+                url: Uri("url".to_string()),
+            }]),
+            created: Some(DateTime("created".to_string())),
+            published: Some(DateTime("published".to_string())),
+            updated: Some(DateTime("updated".to_string())),
+            rejected: Some(DateTime("rejected".to_string())),
+            credits: Some(models::vulnerability::VulnerabilityCredits {
+                organizations: Some(vec![corresponding_entity()]),
+                individuals: Some(vec![corresponding_contact()]),
+            }),
+            tools: Some(corresponding_tools()),
+            analysis: Some(models::vulnerability::VulnerabilityAnalysis {
+                state: Some(models::vulnerability::ImpactAnalysisState::NotAffected),
+                justification: Some(
+                    models::vulnerability::ImpactAnalysisJustification::CodeNotReachable,
+                ),
+                responses: Some(vec![
+                    models::vulnerability::ImpactAnalysisResponse::WillNotFix,
+                    models::vulnerability::ImpactAnalysisResponse::Update,
+                ]),
+                detail: Some("detail".to_string()),
+                first_issued: Some(DateTime("first issued".to_string())),
+                last_updated: Some(DateTime("last updated".to_string())),
+            }),
+            affects: Some(vec![models::vulnerability::VulnerabilityTarget {
+                bom_ref: "ref".to_string(),
+                versions: Some(vec![
+                    models::vulnerability::AffectedVersion {
+                        version_range: models::vulnerability::VersionRange::Version(
+                            NormalizedString::new_unchecked("version".to_string()),
+                        ),
+                        status: Some(models::vulnerability::AffectedStatus::Affected),
+                    },
+                    models::vulnerability::AffectedVersion {
+                        version_range: models::vulnerability::VersionRange::Range(
+                            NormalizedString::new_unchecked("range".to_string()),
+                        ),
+                        status: Some(models::vulnerability::AffectedStatus::Unaffected),
+                    },
+                ]),
+            }]),
+            properties: Some(corresponding_properties()),
+        }
+    }
+
+    fn example_vulnerability_source() -> VulnerabilitySource {
+        VulnerabilitySource {
+            name: Some("name".to_string()),
+            url: Some("url".to_string()),
+        }
+    }
+
+    fn corresponding_vulnerability_source() -> models::vulnerability::VulnerabilitySource {
+        models::vulnerability::VulnerabilitySource {
+            name: Some(NormalizedString::new_unchecked("name".to_string())),
+            url: Some(Uri("url".to_string())),
+        }
+    }
+
+    #[test]
+    fn it_can_convert_to_the_internal_model() {
+        let actual: models::vulnerability::Vulnerabilities = example_vulnerabilities().into();
+        assert_eq!(actual, corresponding_vulnerabilities());
+    }
+
+    #[test]
+    fn it_can_convert_from_the_internal_model() {
+        let actual: Vulnerabilities = corresponding_vulnerabilities().into();
+        assert_eq!(actual, example_vulnerabilities());
+    }
+
+    #[test]
+    fn it_should_serialize_affected_versions_to_json() {
+        let actual = serde_json::to_value(example_vulnerability().affects).unwrap();
+        let expected = serde_json::json!([
+            {
+                "ref": "ref",
+                "versions": [
+                    { "version": "version", "status": "affected" },
+                    { "range": "range", "status": "unaffected" }
+                ]
+            }
+        ]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_vulnerabilities());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<vulnerabilities>
+  <vulnerability bom-ref="bom-ref">
+    <id>id</id>
+    <source>
+      <name>name</name>
+      <url>url</url>
+    </source>
+    <references>
+      <reference>
+        <id>reference id</id>
+        <source>
+          <name>name</name>
+          <url>url</url>
+        </source>
+      </reference>
+    </references>
+    <ratings>
+      <rating>
+        <source>
+          <name>name</name>
+          <url>url</url>
+        </source>
+        <score>9.8</score>
+        <severity>critical</severity>
+        <method>CVSSv31</method>
+        <vector>vector</vector>
+        <justification>justification</justification>
+      </rating>
+    </ratings>
+    <cwes>
+      <cwe>502</cwe>
+    </cwes>
+    <description>description</description>
+    <detail>detail</detail>
+    <recommendation>recommendation</recommendation>
+    <workaround>workaround</workaround>
+    <proofOfConcept>
+      <reproductionSteps>reproduction steps</reproductionSteps>
+      <environment>environment</environment>
+      <supportingMaterial>
+        <attachment content-type="content type" encoding="encoding">content</attachment>
+      </supportingMaterial>
+    </proofOfConcept>
+    <advisories>
+      <advisory>
+        <title>title</title>
+        <url>url</url>
+      </advisory>
+    </advisories>
+    <created>created</created>
+    <published>published</published>
+    <updated>updated</updated>
+    <rejected>rejected</rejected>
+    <credits>
+      <organizations>
+        <organization>
+          <name>name</name>
+          <url>url</url>
+          <contact>
+            <name>name</name>
+            <email>email</email>
+            <phone>phone</phone>
+          </contact>
+        </organization>
+      </organizations>
+      <individuals>
+        <individual>
+          <name>name</name>
+          <email>email</email>
+          <phone>phone</phone>
+        </individual>
+      </individuals>
+    </credits>
+    <tools>
+      <tool>
+        <vendor>vendor</vendor>
+        <name>name</name>
+        <version>version</version>
+        <hashes>
+          <hash alg="algorithm">hash value</hash>
+        </hashes>
+        <externalReferences>
+          <reference type="external reference type">
+            <url>url</url>
+            <comment>comment</comment>
+            <hashes>
+              <hash alg="algorithm">hash value</hash>
+            </hashes>
+          </reference>
+        </externalReferences>
+      </tool>
+    </tools>
+    <analysis>
+      <state>not_affected</state>
+      <justification>code_not_reachable</justification>
+      <responses>
+        <response>will_not_fix</response>
+        <response>update</response>
+      </responses>
+      <detail>detail</detail>
+      <firstIssued>first issued</firstIssued>
+      <lastUpdated>last updated</lastUpdated>
+    </analysis>
+    <affects>
+      <target>
+        <ref>ref</ref>
+        <versions>
+          <version>
+            <version>version</version>
+            <status>affected</status>
+          </version>
+          <version>
+            <range>range</range>
+            <status>unaffected</status>
+          </version>
+        </versions>
+      </target>
+    </affects>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+  </vulnerability>
+</vulnerabilities>
+"#;
+        let actual: Vulnerabilities = read_element_from_string(input);
+        let expected = example_vulnerabilities();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,470 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Read the components, services and dependencies of a BOM one at a time.
+//!
+//! Parsing a BOM with e.g. [`Bom::parse_from_json_v1_3`](crate::models::bom::Bom::parse_from_json_v1_3)
+//! keeps the whole document in memory, which can be a problem for very large BOMs such as those
+//! of container images. The functions in this module instead hand every top-level component,
+//! service and dependency to a callback as soon as it has been read, and skip everything else.
+//! Nested components and services are part of their parent item.
+//!
+//! Every item can be validated on its own using the [`Validate`] trait.
+//!
+//! ```rust
+//! use cyclonedx_bom::models::bom::SpecVersion;
+//! use cyclonedx_bom::stream::{read_json, BomItem};
+//! use cyclonedx_bom::validation::Validate;
+//!
+//! let bom_json = r#"{
+//!   "bomFormat": "CycloneDX",
+//!   "specVersion": "1.3",
+//!   "version": 1,
+//!   "components": [
+//!     { "type": "library", "name": "first", "version": "1.0.0" },
+//!     { "type": "library", "name": "second", "version": "1.0.0" }
+//!   ]
+//! }"#;
+//!
+//! let mut names = Vec::new();
+//! read_json(bom_json.as_bytes(), SpecVersion::V1_3, |item| {
+//!     assert!(item.validate().unwrap().passed());
+//!     if let BomItem::Component(component) = item {
+//!         names.push(component.name.to_string());
+//!     }
+//! })
+//! .expect("Failed to read BOM");
+//!
+//! assert_eq!(names, vec!["first", "second"]);
+//! ```
+
+use std::fmt;
+use std::io::Read;
+use std::marker::PhantomData;
+
+use serde::de::{DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::Deserializer;
+use xml::reader;
+use xml::{EventReader, ParserConfig};
+
+use crate::errors::{JsonReadError, XmlReadError};
+use crate::json::json_pointer;
+use crate::models::{
+    bom::SpecVersion, component::Component, dependency::Dependency, service::Service,
+};
+use crate::specs::{v1_3, v1_4, v1_5};
+use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
+use crate::xml::{
+    expected_namespace_or_error, read_lax_validation_tag, to_xml_read_error,
+    unexpected_element_error, with_position, CountingReader, FromXml,
+};
+
+/// A top-level item of a BOM that was read on its own
+#[derive(Debug, PartialEq, Eq)]
+pub enum BomItem {
+    Component(Box<Component>),
+    Service(Box<Service>),
+    Dependency(Dependency),
+}
+
+impl Validate for BomItem {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            BomItem::Component(component) => component.validate_with_context(context),
+            BomItem::Service(service) => service.validate_with_context(context),
+            // dependencies can only be validated against the rest of the BOM
+            BomItem::Dependency(_) => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+/// Read a JSON document conforming to the given version of the specification, passing every
+/// top-level component, service and dependency to `on_item` as soon as it has been read
+pub fn read_json<R: Read, F: FnMut(BomItem)>(
+    reader: R,
+    spec_version: SpecVersion,
+    mut on_item: F,
+) -> Result<(), JsonReadError> {
+    match spec_version {
+        SpecVersion::V1_3 => read_json_items::<
+            _,
+            v1_3::component::Component,
+            v1_3::service::Service,
+            v1_3::dependency::Dependency,
+        >(reader, &mut on_item),
+        SpecVersion::V1_4 => read_json_items::<
+            _,
+            v1_4::component::Component,
+            v1_4::service::Service,
+            v1_4::dependency::Dependency,
+        >(reader, &mut on_item),
+        SpecVersion::V1_5 => read_json_items::<
+            _,
+            v1_5::component::Component,
+            v1_5::service::Service,
+            v1_5::dependency::Dependency,
+        >(reader, &mut on_item),
+    }
+}
+
+/// Read an XML document conforming to the given version of the specification, passing every
+/// top-level component, service and dependency to `on_item` as soon as it has been read
+pub fn read_xml<R: Read, F: FnMut(BomItem)>(
+    reader: R,
+    spec_version: SpecVersion,
+    mut on_item: F,
+) -> Result<(), XmlReadError> {
+    match spec_version {
+        SpecVersion::V1_3 => read_xml_items::<
+            _,
+            v1_3::component::Component,
+            v1_3::service::Service,
+            v1_3::dependency::Dependency,
+        >(reader, spec_version, &mut on_item),
+        SpecVersion::V1_4 => read_xml_items::<
+            _,
+            v1_4::component::Component,
+            v1_4::service::Service,
+            v1_4::dependency::Dependency,
+        >(reader, spec_version, &mut on_item),
+        SpecVersion::V1_5 => read_xml_items::<
+            _,
+            v1_5::component::Component,
+            v1_5::service::Service,
+            v1_5::dependency::Dependency,
+        >(reader, spec_version, &mut on_item),
+    }
+}
+
+fn read_json_items<R, C, S, D>(
+    reader: R,
+    on_item: &mut dyn FnMut(BomItem),
+) -> Result<(), JsonReadError>
+where
+    R: Read,
+    C: DeserializeOwned + Into<Component>,
+    S: DeserializeOwned + Into<Service>,
+    D: DeserializeOwned + Into<Dependency>,
+{
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let mut track = serde_path_to_error::Track::new();
+    serde_path_to_error::Deserializer::new(&mut deserializer, &mut track)
+        .deserialize_map(BomVisitor::<C, S, D> {
+            on_item,
+            types: PhantomData,
+        })
+        .map_err(|error| JsonReadError::JsonElementReadError {
+            error,
+            path: json_pointer(&track.path()),
+        })?;
+    deserializer.end()?;
+    Ok(())
+}
+
+/// Visits the top-level object of a BOM, streaming the items and ignoring all other fields
+struct BomVisitor<'a, C, S, D> {
+    on_item: &'a mut dyn FnMut(BomItem),
+    types: PhantomData<(C, S, D)>,
+}
+
+impl<'de, 'a, C, S, D> Visitor<'de> for BomVisitor<'a, C, S, D>
+where
+    C: DeserializeOwned + Into<Component>,
+    S: DeserializeOwned + Into<Service>,
+    D: DeserializeOwned + Into<Dependency>,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a CycloneDX BOM")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "components" => map.next_value_seed(ItemsSeed::<C, _> {
+                    on_item: &mut *self.on_item,
+                    to_item: |component: C| BomItem::Component(Box::new(component.into())),
+                    item_type: PhantomData,
+                })?,
+                "services" => map.next_value_seed(ItemsSeed::<S, _> {
+                    on_item: &mut *self.on_item,
+                    to_item: |service: S| BomItem::Service(Box::new(service.into())),
+                    item_type: PhantomData,
+                })?,
+                "dependencies" => map.next_value_seed(ItemsSeed::<D, _> {
+                    on_item: &mut *self.on_item,
+                    to_item: |dependency: D| BomItem::Dependency(dependency.into()),
+                    item_type: PhantomData,
+                })?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes an array one element at a time, passing each element on as it is read
+struct ItemsSeed<'a, T, I> {
+    on_item: &'a mut dyn FnMut(BomItem),
+    to_item: I,
+    item_type: PhantomData<T>,
+}
+
+impl<'de, 'a, T, I> DeserializeSeed<'de> for ItemsSeed<'a, T, I>
+where
+    T: DeserializeOwned,
+    I: Fn(T) -> BomItem,
+{
+    type Value = ();
+
+    fn deserialize<De: Deserializer<'de>>(self, deserializer: De) -> Result<(), De::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, 'a, T, I> Visitor<'de> for ItemsSeed<'a, T, I>
+where
+    T: DeserializeOwned,
+    I: Fn(T) -> BomItem,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while let Some(item) = seq.next_element::<T>()? {
+            (self.on_item)((self.to_item)(item));
+        }
+        Ok(())
+    }
+}
+
+const BOM_TAG: &str = "bom";
+const COMPONENTS_TAG: &str = "components";
+const COMPONENT_TAG: &str = "component";
+const SERVICES_TAG: &str = "services";
+const SERVICE_TAG: &str = "service";
+const DEPENDENCIES_TAG: &str = "dependencies";
+const DEPENDENCY_TAG: &str = "dependency";
+
+fn read_xml_items<R, C, S, D>(
+    reader: R,
+    spec_version: SpecVersion,
+    on_item: &mut dyn FnMut(BomItem),
+) -> Result<(), XmlReadError>
+where
+    R: Read,
+    C: FromXml + Into<Component>,
+    S: FromXml + Into<Service>,
+    D: FromXml + Into<Dependency>,
+{
+    let config = ParserConfig::default().trim_whitespace(true);
+    let mut event_reader = EventReader::new_with_config(CountingReader::new(reader), config);
+
+    read_xml_bom::<_, C, S, D>(&mut event_reader, spec_version, on_item)
+        .map_err(|error| with_position(error, &event_reader))
+}
+
+fn read_xml_bom<R, C, S, D>(
+    event_reader: &mut EventReader<R>,
+    spec_version: SpecVersion,
+    on_item: &mut dyn FnMut(BomItem),
+) -> Result<(), XmlReadError>
+where
+    R: Read,
+    C: FromXml + Into<Component>,
+    S: FromXml + Into<Service>,
+    D: FromXml + Into<Dependency>,
+{
+    event_reader
+        .next()
+        .map_err(to_xml_read_error(BOM_TAG))
+        .and_then(|event| match event {
+            reader::XmlEvent::StartDocument { .. } => Ok(()),
+            unexpected => Err(unexpected_element_error(BOM_TAG, unexpected)),
+        })?;
+
+    event_reader
+        .next()
+        .map_err(to_xml_read_error(BOM_TAG))
+        .and_then(|event| match event {
+            reader::XmlEvent::StartElement {
+                name, namespace, ..
+            } if name.local_name == BOM_TAG => {
+                expected_namespace_or_error(spec_version.to_string(), &namespace)
+            }
+            unexpected => Err(unexpected_element_error(BOM_TAG, unexpected)),
+        })?;
+
+    let mut got_end_tag = false;
+    while !got_end_tag {
+        let next_element = event_reader.next().map_err(to_xml_read_error(BOM_TAG))?;
+        match next_element {
+            reader::XmlEvent::StartElement { name, .. } if name.local_name == COMPONENTS_TAG => {
+                read_xml_list::<_, C>(event_reader, COMPONENTS_TAG, COMPONENT_TAG, |c| {
+                    on_item(BomItem::Component(Box::new(c.into())))
+                })?
+            }
+            reader::XmlEvent::StartElement { name, .. } if name.local_name == SERVICES_TAG => {
+                read_xml_list::<_, S>(event_reader, SERVICES_TAG, SERVICE_TAG, |s| {
+                    on_item(BomItem::Service(Box::new(s.into())))
+                })?
+            }
+            reader::XmlEvent::StartElement { name, .. } if name.local_name == DEPENDENCIES_TAG => {
+                read_xml_list::<_, D>(event_reader, DEPENDENCIES_TAG, DEPENDENCY_TAG, |d| {
+                    on_item(BomItem::Dependency(d.into()))
+                })?
+            }
+            // everything else, including the metadata, is skipped
+            reader::XmlEvent::StartElement { name, .. } => {
+                read_lax_validation_tag(event_reader, &name)?
+            }
+            reader::XmlEvent::EndElement { name } if name.local_name == BOM_TAG => {
+                got_end_tag = true;
+            }
+            unexpected => return Err(unexpected_element_error(BOM_TAG, unexpected)),
+        }
+    }
+
+    Ok(())
+}
+
+fn read_xml_list<R: Read, T: FromXml>(
+    event_reader: &mut EventReader<R>,
+    list_tag: &str,
+    item_tag: &str,
+    mut on_item: impl FnMut(T),
+) -> Result<(), XmlReadError> {
+    let mut got_end_tag = false;
+    while !got_end_tag {
+        let next_element = event_reader.next().map_err(to_xml_read_error(list_tag))?;
+        match next_element {
+            reader::XmlEvent::StartElement {
+                name, attributes, ..
+            } if name.local_name == item_tag => {
+                on_item(T::read_xml_element(event_reader, &name, &attributes)?)
+            }
+            // lax validation of any elements from a different schema
+            reader::XmlEvent::StartElement { name, .. } => {
+                read_lax_validation_tag(event_reader, &name)?
+            }
+            reader::XmlEvent::EndElement { name } if name.local_name == list_tag => {
+                got_end_tag = true;
+            }
+            unexpected => return Err(unexpected_element_error(list_tag, unexpected)),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::{FailureReason, Severity};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_stream_json_items_and_skip_everything_else() {
+        let input = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.4",
+  "version": 1,
+  "metadata": { "component": { "type": "application", "name": "app", "version": "1.0.0" } },
+  "dependencies": [ { "ref": "lib", "dependsOn": [] } ],
+  "components": [ { "type": "library", "name": "lib", "version": "1.0.0", "bom-ref": "lib" } ],
+  "services": [ { "name": "service" } ]
+}"#;
+
+        let mut items = Vec::new();
+        read_json(input.as_bytes(), SpecVersion::V1_4, |item| items.push(item))
+            .expect("Failed to read BOM");
+
+        assert_eq!(
+            items,
+            vec![
+                BomItem::Dependency(Dependency {
+                    dependency_ref: "lib".to_string(),
+                    dependencies: vec![],
+                }),
+                BomItem::Component(Box::new(Component::new(
+                    crate::models::component::Classification::Library,
+                    "lib",
+                    "1.0.0",
+                    Some("lib".to_string()),
+                ))),
+                BomItem::Service(Box::new(Service::new("service", None))),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_validate_streamed_xml_items_on_their_own() {
+        let input = r#"<?xml version="1.0"?>
+<bom version="1" xmlns="http://cyclonedx.org/schema/bom/1.3">
+  <components>
+    <component type="unknown">
+      <name>lib</name>
+      <version>1.0.0</version>
+    </component>
+  </components>
+</bom>"#;
+
+        let mut results = Vec::new();
+        read_xml(input.as_bytes(), SpecVersion::V1_3, |item| {
+            results.push(item.validate().expect("Failed to validate item"))
+        })
+        .expect("Failed to read BOM");
+
+        assert_eq!(
+            results,
+            vec![ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    severity: Severity::Error,
+                    message: "Unknown classification".to_string(),
+                    context: ValidationContext::default()
+                        .extend_context_with_struct_field("Component", "component_type"),
+                }]
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_fail_to_stream_xml_with_a_different_spec_version() {
+        let input = r#"<?xml version="1.0"?>
+<bom version="1" xmlns="http://cyclonedx.org/schema/bom/1.4"/>"#;
+
+        let error = read_xml(input.as_bytes(), SpecVersion::V1_3, |_| ())
+            .expect_err("Expected a namespace error");
+
+        match error {
+            XmlReadError::PositionedError { error, position } => {
+                assert!(matches!(*error, XmlReadError::InvalidNamespaceError { .. }));
+                assert_eq!(position.line, 2);
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+    }
+}
@@ -51,6 +51,45 @@ impl ValidationContext {
     }
 }
 
+/// Renders the context as a path, e.g. `Bom.components[3].licenses[0].expression`
+impl std::fmt::Display for ValidationContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, component) in self.0.iter().enumerate() {
+            match component {
+                ValidationPathComponent::Struct {
+                    struct_name,
+                    field_name,
+                } => {
+                    if index == 0 {
+                        write!(f, "{}", struct_name)?;
+                    }
+                    write!(f, ".{}", field_name)?;
+                }
+                ValidationPathComponent::Array { index } => write!(f, "[{}]", index)?,
+                ValidationPathComponent::EnumVariant { variant_name } => {
+                    write!(f, ".{}", to_snake_case(variant_name))?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::with_capacity(name.len());
+    for (index, character) in name.chars().enumerate() {
+        if character.is_uppercase() {
+            if index > 0 {
+                snake_case.push('_');
+            }
+            snake_case.extend(character.to_lowercase());
+        } else {
+            snake_case.push(character);
+        }
+    }
+    snake_case
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ValidationPathComponent {
     Struct {
@@ -68,28 +107,61 @@ pub enum ValidationPathComponent {
 #[derive(Debug, PartialEq, Eq)]
 pub enum ValidationResult {
     Passed,
-    Failed { reasons: Vec<FailureReason> },
+    /// Validation passed, but there are issues worth reporting, e.g. missing package URLs
+    PassedWithWarnings {
+        warnings: Vec<FailureReason>,
+    },
+    /// Validation failed, `reasons` contains at least one error and may also contain warnings
+    Failed {
+        reasons: Vec<FailureReason>,
+    },
 }
 
 impl ValidationResult {
     pub fn merge(self, other: Self) -> Self {
         match (self, other) {
-            (Self::Passed, Self::Passed) => Self::Passed,
-            (Self::Passed, Self::Failed { reasons }) => Self::Failed { reasons },
-            (Self::Failed { reasons }, Self::Passed) => Self::Failed { reasons },
+            (Self::Passed, other) => other,
+            (result, Self::Passed) => result,
             (
-                Self::Failed {
-                    reasons: mut left_reasons,
+                Self::PassedWithWarnings {
+                    warnings: mut left_warnings,
                 },
-                Self::Failed {
-                    reasons: mut right_reasons,
+                Self::PassedWithWarnings {
+                    warnings: mut right_warnings,
                 },
             ) => {
-                left_reasons.append(&mut right_reasons);
-                Self::Failed {
-                    reasons: left_reasons,
+                left_warnings.append(&mut right_warnings);
+                Self::PassedWithWarnings {
+                    warnings: left_warnings,
                 }
             }
+            (left, right) => {
+                let mut reasons = left.into_reasons();
+                reasons.append(&mut right.into_reasons());
+                Self::Failed { reasons }
+            }
+        }
+    }
+
+    /// Whether validation passed, possibly with warnings
+    pub fn passed(&self) -> bool {
+        !matches!(self, Self::Failed { .. })
+    }
+
+    /// All errors and warnings, in the order they were found
+    pub fn reasons(&self) -> &[FailureReason] {
+        match self {
+            Self::Passed => &[],
+            Self::PassedWithWarnings { warnings } => warnings,
+            Self::Failed { reasons } => reasons,
+        }
+    }
+
+    fn into_reasons(self) -> Vec<FailureReason> {
+        match self {
+            Self::Passed => Vec::new(),
+            Self::PassedWithWarnings { warnings } => warnings,
+            Self::Failed { reasons } => reasons,
         }
     }
 }
@@ -102,11 +174,119 @@ impl Default for ValidationResult {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct FailureReason {
+    pub severity: Severity,
     pub message: String,
     pub context: ValidationContext,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The BOM does not conform to the specification
+    Error,
+    /// The BOM conforms to the specification, but does not follow best practices
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum ValidationError {
     #[error("Failed to compile regular expression: {0}")]
     InvalidRegularExpressionError(#[from] regex::Error),
+
+    #[error("Failed to compile JSON schema: {0}")]
+    InvalidSchemaError(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn reason(severity: Severity, message: &str) -> FailureReason {
+        FailureReason {
+            severity,
+            message: message.to_string(),
+            context: ValidationContext::default(),
+        }
+    }
+
+    #[test]
+    fn it_should_display_the_context_as_a_path() {
+        let context = ValidationContext::default()
+            .extend_context_with_struct_field("Bom", "components")
+            .extend_context(vec![ValidationPathComponent::Array { index: 3 }])
+            .extend_context_with_struct_field("Component", "licenses")
+            .extend_context(vec![
+                ValidationPathComponent::Array { index: 0 },
+                ValidationPathComponent::EnumVariant {
+                    variant_name: "Expression".to_string(),
+                },
+            ]);
+
+        assert_eq!(
+            context.to_string(),
+            "Bom.components[3].licenses[0].expression"
+        );
+    }
+
+    #[test]
+    fn it_should_display_enum_variants_in_snake_case() {
+        let context = ValidationContext(vec![
+            ValidationPathComponent::Array { index: 2 },
+            ValidationPathComponent::EnumVariant {
+                variant_name: "SpdxId".to_string(),
+            },
+        ]);
+
+        assert_eq!(context.to_string(), "[2].spdx_id");
+    }
+
+    #[test]
+    fn it_should_merge_warnings_without_failing() {
+        let result = ValidationResult::Passed
+            .merge(ValidationResult::PassedWithWarnings {
+                warnings: vec![reason(Severity::Warning, "first")],
+            })
+            .merge(ValidationResult::PassedWithWarnings {
+                warnings: vec![reason(Severity::Warning, "second")],
+            });
+
+        assert!(result.passed());
+        assert_eq!(
+            result,
+            ValidationResult::PassedWithWarnings {
+                warnings: vec![
+                    reason(Severity::Warning, "first"),
+                    reason(Severity::Warning, "second")
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_merging_warnings_with_errors() {
+        let result = ValidationResult::PassedWithWarnings {
+            warnings: vec![reason(Severity::Warning, "warning")],
+        }
+        .merge(ValidationResult::Failed {
+            reasons: vec![reason(Severity::Error, "error")],
+        });
+
+        assert!(!result.passed());
+        assert_eq!(
+            result.reasons(),
+            &[
+                reason(Severity::Warning, "warning"),
+                reason(Severity::Error, "error")
+            ]
+        );
+    }
 }
@@ -1,12 +1,13 @@
-use crate::errors::{XmlReadError, XmlWriteError};
+use crate::errors::{DocumentPosition, XmlReadError, XmlWriteError};
 use std::io::{Read, Write};
 use xml::{
     attribute::OwnedAttribute,
+    common::Position,
     name::OwnedName,
     namespace::{Namespace, NS_NO_PREFIX},
     reader::{self},
     writer::{self, EventWriter},
-    EventReader,
+    EventReader, ParserConfig,
 };
 
 pub(crate) trait ToXmlDocument {
@@ -70,6 +71,54 @@ pub(crate) trait FromXmlDocument {
         Self: Sized;
 }
 
+/// Read an XML document, adding the position at which reading failed to any error
+pub(crate) fn read_xml_document_with_position<T: FromXmlDocument, R: Read>(
+    reader: R,
+) -> Result<T, XmlReadError> {
+    let config = ParserConfig::default().trim_whitespace(true);
+    let mut event_reader = EventReader::new_with_config(CountingReader::new(reader), config);
+    T::read_xml_document(&mut event_reader).map_err(|error| with_position(error, &event_reader))
+}
+
+/// Wrap the error with the position that the event reader has reached in the document
+pub(crate) fn with_position<R: Read>(
+    error: XmlReadError,
+    event_reader: &EventReader<CountingReader<R>>,
+) -> XmlReadError {
+    let position = event_reader.position();
+    XmlReadError::PositionedError {
+        error: Box::new(error),
+        position: DocumentPosition {
+            offset: event_reader.source().offset,
+            line: position.row + 1,
+            column: position.column + 1,
+        },
+    }
+}
+
+/// Counts the bytes read from the underlying reader.
+///
+/// The XML parser reads its input one character at a time, so the count is the byte offset that
+/// parsing has reached.
+pub(crate) struct CountingReader<R> {
+    reader: R,
+    offset: u64,
+}
+
+impl<R> CountingReader<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self { reader, offset: 0 }
+    }
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let count = self.reader.read(buf)?;
+        self.offset += count as u64;
+        Ok(count)
+    }
+}
+
 pub(crate) trait FromXml {
     fn read_xml_element<R: Read>(
         event_reader: &mut EventReader<R>,
@@ -208,6 +257,26 @@ impl FromXmlType for u32 {
     }
 }
 
+impl FromXmlType for f32 {
+    fn xml_type_display() -> String {
+        "xs:decimal".to_string()
+    }
+
+    fn from_xml_value(
+        element: impl ToString,
+        value: impl AsRef<str>,
+    ) -> Result<Self, XmlReadError> {
+        let value = value.as_ref();
+        let value: f32 = value.parse().map_err(|_| XmlReadError::InvalidParseError {
+            value: value.to_string(),
+            data_type: Self::xml_type_display(),
+            element: element.to_string(),
+        })?;
+
+        Ok(value)
+    }
+}
+
 pub(crate) fn read_simple_tag<R: Read>(
     event_reader: &mut EventReader<R>,
     element: &OwnedName,
@@ -399,6 +468,27 @@ pub(crate) mod test {
         output
     }
 
+    /// Read the first element of the string, returning the error instead of panicking on it
+    pub(crate) fn try_read_element_from_string<X: FromXml>(
+        string: impl AsRef<str>,
+    ) -> Result<X, XmlReadError> {
+        let mut event_reader =
+            EventReader::new_with_config(string.as_ref().as_bytes(), parser_config());
+
+        loop {
+            match event_reader
+                .next()
+                .expect("Failed to read from the XML input")
+            {
+                reader::XmlEvent::StartDocument { .. } => (),
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } => return X::read_xml_element(&mut event_reader, &name, &attributes),
+                other => panic!("Expected to start an element, but got {:?}", other),
+            }
+        }
+    }
+
     pub(crate) fn read_element_from_string<X: FromXml>(string: impl AsRef<str>) -> X {
         let mut event_reader =
             EventReader::new_with_config(string.as_ref().as_bytes(), parser_config());
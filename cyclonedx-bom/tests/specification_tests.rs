@@ -1,24 +1,80 @@
-use cyclonedx_bom::models::bom::Bom;
+use std::path::Path;
+
+use cyclonedx_bom::errors::{JsonReadError, XmlReadError};
+use cyclonedx_bom::external_models::date_time::DateTime;
+use cyclonedx_bom::models::bom::{Bom, SpecVersion, UrnUuid};
+use cyclonedx_bom::models::metadata::Metadata;
+#[cfg(feature = "json-schema")]
+use cyclonedx_bom::schema;
+use cyclonedx_bom::stream::{self, BomItem};
 use cyclonedx_bom::validation::{Validate, ValidationResult};
 
+/// The versions of the specification that have test documents in `data/<version>`
+const SPEC_VERSIONS: [SpecVersion; 3] = [SpecVersion::V1_3, SpecVersion::V1_4, SpecVersion::V1_5];
+
+/// Run the test against every document of every version of the specification that matches the
+/// pattern
+fn for_each_document(pattern: &str, mut test: impl FnMut(SpecVersion, &Path)) {
+    for spec_version in SPEC_VERSIONS {
+        insta::glob!(&format!("data/{spec_version}/{pattern}"), |path| {
+            test(spec_version, path)
+        });
+    }
+}
+
+fn parse_json(spec_version: SpecVersion, path: &Path) -> Result<Bom, JsonReadError> {
+    let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
+    match spec_version {
+        SpecVersion::V1_3 => Bom::parse_from_json_v1_3(file),
+        SpecVersion::V1_4 => Bom::parse_from_json_v1_4(file),
+        SpecVersion::V1_5 => Bom::parse_from_json_v1_5(file),
+    }
+}
+
+fn parse_xml(spec_version: SpecVersion, path: &Path) -> Result<Bom, XmlReadError> {
+    let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
+    match spec_version {
+        SpecVersion::V1_3 => Bom::parse_from_xml_v1_3(file),
+        SpecVersion::V1_4 => Bom::parse_from_xml_v1_4(file),
+        SpecVersion::V1_5 => Bom::parse_from_xml_v1_5(file),
+    }
+}
+
+/// Assert that a valid document passed validation
+///
+/// Documents that only miss best practices, e.g. package URLs, pass with warnings. Those are
+/// compared to the `warnings` snapshot of the document, so that every warning is expected.
+fn assert_passed(validation_result: ValidationResult, path: &Path) {
+    match validation_result {
+        ValidationResult::Passed => {}
+        ValidationResult::PassedWithWarnings { warnings } => {
+            let warnings: Vec<String> = warnings
+                .iter()
+                .map(|warning| format!("{}: {}", warning.context, warning.message))
+                .collect();
+            insta::assert_snapshot!("warnings", warnings.join("\n"));
+        }
+        failed => panic!("{:?} unexpectedly failed validation: {:?}", path, failed),
+    }
+}
+
 #[test]
 fn it_should_parse_all_of_the_valid_xml_specifications() {
-    insta::glob!("data/1.3/valid*.xml", |path| {
-        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
-        let bom = Bom::parse_from_xml_v1_3(file).expect(&format!(
+    for_each_document("valid*.xml", |spec_version, path| {
+        let bom = parse_xml(spec_version, path).expect(&format!(
             "Failed to parse the document as an SBOM: {path:?}"
         ));
 
         let validation_result = bom.validate().expect("Failed to validate BOM");
-        assert_eq!(
-            validation_result,
-            ValidationResult::Passed,
-            "{path:?} unexpectedly failed validation"
-        );
+        assert_passed(validation_result, path);
 
         let mut output = Vec::new();
-        bom.output_as_xml_v1_3(&mut output)
-            .expect(&format!("Failed to output the file: {path:?}"));
+        match spec_version {
+            SpecVersion::V1_3 => bom.output_as_xml_v1_3(&mut output),
+            SpecVersion::V1_4 => bom.output_as_xml_v1_4(&mut output),
+            SpecVersion::V1_5 => bom.output_as_xml_v1_5(&mut output),
+        }
+        .expect(&format!("Failed to output the file: {path:?}"));
         let bom_output = String::from_utf8_lossy(&output).to_string();
 
         insta::assert_snapshot!(bom_output);
@@ -27,22 +83,21 @@ fn it_should_parse_all_of_the_valid_xml_specifications() {
 
 #[test]
 fn it_should_parse_all_of_the_valid_json_specifications() {
-    insta::glob!("data/1.3/valid*.json", |path| {
-        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
-        let bom = Bom::parse_from_json_v1_3(file).expect(&format!(
+    for_each_document("valid*.json", |spec_version, path| {
+        let bom = parse_json(spec_version, path).expect(&format!(
             "Failed to parse the document as an SBOM: {path:?}"
         ));
 
         let validation_result = bom.validate().expect("Failed to validate BOM");
-        assert_eq!(
-            validation_result,
-            ValidationResult::Passed,
-            "{path:?} unexpectedly failed validation"
-        );
+        assert_passed(validation_result, path);
 
         let mut output = Vec::new();
-        bom.output_as_json_v1_3(&mut output)
-            .expect(&format!("Failed to output the file: {path:?}"));
+        match spec_version {
+            SpecVersion::V1_3 => bom.output_as_json_v1_3(&mut output),
+            SpecVersion::V1_4 => bom.output_as_json_v1_4(&mut output),
+            SpecVersion::V1_5 => bom.output_as_json_v1_5(&mut output),
+        }
+        .expect(&format!("Failed to output the file: {path:?}"));
         let bom_output = String::from_utf8_lossy(&output).to_string();
 
         insta::assert_snapshot!(bom_output);
@@ -51,9 +106,8 @@ fn it_should_parse_all_of_the_valid_json_specifications() {
 
 #[test]
 fn it_should_fail_to_parse_all_of_the_invalid_xml_specifications() {
-    insta::glob!("data/1.3/invalid*.xml", |path| {
-        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
-        if let Ok(bom) = Bom::parse_from_xml_v1_3(file) {
+    for_each_document("invalid*.xml", |spec_version, path| {
+        if let Ok(bom) = parse_xml(spec_version, path) {
             let validation_result = bom.validate().expect("Failed to validate BOM");
             assert_ne!(
                 validation_result,
@@ -64,17 +118,240 @@ fn it_should_fail_to_parse_all_of_the_invalid_xml_specifications() {
     });
 }
 
+/// Also covers the documents in `referential_integrity`, which break references between parts of
+/// the document that no schema can check
 #[test]
 fn it_should_fail_to_parse_all_of_the_invalid_json_specifications() {
-    insta::glob!("data/1.3/invalid*.json", |path| {
+    for_each_document(
+        "{invalid*.json,referential_integrity/invalid*.json}",
+        |spec_version, path| {
+            if let Ok(bom) = parse_json(spec_version, path) {
+                let validation_result = bom.validate().expect("Failed to validate BOM");
+                assert_ne!(
+                    validation_result,
+                    ValidationResult::Passed,
+                    "{path:?} unexpectedly passed validation"
+                );
+            }
+        },
+    );
+}
+
+#[test]
+fn it_should_detect_the_spec_version_of_all_of_the_valid_specifications() {
+    for_each_document("valid*.{json,xml}", |expected_version, path| {
         let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
-        if let Ok(bom) = Bom::parse_from_json_v1_3(file) {
-            let validation_result = bom.validate().expect("Failed to validate BOM");
-            assert_ne!(
-                validation_result,
-                ValidationResult::Passed,
-                "{path:?} unexpectedly passed validation"
-            );
+        let (_, spec_version) = Bom::parse(file).expect(&format!(
+            "Failed to parse the document as an SBOM: {path:?}"
+        ));
+
+        assert_eq!(
+            spec_version, expected_version,
+            "{path:?} detected the wrong version"
+        );
+    });
+}
+
+/// Valid documents of the specification that its own schema rejects, together with the reason
+///
+/// These are kept byte-identical to the specification, so they are skipped by the schema
+/// validation instead of being fixed here.
+#[cfg(feature = "json-schema")]
+const SCHEMA_VALIDATION_EXCEPTIONS: [(&str, &str); 1] = [(
+    "1.3/valid-bom-1.3.json",
+    "the attachment has an `encoding` of null and the commit author an `email` of \"\", \
+     neither of which the 1.3 schema allows",
+)];
+
+#[cfg(feature = "json-schema")]
+#[test]
+fn it_should_pass_schema_validation_of_all_of_the_valid_json_specifications() {
+    for_each_document("valid*.json", |spec_version, path| {
+        if SCHEMA_VALIDATION_EXCEPTIONS
+            .iter()
+            .any(|(document, _)| path.ends_with(document))
+        {
+            return;
         }
+
+        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
+        let document =
+            serde_json::from_reader(file).expect(&format!("Failed to read JSON: {path:?}"));
+
+        let validation_result =
+            schema::validate_json(&document, spec_version).expect("Failed to validate JSON");
+        assert!(
+            validation_result.passed(),
+            "{:?} unexpectedly failed schema validation: {:?}",
+            path,
+            validation_result
+        );
+    });
+}
+
+#[cfg(feature = "json-schema")]
+#[test]
+fn it_should_fail_schema_validation_of_all_of_the_invalid_json_specifications() {
+    for_each_document("invalid*.json", |spec_version, path| {
+        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
+        let document =
+            serde_json::from_reader(file).expect(&format!("Failed to read JSON: {path:?}"));
+
+        let schema_result =
+            schema::validate_json(&document, spec_version).expect("Failed to validate JSON");
+        assert!(
+            !schema_result.passed(),
+            "{:?} unexpectedly passed schema validation",
+            path
+        );
+    });
+}
+
+#[test]
+fn it_should_stream_the_same_items_as_it_parses() {
+    for_each_document("valid*.{json,xml}", |spec_version, path| {
+        let is_json = path
+            .extension()
+            .map_or(false, |extension| extension == "json");
+        let open = || std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
+
+        let mut items = Vec::new();
+        let bom = if is_json {
+            stream::read_json(open(), spec_version, |item| items.push(item))
+                .expect(&format!("Failed to stream the document: {path:?}"));
+            parse_json(spec_version, path).expect(&format!(
+                "Failed to parse the document as an SBOM: {path:?}"
+            ))
+        } else {
+            stream::read_xml(open(), spec_version, |item| items.push(item))
+                .expect(&format!("Failed to stream the document: {path:?}"));
+            parse_xml(spec_version, path).expect(&format!(
+                "Failed to parse the document as an SBOM: {path:?}"
+            ))
+        };
+
+        let mut expected = Vec::new();
+        expected.extend(
+            bom.components
+                .into_iter()
+                .flat_map(|c| c.0)
+                .map(|c| BomItem::Component(Box::new(c))),
+        );
+        expected.extend(
+            bom.services
+                .into_iter()
+                .flat_map(|s| s.0)
+                .map(|s| BomItem::Service(Box::new(s))),
+        );
+        expected.extend(
+            bom.dependencies
+                .into_iter()
+                .flat_map(|d| d.0)
+                .map(BomItem::Dependency),
+        );
+        assert_eq!(items, expected, "{path:?} streamed different items");
+    });
+}
+
+#[cfg(feature = "protobuf")]
+#[test]
+fn it_should_round_trip_all_of_the_valid_json_specifications_through_protobuf() {
+    insta::glob!("data/1.3/valid*.json", |path| {
+        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
+        let bom = Bom::parse_from_json_v1_3(file).expect(&format!(
+            "Failed to parse the document as an SBOM: {path:?}"
+        ));
+
+        let mut protobuf = Vec::new();
+        bom.output_as_protobuf_v1_3(&mut protobuf)
+            .expect(&format!("Failed to output the file as protobuf: {path:?}"));
+
+        let bom = Bom::parse_from_protobuf_v1_3(protobuf.as_slice()).expect(&format!(
+            "Failed to parse the protobuf message as an SBOM: {path:?}"
+        ));
+
+        let validation_result = bom.validate().expect("Failed to validate BOM");
+        assert!(
+            validation_result.passed(),
+            "{:?} unexpectedly failed validation after a protobuf round trip: {:?}",
+            path,
+            validation_result
+        );
+
+        let mut round_tripped = Vec::new();
+        bom.output_as_protobuf_v1_3(&mut round_tripped)
+            .expect(&format!("Failed to output the file as protobuf: {path:?}"));
+        assert_eq!(
+            protobuf, round_tripped,
+            "{path:?} changed in a protobuf round trip"
+        );
+
+        let bom = Bom::parse_from_protobuf_v1_3(round_tripped.as_slice()).expect(&format!(
+            "Failed to parse the protobuf message as an SBOM: {path:?}"
+        ));
+        let mut output = Vec::new();
+        bom.output_as_json_v1_3(&mut output)
+            .expect(&format!("Failed to output the file: {path:?}"));
+        let bom_output = String::from_utf8_lossy(&output).to_string();
+
+        insta::assert_snapshot!(bom_output);
+    });
+}
+
+#[test]
+fn it_should_export_all_of_the_valid_json_specifications_as_spdx() {
+    insta::glob!("data/1.3/valid*.json", |path| {
+        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
+        let mut bom = Bom::parse_from_json_v1_3(file).expect(&format!(
+            "Failed to parse the document as an SBOM: {path:?}"
+        ));
+
+        // Fix the values SPDX requires so that the output does not change between runs
+        bom.serial_number.get_or_insert_with(|| {
+            UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string())
+                .expect("Failed to create UrnUuid")
+        });
+        bom.metadata
+            .get_or_insert_with(Metadata::default)
+            .timestamp
+            .get_or_insert_with(|| {
+                DateTime::from_unix_timestamp(1700000000).expect("Failed to create DateTime")
+            });
+
+        let mut output = Vec::new();
+        let losses = bom
+            .output_as_spdx_json_v2_3(&mut output)
+            .expect(&format!("Failed to export the file as SPDX: {path:?}"));
+        let mut spdx_output = String::from_utf8_lossy(&output).to_string();
+        for loss in losses {
+            spdx_output.push_str(&format!("\n{loss:?}"));
+        }
+
+        insta::assert_snapshot!(spdx_output);
+    });
+}
+
+#[test]
+fn it_should_import_all_of_the_valid_json_specifications_exported_as_spdx() {
+    insta::glob!("data/1.3/valid*.json", |path| {
+        let file = std::fs::File::open(path).expect(&format!("Failed to read file: {path:?}"));
+        let bom = Bom::parse_from_json_v1_3(file).expect(&format!(
+            "Failed to parse the document as an SBOM: {path:?}"
+        ));
+
+        let mut spdx = Vec::new();
+        bom.output_as_spdx_json_v2_3(&mut spdx)
+            .expect(&format!("Failed to export the file as SPDX: {path:?}"));
+        let (bom, _) = Bom::parse_from_spdx_json_v2_3(spdx.as_slice()).expect(&format!(
+            "Failed to import the SPDX document as an SBOM: {path:?}"
+        ));
+
+        let validation_result = bom.validate().expect("Failed to validate BOM");
+        assert!(
+            validation_result.passed(),
+            "{:?} unexpectedly failed validation after an SPDX round trip: {:?}",
+            path,
+            validation_result
+        );
     });
 }